@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains helpers for sharing the generated headers, QML modules and types
+//! between CXX-Qt crates using the [`links` manifest key](https://doc.rust-lang.org/cargo/reference/build-scripts.html#the-links-manifest-key).
+//!
+//! A crate with a `links` key exports its metadata from its build script,
+//! and Cargo passes it to the build scripts of the crates directly depending on it
+//! as `DEP_<links>_CXX_QT_*` environment variables.
+
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+const INCLUDE_PATHS: &str = "CXX_QT_INCLUDE_PATHS";
+const QML_IMPORT_PATHS: &str = "CXX_QT_QML_IMPORT_PATHS";
+const METATYPES_JSON: &str = "CXX_QT_METATYPES_JSON";
+const TYPE_REGISTRIES: &str = "CXX_QT_TYPE_REGISTRIES";
+
+/// The metadata of the CXX-Qt crates that are dependencies of the crate being built,
+/// or of the crate being built when it is exported to its dependents
+#[derive(Default)]
+pub(crate) struct Dependencies {
+    /// The include paths containing the `cxx-qt-gen` directory of generated headers
+    pub include_paths: Vec<PathBuf>,
+    /// The QML import paths containing the QML modules
+    pub qml_import_paths: Vec<PathBuf>,
+    /// The metatypes JSON files of the QObjects, for use as qmltyperegistrar foreign types
+    pub metatypes_json: Vec<PathBuf>,
+    /// The CXX-Qt type registries of the bridges
+    pub type_registries: Vec<PathBuf>,
+}
+
+impl Dependencies {
+    /// Find the metadata of all the CXX-Qt crates that the crate being built depends on
+    pub fn find() -> Self {
+        let mut links: Vec<String> = env::vars_os()
+            .filter_map(|(key, _)| {
+                key.to_str()?
+                    .strip_prefix("DEP_")?
+                    .strip_suffix(&format!("_{INCLUDE_PATHS}"))
+                    .map(str::to_owned)
+            })
+            .collect();
+        // Keep the order stable so that the build is reproducible
+        links.sort();
+
+        let mut dependencies = Self::default();
+        for link in links {
+            let read = |key: &str| -> Vec<PathBuf> {
+                env::var_os(format!("DEP_{link}_{key}"))
+                    .map(|paths| env::split_paths(&paths).collect())
+                    .unwrap_or_default()
+            };
+            extend_unique(&mut dependencies.include_paths, read(INCLUDE_PATHS));
+            extend_unique(&mut dependencies.qml_import_paths, read(QML_IMPORT_PATHS));
+            extend_unique(&mut dependencies.metatypes_json, read(METATYPES_JSON));
+            extend_unique(&mut dependencies.type_registries, read(TYPE_REGISTRIES));
+        }
+        dependencies
+    }
+
+    /// Export the metadata to the crates depending on this crate, if it has a `links` key
+    ///
+    /// Exported paths include those of the dependencies, so that they are also
+    /// available to crates which only depend on them indirectly.
+    pub fn export(&self) {
+        if env::var_os("CARGO_MANIFEST_LINKS").is_none() {
+            return;
+        }
+
+        for (key, paths) in [
+            (INCLUDE_PATHS, &self.include_paths),
+            (QML_IMPORT_PATHS, &self.qml_import_paths),
+            (METATYPES_JSON, &self.metatypes_json),
+            (TYPE_REGISTRIES, &self.type_registries),
+        ] {
+            let value = join_paths(paths);
+            println!("cargo:{key}={}", value.to_string_lossy());
+        }
+    }
+}
+
+/// Add the given paths, skipping any that are already present
+pub(crate) fn extend_unique(paths: &mut Vec<PathBuf>, other: impl IntoIterator<Item = PathBuf>) {
+    for path in other {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+fn join_paths(paths: &[impl AsRef<Path>]) -> OsString {
+    env::join_paths(paths.iter().map(AsRef::as_ref))
+        .expect("Could not join paths of CXX-Qt dependency metadata")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_dependencies() {
+        let set = |link: &str, key: &str, paths: &[&str]| {
+            env::set_var(format!("DEP_{link}_{key}"), join_paths(paths));
+        };
+        // Two upstream crates which both export the metadata of a shared dependency
+        set(
+            "CXXQT_TEST_FIRST",
+            INCLUDE_PATHS,
+            &["/first/include", "/shared/include"],
+        );
+        set(
+            "CXXQT_TEST_FIRST",
+            QML_IMPORT_PATHS,
+            &["/first/qml", "/shared/qml"],
+        );
+        set(
+            "CXXQT_TEST_FIRST",
+            METATYPES_JSON,
+            &["/first/metatypes.json"],
+        );
+        set(
+            "CXXQT_TEST_FIRST",
+            TYPE_REGISTRIES,
+            &["/first/type-registry.txt"],
+        );
+        set(
+            "CXXQT_TEST_SECOND",
+            INCLUDE_PATHS,
+            &["/shared/include", "/second/include"],
+        );
+        set("CXXQT_TEST_SECOND", QML_IMPORT_PATHS, &["/shared/qml"]);
+        set(
+            "CXXQT_TEST_SECOND",
+            METATYPES_JSON,
+            &["/second/metatypes.json"],
+        );
+        set(
+            "CXXQT_TEST_SECOND",
+            TYPE_REGISTRIES,
+            &["/second/type-registry.txt"],
+        );
+        // A dependency with a links key which is not a CXX-Qt crate is ignored
+        set("CXXQT_TEST_OTHER", "ROOT", &["/other"]);
+
+        let dependencies = Dependencies::find();
+        let paths = |paths: &[&str]| paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+        assert_eq!(
+            dependencies.include_paths,
+            paths(&["/first/include", "/shared/include", "/second/include"])
+        );
+        assert_eq!(
+            dependencies.qml_import_paths,
+            paths(&["/first/qml", "/shared/qml"])
+        );
+        assert_eq!(
+            dependencies.metatypes_json,
+            paths(&["/first/metatypes.json", "/second/metatypes.json"])
+        );
+        assert_eq!(
+            dependencies.type_registries,
+            paths(&["/first/type-registry.txt", "/second/type-registry.txt"])
+        );
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains helpers for discovering the CXX and CXX-Qt bridges of a crate
+//! by following the `mod` declarations from the crate root.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use cxx_qt_gen::{parse_qt_file, CxxQtItem, Parser};
+use syn::{Attribute, Expr, ExprLit, Item, ItemMod, Lit, Meta};
+
+/// A file containing a bridge which was found by following the `mod` declarations
+pub(crate) struct DiscoveredBridge {
+    /// The path of the file, relative to the crate's Cargo.toml file
+    pub path: PathBuf,
+    /// Whether the bridge contains `#[qml_element]` QObjects, which need to be part of a QML module
+    pub has_qml_elements: bool,
+}
+
+/// Find all files with a `#[cxx_qt::bridge]` or `#[cxx::bridge]` module
+/// in the module tree starting at the given crate root
+pub(crate) fn discover_bridges(manifest_dir: &Path, root: &Path) -> Vec<DiscoveredBridge> {
+    let mut discovery = Discovery {
+        manifest_dir,
+        visited: HashSet::new(),
+        bridges: Vec::new(),
+    };
+    let root = manifest_dir.join(root);
+    let root_dir = root.parent().unwrap_or(manifest_dir).to_path_buf();
+    discovery.visit_file(&root, &root_dir);
+    discovery.bridges
+}
+
+struct Discovery<'a> {
+    manifest_dir: &'a Path,
+    visited: HashSet<PathBuf>,
+    bridges: Vec<DiscoveredBridge>,
+}
+
+impl Discovery<'_> {
+    /// Visit a file of the module tree, where `mod_dir` is the directory of its child modules
+    fn visit_file(&mut self, path: &Path, mod_dir: &Path) {
+        // A file can be reached multiple times with #[path] attributes, possibly through
+        // different relative paths, so compare the canonical paths
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if !self.visited.insert(canonical_path) {
+            return;
+        }
+
+        // Rerun when any module changes, as it could declare a new bridge or module
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let file = match parse_qt_file(path) {
+            Ok(file) => file,
+            Err(err) => {
+                println!(
+                    "cargo:warning=Could not parse {} while discovering CXX-Qt bridges: {err}",
+                    path.display()
+                );
+                return;
+            }
+        };
+
+        let mut has_bridge = false;
+        let mut has_qml_elements = false;
+        for item in file.items {
+            match item {
+                CxxQtItem::Cxx(_) => has_bridge = true,
+                CxxQtItem::CxxQt(mut module) => {
+                    has_bridge = true;
+
+                    // Types imported from other bridges are not known yet, but are not needed here
+                    if let Some((_, items)) = &mut module.content {
+                        items.retain(|item| !matches!(item, Item::Use(_)));
+                    }
+                    match Parser::from(module) {
+                        Ok(parser) => has_qml_elements |= parser.has_qml_elements(),
+                        Err(err) => println!(
+                            "cargo:warning=Could not parse the bridge in {} while discovering CXX-Qt bridges: {err}",
+                            path.display()
+                        ),
+                    }
+                }
+                CxxQtItem::Item(Item::Mod(module)) => {
+                    let file_dir = path.parent().unwrap_or(self.manifest_dir).to_path_buf();
+                    self.visit_mod(&module, &file_dir, mod_dir);
+                }
+                CxxQtItem::Item(_) => {}
+            }
+        }
+
+        if has_bridge {
+            self.bridges.push(DiscoveredBridge {
+                path: path
+                    .strip_prefix(self.manifest_dir)
+                    .unwrap_or(path)
+                    .to_path_buf(),
+                has_qml_elements,
+            });
+        }
+    }
+
+    /// Visit a `mod` item, either declared inline or in another file
+    fn visit_mod(&mut self, module: &ItemMod, file_dir: &Path, mod_dir: &Path) {
+        let path_attribute = path_attribute(&module.attrs);
+
+        if let Some((_, items)) = &module.content {
+            let mod_dir = path_attribute
+                .map(|path| mod_dir.join(path))
+                .unwrap_or_else(|| mod_dir.join(module.ident.to_string()));
+            for item in items {
+                if let Item::Mod(module) = item {
+                    self.visit_mod(module, &mod_dir, &mod_dir);
+                }
+            }
+            return;
+        }
+
+        // Files given by a #[path] attribute, and mod.rs files, have child modules next to them
+        if let Some(path) = path_attribute {
+            let path = file_dir.join(path);
+            let child_dir = path.parent().unwrap_or(file_dir).to_path_buf();
+            self.visit_file(&path, &child_dir);
+            return;
+        }
+
+        let ident = module.ident.to_string();
+        let path = mod_dir.join(format!("{ident}.rs"));
+        if path.exists() {
+            self.visit_file(&path, &mod_dir.join(&ident));
+            return;
+        }
+
+        let path = mod_dir.join(&ident).join("mod.rs");
+        if path.exists() {
+            self.visit_file(&path, &mod_dir.join(&ident));
+        } else {
+            println!(
+                "cargo:warning=Could not find the file of module {ident} in {} while discovering CXX-Qt bridges",
+                mod_dir.display()
+            );
+        }
+    }
+}
+
+/// The value of a `#[path = "..."]` attribute
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("path"))
+        .and_then(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) => Some(lit_str.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cxx_qt_gen::TempDir;
+    use std::fs;
+
+    /// A crate in a temporary directory, with the given files relative to its Cargo.toml
+    struct Fixture {
+        manifest_dir: TempDir,
+    }
+
+    impl Fixture {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let manifest_dir = TempDir::new(&format!("cxx-qt-build-discovery-{name}"));
+            for (path, contents) in files {
+                let path = manifest_dir.path().join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, contents).unwrap();
+            }
+            Self { manifest_dir }
+        }
+
+        fn discover(&self) -> Vec<PathBuf> {
+            let mut paths: Vec<PathBuf> =
+                discover_bridges(self.manifest_dir.path(), Path::new("src/lib.rs"))
+                    .into_iter()
+                    .map(|bridge| bridge.path)
+                    .collect();
+            paths.sort();
+            paths
+        }
+    }
+
+    const BRIDGE: &str = "#[cxx::bridge] mod ffi {}";
+
+    #[test]
+    fn test_discover_inline_module() {
+        let fixture = Fixture::new(
+            "inline",
+            &[("src/lib.rs", "mod a { mod b; }"), ("src/a/b.rs", BRIDGE)],
+        );
+        assert_eq!(fixture.discover(), vec![PathBuf::from("src/a/b.rs")]);
+    }
+
+    #[test]
+    fn test_discover_path_attribute() {
+        let fixture = Fixture::new(
+            "path",
+            &[
+                (
+                    "src/lib.rs",
+                    r#"#[path = "other/file.rs"] mod a; #[path = "inline"] mod b { mod c; }"#,
+                ),
+                ("src/other/file.rs", "mod child;"),
+                ("src/other/child.rs", BRIDGE),
+                ("src/inline/c.rs", BRIDGE),
+            ],
+        );
+        assert_eq!(
+            fixture.discover(),
+            vec![
+                PathBuf::from("src/inline/c.rs"),
+                PathBuf::from("src/other/child.rs")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discover_mod_rs() {
+        let fixture = Fixture::new(
+            "mod_rs",
+            &[
+                ("src/lib.rs", "mod foo;"),
+                ("src/foo/mod.rs", "mod bar;"),
+                ("src/foo/bar.rs", BRIDGE),
+            ],
+        );
+        assert_eq!(fixture.discover(), vec![PathBuf::from("src/foo/bar.rs")]);
+    }
+
+    #[test]
+    fn test_discover_path_attribute_cycle() {
+        let fixture = Fixture::new(
+            "cycle",
+            &[
+                ("src/lib.rs", r#"#[path = "a.rs"] mod a;"#),
+                (
+                    "src/a.rs",
+                    r#"#[path = "../src/lib.rs"] mod root; #[cxx::bridge] mod ffi {}"#,
+                ),
+            ],
+        );
+        assert_eq!(fixture.discover(), vec![PathBuf::from("src/a.rs")]);
+    }
+
+    #[test]
+    fn test_discover_qml_elements() {
+        let fixture = Fixture::new(
+            "qml",
+            &[
+                ("src/lib.rs", "mod object;"),
+                (
+                    "src/object.rs",
+                    r#"
+                    #[cxx_qt::bridge]
+                    mod qobject {
+                        extern "RustQt" {
+                            #[qobject]
+                            #[qml_element]
+                            type MyObject = super::MyObjectRust;
+                        }
+                    }
+                    "#,
+                ),
+            ],
+        );
+        let bridges = discover_bridges(fixture.manifest_dir.path(), Path::new("src/lib.rs"));
+        assert_eq!(bridges.len(), 1);
+        assert!(bridges[0].has_qml_elements);
+    }
+}
@@ -0,0 +1,316 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains helpers for exporting the generated headers and library
+//! so that they can be consumed by C++ build systems other than Cargo.
+
+use std::{
+    env,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// The details of the library which are described by the export
+pub(crate) struct Export<'a> {
+    /// The directory to write the include tree, pkg-config file and CMake package into
+    pub directory: &'a Path,
+    /// The directory that Cargo writes the static library of the crate into,
+    /// if it is not the artifact directory found from `OUT_DIR`
+    pub library_directory: Option<&'a Path>,
+    /// The root of the headers that have been written during the build
+    pub header_root: &'a Path,
+    /// The major version of Qt that has been linked
+    pub qt_version_major: u32,
+    /// The Qt modules that are required, without the `Qt` prefix, sorted so that the output is stable
+    pub qt_modules: Vec<String>,
+    /// Any defines that consumers of the headers need, sorted so that the output is stable
+    pub defines: Vec<String>,
+}
+
+impl Export<'_> {
+    /// Write the include tree, pkg-config file and CMake config package
+    pub fn write(&self) {
+        let include_dir = self.directory.join("include");
+        copy_headers(self.header_root, &include_dir);
+
+        let package_name = env::var("CARGO_PKG_NAME").unwrap();
+        let package_version = env::var("CARGO_PKG_VERSION").unwrap();
+        let library_directory = self.library_directory.map_or_else(
+            || {
+                let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+                artifact_directory(&out_dir).unwrap_or_else(|| {
+                    panic!(
+                        "Could not find the Cargo artifact directory from OUT_DIR {}, use CxxQtBuilder::export_library_directory",
+                        out_dir.display()
+                    )
+                })
+            },
+            Path::to_path_buf,
+        );
+        let library_path = static_library_path(&library_directory);
+        // A Rust static library always needs the system libraries that the standard library uses
+        let system_libs = if env::var("CARGO_CFG_TARGET_FAMILY").as_deref() == Ok("unix") {
+            "-lpthread -ldl -lm"
+        } else {
+            ""
+        };
+
+        let pkg_config_dir = self.directory.join("lib").join("pkgconfig");
+        std::fs::create_dir_all(&pkg_config_dir)
+            .expect("Could not create pkg-config export directory");
+        let mut pkg_config = File::create(pkg_config_dir.join(format!("{package_name}.pc")))
+            .expect("Could not create pkg-config file");
+        write!(
+            pkg_config,
+            "{}",
+            self.pkg_config(&package_name, &package_version, &library_path, system_libs)
+        )
+        .expect("Could not write pkg-config file");
+
+        let cmake_dir = self.directory.join("lib").join("cmake").join(&package_name);
+        std::fs::create_dir_all(&cmake_dir).expect("Could not create CMake export directory");
+        let mut cmake_config = File::create(cmake_dir.join(format!("{package_name}Config.cmake")))
+            .expect("Could not create CMake config file");
+        write!(
+            cmake_config,
+            "{}",
+            self.cmake_config(&package_name, &library_path)
+        )
+        .expect("Could not write CMake config file");
+        let mut cmake_config_version =
+            File::create(cmake_dir.join(format!("{package_name}ConfigVersion.cmake")))
+                .expect("Could not create CMake config version file");
+        write!(
+            cmake_config_version,
+            "{}",
+            cmake_config_version_contents(&package_version)
+        )
+        .expect("Could not write CMake config version file");
+    }
+
+    fn pkg_config(
+        &self,
+        package_name: &str,
+        package_version: &str,
+        library_path: &Path,
+        system_libs: &str,
+    ) -> String {
+        let qt_version_major = self.qt_version_major;
+        let requires = self
+            .qt_modules
+            .iter()
+            .map(|module| format!("Qt{qt_version_major}{module}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let defines = self
+            .defines
+            .iter()
+            .map(|define| format!(" -D{define}"))
+            .collect::<String>();
+        format!(
+            r#"prefix=${{pcfiledir}}/../..
+includedir=${{prefix}}/include
+
+Name: {package_name}
+Description: C++ headers and static library generated by CXX-Qt for {package_name}
+Version: {package_version}
+Requires: {requires}
+Cflags: -I${{includedir}} -I${{includedir}}/cxx-qt-gen{defines}
+Libs: {libs}
+"#,
+            libs = format!("{} {system_libs}", library_path.display()).trim_end()
+        )
+    }
+
+    fn cmake_config(&self, package_name: &str, library_path: &Path) -> String {
+        let qt_version_major = self.qt_version_major;
+        let components = self.qt_modules.join(" ");
+        let link_libraries = self
+            .qt_modules
+            .iter()
+            .map(|module| format!("Qt{qt_version_major}::{module};"))
+            .collect::<String>();
+        let defines = self.defines.join(";");
+        format!(
+            r#"# Generated by CXX-Qt for {package_name}
+include(CMakeFindDependencyMacro)
+find_dependency(Qt{qt_version_major} COMPONENTS {components})
+find_dependency(Threads)
+
+get_filename_component(_CXXQT_EXPORT_PREFIX "${{CMAKE_CURRENT_LIST_DIR}}/../../.." ABSOLUTE)
+
+if(NOT EXISTS "{library_path}")
+    message(FATAL_ERROR "The static library {library_path} of {package_name} does not exist, build the crate with Cargo first")
+endif()
+
+if(NOT TARGET {package_name}::{package_name})
+    add_library({package_name}::{package_name} STATIC IMPORTED)
+    set_target_properties({package_name}::{package_name} PROPERTIES
+        IMPORTED_LOCATION "{library_path}"
+        INTERFACE_INCLUDE_DIRECTORIES "${{_CXXQT_EXPORT_PREFIX}}/include;${{_CXXQT_EXPORT_PREFIX}}/include/cxx-qt-gen"
+        INTERFACE_COMPILE_DEFINITIONS "{defines}"
+        INTERFACE_LINK_LIBRARIES "{link_libraries}Threads::Threads;${{CMAKE_DL_LIBS}}"
+    )
+endif()
+
+unset(_CXXQT_EXPORT_PREFIX)
+"#,
+            library_path = library_path.display().to_string().replace('\\', "/")
+        )
+    }
+}
+
+fn cmake_config_version_contents(package_version: &str) -> String {
+    format!(
+        r#"set(PACKAGE_VERSION "{package_version}")
+
+if(PACKAGE_FIND_VERSION VERSION_GREATER PACKAGE_VERSION)
+    set(PACKAGE_VERSION_COMPATIBLE FALSE)
+else()
+    set(PACKAGE_VERSION_COMPATIBLE TRUE)
+    if(PACKAGE_FIND_VERSION STREQUAL PACKAGE_VERSION)
+        set(PACKAGE_VERSION_EXACT TRUE)
+    endif()
+endif()
+"#
+    )
+}
+
+/// Copy all of the headers within the given directory into the include directory,
+/// keeping the relative paths so that includes such as `cxx-qt/type.h` or `rust/cxx.h` still work.
+fn copy_headers(from: &Path, to: &Path) {
+    for entry in std::fs::read_dir(from).expect("Could not read header directory") {
+        let path = entry.expect("Could not read header directory entry").path();
+        if path.is_dir() {
+            copy_headers(&path, &to.join(path.file_name().unwrap()));
+        } else if path.extension().is_some_and(|extension| extension == "h") {
+            std::fs::create_dir_all(to).expect("Could not create export include directory");
+            std::fs::copy(&path, to.join(path.file_name().unwrap()))
+                .expect("Could not copy header into export include directory");
+        }
+    }
+}
+
+/// The directory of the artifacts of the profile being built, found from the `OUT_DIR` of the build script
+///
+/// Cargo places `OUT_DIR` at `<profile directory>/build/<package>-<hash>/out`, where the profile directory
+/// is `<target directory>/[<target triple>/]<profile>`. So this follows `CARGO_TARGET_DIR`, workspaces,
+/// `--target`, and custom profiles.
+fn artifact_directory(out_dir: &Path) -> Option<PathBuf> {
+    let build_dir = out_dir.parent()?.parent()?;
+    if out_dir.file_name()? != "out" || build_dir.file_name()? != "build" {
+        return None;
+    }
+    build_dir.parent().map(Path::to_path_buf)
+}
+
+/// The path of the static library that Cargo builds for this crate into the given directory.
+///
+/// Build scripts run before the library is built, so it does not exist yet.
+/// The CMake package checks that the library exists when it is found.
+fn static_library_path(library_directory: &Path) -> PathBuf {
+    let library_name = env::var("CARGO_PKG_NAME").unwrap().replace('-', "_");
+    let file_name = if env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("msvc") {
+        format!("{library_name}.lib")
+    } else {
+        format!("lib{library_name}.a")
+    };
+    library_directory.join(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> Export<'static> {
+        Export {
+            directory: Path::new("/export"),
+            library_directory: None,
+            header_root: Path::new("/out/include"),
+            qt_version_major: 6,
+            qt_modules: vec!["Core".to_owned(), "Qml".to_owned()],
+            defines: vec!["A=1".to_owned(), "B".to_owned()],
+        }
+    }
+
+    #[test]
+    fn test_pkg_config() {
+        assert_eq!(
+            export().pkg_config(
+                "my-crate",
+                "0.1.0",
+                Path::new("/target/release/libmy_crate.a"),
+                "-lpthread -ldl -lm"
+            ),
+            r#"prefix=${pcfiledir}/../..
+includedir=${prefix}/include
+
+Name: my-crate
+Description: C++ headers and static library generated by CXX-Qt for my-crate
+Version: 0.1.0
+Requires: Qt6Core Qt6Qml
+Cflags: -I${includedir} -I${includedir}/cxx-qt-gen -DA=1 -DB
+Libs: /target/release/libmy_crate.a -lpthread -ldl -lm
+"#
+        );
+    }
+
+    #[test]
+    fn test_cmake_config() {
+        assert_eq!(
+            export().cmake_config("my-crate", Path::new("/target/release/libmy_crate.a")),
+            r#"# Generated by CXX-Qt for my-crate
+include(CMakeFindDependencyMacro)
+find_dependency(Qt6 COMPONENTS Core Qml)
+find_dependency(Threads)
+
+get_filename_component(_CXXQT_EXPORT_PREFIX "${CMAKE_CURRENT_LIST_DIR}/../../.." ABSOLUTE)
+
+if(NOT EXISTS "/target/release/libmy_crate.a")
+    message(FATAL_ERROR "The static library /target/release/libmy_crate.a of my-crate does not exist, build the crate with Cargo first")
+endif()
+
+if(NOT TARGET my-crate::my-crate)
+    add_library(my-crate::my-crate STATIC IMPORTED)
+    set_target_properties(my-crate::my-crate PROPERTIES
+        IMPORTED_LOCATION "/target/release/libmy_crate.a"
+        INTERFACE_INCLUDE_DIRECTORIES "${_CXXQT_EXPORT_PREFIX}/include;${_CXXQT_EXPORT_PREFIX}/include/cxx-qt-gen"
+        INTERFACE_COMPILE_DEFINITIONS "A=1;B"
+        INTERFACE_LINK_LIBRARIES "Qt6::Core;Qt6::Qml;Threads::Threads;${CMAKE_DL_LIBS}"
+    )
+endif()
+
+unset(_CXXQT_EXPORT_PREFIX)
+"#
+        );
+    }
+
+    #[test]
+    fn test_artifact_directory() {
+        assert_eq!(
+            artifact_directory(Path::new(
+                "/workspace/target/release/build/my-crate-1234/out"
+            )),
+            Some(PathBuf::from("/workspace/target/release"))
+        );
+        assert_eq!(
+            artifact_directory(Path::new(
+                "/custom/x86_64-unknown-linux-gnu/my-profile/build/my-crate-1234/out"
+            )),
+            Some(PathBuf::from("/custom/x86_64-unknown-linux-gnu/my-profile"))
+        );
+        assert_eq!(artifact_directory(Path::new("/tmp/out")), None);
+        assert_eq!(
+            artifact_directory(Path::new("/target/release/other/my-crate-1234/out")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cmake_config_version() {
+        let contents = cmake_config_version_contents("1.2.3");
+        assert!(contents.starts_with("set(PACKAGE_VERSION \"1.2.3\")\n"));
+    }
+}
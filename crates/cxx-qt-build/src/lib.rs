@@ -11,16 +11,26 @@
 //! for CXX-Qt or CXX macros and generate any resulting C++ code. It also builds
 //! the C++ code into a binary with any cxx-qt-lib code and Qt linked.
 
+mod dependencies;
+use dependencies::{extend_unique, Dependencies};
+
 mod diagnostics;
 use diagnostics::{Diagnostic, GeneratedError};
 
+mod discovery;
+use discovery::discover_bridges;
+
+mod export;
+use export::Export;
+
 mod opts;
+pub use opts::BridgeDiscoveryOpts;
 pub use opts::CxxQtBuildersOpts;
 pub use opts::QObjectHeaderOpts;
 
 mod qml_modules;
 use qml_modules::OwningQmlModule;
-pub use qml_modules::QmlModule;
+pub use qml_modules::{QmlApiDescriptionFormat, QmlModule};
 
 pub use qt_build_utils::MocArguments;
 use quote::ToTokens;
@@ -33,8 +43,9 @@ use std::{
 };
 
 use cxx_qt_gen::{
-    parse_qt_file, write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
-    GeneratedRustBlocks, Parser,
+    bridge_hash, bridge_hash_path, parse_qt_file, write_api_json, write_api_markdown,
+    write_bridge_hash, write_cpp, write_rust, ApiDescription, CfgSet, CppFragment, CxxQtItem,
+    GeneratedCppBlocks, GeneratedRustBlocks, Parser, TypeRegistry,
 };
 
 // TODO: we need to eventually support having multiple modules defined in a single file. This
@@ -49,17 +60,27 @@ struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
     qobject: Option<PathBuf>,
     qobject_header: Option<PathBuf>,
+    /// The description of the QObjects and QEnums of the bridge, including their doc comments
+    api_description: Option<ApiDescription>,
 }
 
 struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    /// The hash of the generated Rust code, which the cxx_qt::bridge macro checks against
+    hash: Option<u64>,
+    /// The description of the QObjects and QEnums of the bridge, including their doc comments
+    api_description: Option<ApiDescription>,
 }
 
 impl GeneratedCpp {
     /// Generate QObject and cxx header/source C++ file contents
-    pub fn new(rust_file_path: impl AsRef<Path>) -> Result<Self, Diagnostic> {
+    pub fn new(
+        rust_file_path: impl AsRef<Path>,
+        cfg_set: &CfgSet,
+        type_registry: &TypeRegistry,
+    ) -> Result<Self, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
         let rust_file_path = rust_file_path.as_ref();
@@ -69,6 +90,8 @@ impl GeneratedCpp {
             .map_err(to_diagnostic)?;
 
         let mut cxx_qt = None;
+        let mut hash = None;
+        let mut api_description = None;
         let mut file_ident: String = "".to_owned();
         let mut tokens = proc_macro2::TokenStream::new();
 
@@ -112,7 +135,13 @@ impl GeneratedCpp {
                             rust_file_path.display());
                     }
 
-                    let parser = Parser::from(m.clone())
+                    // The C++ is generated from the bridge without its inactive items
+                    let mut stripped = m.clone();
+                    cfg_set
+                        .strip(&mut stripped)
+                        .map_err(GeneratedError::from)
+                        .map_err(to_diagnostic)?;
+                    let parser = Parser::from_with_type_registry(stripped, type_registry)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
                     let generated_cpp = GeneratedCppBlocks::from(&parser)
@@ -121,12 +150,24 @@ impl GeneratedCpp {
                     // TODO: we'll have to extend the C++ data here rather than overwriting
                     // assuming we share the same file
                     cxx_qt = Some(write_cpp(&generated_cpp));
+                    api_description = Some(
+                        ApiDescription::from(&parser)
+                            .map_err(GeneratedError::from)
+                            .map_err(to_diagnostic)?,
+                    );
+
+                    file_ident = parser.cxx_file_stem.clone();
 
+                    // The Rust is generated from the whole bridge like the cxx_qt::bridge macro,
+                    // keeping the #[cfg] attributes which cxx_gen then evaluates with the cfg set
+                    let parser = Parser::from_with_type_registry(m.clone(), type_registry)
+                        .map_err(GeneratedError::from)
+                        .map_err(to_diagnostic)?;
                     let generated_rust = GeneratedRustBlocks::from(&parser)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
                     let rust_tokens = write_rust(&generated_rust);
-                    file_ident = parser.cxx_file_stem.clone();
+                    hash = Some(bridge_hash(&rust_tokens));
 
                     // We need to do this and can't rely on the macro, as we need to generate the
                     // CXX bridge Rust code that is then fed into the cxx_gen generation.
@@ -138,7 +179,8 @@ impl GeneratedCpp {
             }
         }
 
-        let opt = cxx_gen::Opt::default();
+        let mut opt = cxx_gen::Opt::default();
+        opt.cfg_evaluator = Box::new(CxxQtCfgEvaluator(cfg_set.clone()));
         let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
@@ -147,6 +189,8 @@ impl GeneratedCpp {
             cxx_qt,
             cxx,
             file_ident,
+            hash,
+            api_description,
         })
     }
 
@@ -167,6 +211,7 @@ impl GeneratedCpp {
             plain_cpp: PathBuf::new(),
             qobject: None,
             qobject_header: None,
+            api_description: self.api_description,
         };
         if let Some(cxx_qt_generated) = &self.cxx_qt {
             let header_path = PathBuf::from(format!(
@@ -181,11 +226,24 @@ impl GeneratedCpp {
                 CppFragment::Header(header) => header,
                 CppFragment::Source(_) => panic!("Unexpected call for source fragment."),
             };
+            if let Some(hash) = self.hash {
+                writeln!(header, "// CXX-Qt bridge hash: {hash:#018x}")
+                    .expect("Could not write cxx-qt header file");
+            }
             header
                 .write_all(header_generated.as_bytes())
                 .expect("Could not write cxx-qt header file");
             cpp_file_paths.qobject_header = Some(header_path);
 
+            // The cxx_qt::bridge macro includes the hash to check that it matches its own
+            if let Some(hash) = self.hash {
+                write_bridge_hash(
+                    bridge_hash_path(env::var("OUT_DIR").unwrap(), &self.file_ident),
+                    hash,
+                )
+                .expect("Could not write cxx-qt bridge hash file");
+            }
+
             let cpp_path = PathBuf::from(format!(
                 "{}/{}.cxxqt.cpp",
                 cpp_directory.display(),
@@ -226,10 +284,51 @@ impl GeneratedCpp {
     }
 }
 
+/// Evaluates the #[cfg] attributes of the CXX bridges with the [CfgSet] of the build script
+struct CxxQtCfgEvaluator(CfgSet);
+
+impl cxx_gen::CfgEvaluator for CxxQtCfgEvaluator {
+    fn eval(&self, name: &str, value: Option<&str>) -> cxx_gen::CfgResult {
+        match self.0.eval_cfg(name, value) {
+            Ok(true) => cxx_gen::CfgResult::True,
+            Ok(false) => cxx_gen::CfgResult::False,
+            Err(msg) => cxx_gen::CfgResult::Undetermined { msg },
+        }
+    }
+}
+
+/// Collect the types declared by the CXX-Qt bridges in the given Rust files
+///
+/// Bridges that fail to parse are skipped here, their errors are reported when generating.
+fn collect_type_registry<'a>(
+    rs_source: impl Iterator<Item = &'a PathBuf>,
+    cfg_set: &CfgSet,
+) -> TypeRegistry {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    let mut type_registry = TypeRegistry::default();
+    for rs_path in rs_source {
+        let path = format!("{manifest_dir}/{}", rs_path.display());
+        if let Ok(file) = parse_qt_file(&path) {
+            for item in file.items {
+                if let CxxQtItem::CxxQt(mut m) = item {
+                    if cfg_set.strip(&mut m).is_ok() {
+                        let _ = type_registry.register(m);
+                    }
+                }
+            }
+        }
+    }
+
+    type_registry
+}
+
 /// Generate C++ files from a given list of Rust files, returning the generated paths
 fn generate_cxxqt_cpp_files(
     rs_source: &[impl AsRef<Path>],
     header_dir: impl AsRef<Path>,
+    cfg_set: &CfgSet,
+    type_registry: &TypeRegistry,
 ) -> Vec<GeneratedCppFilePaths> {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
 
@@ -239,7 +338,7 @@ fn generate_cxxqt_cpp_files(
         let path = format!("{manifest_dir}/{}", rs_path.as_ref().display());
         println!("cargo:rerun-if-changed={path}");
 
-        let generated_code = match GeneratedCpp::new(&path) {
+        let generated_code = match GeneratedCpp::new(&path, cfg_set, type_registry) {
             Ok(v) => v,
             Err(diagnostic) => {
                 diagnostic.report();
@@ -297,6 +396,16 @@ fn panic_duplicate_file_and_qml_module(
 /// for the Qt installation found by the C++ build system. This ensures that the C++ build system and
 /// [CxxQtBuilder] link to the same installation of Qt.
 ///
+/// For C++ build systems which cannot set environment variables for Cargo, such as Meson or plain Make,
+/// use [CxxQtBuilder::export] to write a self-contained include tree together with a pkg-config file
+/// and a CMake config package describing the static library and the Qt modules it requires.
+///
+/// To use the QObjects, QEnums and QML modules of another crate built with [CxxQtBuilder], give that
+/// crate a [`links` key](https://doc.rust-lang.org/cargo/reference/build-scripts.html#the-links-manifest-key)
+/// in its Cargo.toml and depend on it directly. [CxxQtBuilder] then adds its generated headers to the
+/// include paths, its types to those which bridges can import with `use`, its QML import path to qmlcachegen,
+/// and the metatypes of its QObjects as foreign types for qmltyperegistrar.
+///
 /// Under the hood, [CxxQtBuilder] uses [cc::Build], which allows compiling aditional C++ files as well.
 /// Refer to [CxxQtBuilder::cc_builder] for details.
 ///
@@ -311,6 +420,10 @@ pub struct CxxQtBuilder {
     qml_modules: Vec<OwningQmlModule>,
     cc_builder: cc::Build,
     extra_defines: HashSet<String>,
+    export_dir: Option<PathBuf>,
+    export_library_dir: Option<PathBuf>,
+    qml_api_descriptions: Vec<(QmlApiDescriptionFormat, PathBuf)>,
+    bridge_discovery: Option<BridgeDiscoveryOpts>,
 }
 
 impl CxxQtBuilder {
@@ -326,6 +439,10 @@ impl CxxQtBuilder {
             qml_modules: vec![],
             cc_builder: cc::Build::new(),
             extra_defines: HashSet::new(),
+            export_dir: None,
+            export_library_dir: None,
+            qml_api_descriptions: vec![],
+            bridge_discovery: None,
         }
     }
 
@@ -348,6 +465,56 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Discover the files containing `#[cxx_qt::bridge]` or `#[cxx::bridge]` modules by following the
+    /// `mod` declarations of the crate, instead of specifying each of them with [CxxQtBuilder::file].
+    ///
+    /// Discovered files that are not already specified with [CxxQtBuilder::file] or in a [QmlModule]
+    /// are built as if they were passed to [CxxQtBuilder::file]. A warning is emitted for discovered
+    /// bridges containing a `#[qml_element]`, as these need to be added to the `rust_files` of a [QmlModule].
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::{BridgeDiscoveryOpts, CxxQtBuilder};
+    /// CxxQtBuilder::new()
+    ///     .discover_bridges(BridgeDiscoveryOpts::default().deny("src/legacy"))
+    ///     .build();
+    /// ```
+    pub fn discover_bridges(mut self, opts: BridgeDiscoveryOpts) -> Self {
+        self.bridge_discovery = Some(opts);
+        self
+    }
+
+    /// Register the files found by [CxxQtBuilder::discover_bridges]
+    fn register_discovered_bridges(&mut self, opts: BridgeDiscoveryOpts) {
+        let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let root = opts.root.clone().unwrap_or_else(|| {
+            if manifest_dir.join("src/lib.rs").exists() {
+                PathBuf::from("src/lib.rs")
+            } else {
+                PathBuf::from("src/main.rs")
+            }
+        });
+
+        for bridge in discover_bridges(&manifest_dir, &root) {
+            if !opts.is_allowed(&bridge.path)
+                || self.rust_sources.contains(&bridge.path)
+                || self
+                    .qml_modules
+                    .iter()
+                    .any(|qml_module| qml_module.rust_files.contains(&bridge.path))
+            {
+                continue;
+            }
+
+            if bridge.has_qml_elements {
+                println!(
+                    "cargo:warning=CXX-Qt bridge {} contains a #[qml_element] but is not part of a QML module. Add it to the rust_files of a QmlModule, or deny it in the BridgeDiscoveryOpts.",
+                    bridge.path.display()
+                );
+            }
+            self.rust_sources.push(bridge.path);
+        }
+    }
+
     /// Include files listed in a .qrc file into the binary
     /// with [Qt's resource system](https://doc.qt.io/qt-6/resources.html).
     /// ```no_run
@@ -443,6 +610,91 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Export the generated library for consumption by C++ build systems other than Cargo.
+    ///
+    /// After building, the given directory contains
+    /// - `include/` with the generated headers, the `cxx-qt/*.h` and `rust/cxx.h` headers
+    ///   and any headers from [CxxQtBuildersOpts], such as those of cxx-qt-lib
+    /// - `lib/pkgconfig/<package>.pc` for pkg-config based build systems such as Meson or Make
+    /// - `lib/cmake/<package>/<package>Config.cmake` providing the `<package>::<package>` imported target
+    ///
+    /// Both describe the static library that Cargo builds for the crate, so the crate should have
+    /// `crate-type = ["staticlib"]`, and the Qt modules which need to be linked.
+    /// The library is expected in the directory of the profile being built, which is found from `OUT_DIR`,
+    /// use [CxxQtBuilder::export_library_directory] if it is copied elsewhere.
+    /// Relative paths are treated as relative to the path of your crate's Cargo.toml file.
+    ///
+    /// The supported API of the headers is the public Qt interface of each generated `QObject` in
+    /// `cxx-qt-gen/<file>.cxxqt.h`, its constructors, properties, invokables, signals and enums,
+    /// and the types of `rust/cxx.h` and `cxx-qt-lib`. The `cxx-qt-gen/<file>.cxx.h` headers,
+    /// the `cxx-qt/*.h` headers and anything in the `rust::cxxqt1` namespace are implementation details
+    /// which are only included by the generated headers and may change between versions.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/lib.rs")
+    ///     .export("export")
+    ///     .build();
+    /// ```
+    pub fn export(mut self, directory: impl AsRef<Path>) -> Self {
+        self.export_dir = Some(Self::relative_to_manifest(directory.as_ref()));
+        self
+    }
+
+    /// Override the directory containing the static library described by [CxxQtBuilder::export]
+    ///
+    /// By default this is the directory that Cargo writes the artifacts of the profile into,
+    /// for example `target/release` or `target/<triple>/debug` with `--target`.
+    /// Relative paths are treated as relative to the path of your crate's Cargo.toml file.
+    pub fn export_library_directory(mut self, directory: impl AsRef<Path>) -> Self {
+        self.export_library_dir = Some(Self::relative_to_manifest(directory.as_ref()));
+        self
+    }
+
+    fn relative_to_manifest(path: &Path) -> PathBuf {
+        if path.is_relative() {
+            PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(path)
+        } else {
+            path.to_path_buf()
+        }
+    }
+
+    /// Write a description of the QML types of each QML module, including their doc comments,
+    /// in the given format to `<uri>.md` or `<uri>.json` in the given directory
+    ///
+    /// This can be called multiple times to write multiple formats.
+    /// Relative paths are treated as relative to the path of your crate's Cargo.toml file.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::{CxxQtBuilder, QmlApiDescriptionFormat, QmlModule};
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &["qml/main.qml"],
+    ///         ..Default::default()
+    ///     })
+    ///     .qml_api_description(QmlApiDescriptionFormat::Markdown, "docs")
+    ///     .build();
+    /// ```
+    pub fn qml_api_description(
+        mut self,
+        format: QmlApiDescriptionFormat,
+        directory: impl AsRef<Path>,
+    ) -> Self {
+        let directory = directory.as_ref();
+        self.qml_api_descriptions.push((
+            format,
+            if directory.is_relative() {
+                PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(directory)
+            } else {
+                directory.to_path_buf()
+            },
+        ));
+        self
+    }
+
     /// Build with the given extra options
     pub fn with_opts(mut self, opts: CxxQtBuildersOpts) -> Self {
         let header_root = header_root();
@@ -475,10 +727,18 @@ impl CxxQtBuilder {
         // Ensure that the linker is setup correctly for Cargo builds
         qt_build_utils::setup_linker();
 
+        if let Some(opts) = self.bridge_discovery.take() {
+            self.register_discovered_bridges(opts);
+        }
+
         let header_root = header_root();
         let generated_header_dir = format!("{header_root}/cxx-qt-gen");
 
-        let mut qtbuild = qt_build_utils::QtBuild::new(self.qt_modules.into_iter().collect())
+        // Find any CXX-Qt crates that this crate depends on
+        let dependencies = Dependencies::find();
+
+        let qt_modules: Vec<String> = self.qt_modules.into_iter().collect();
+        let mut qtbuild = qt_build_utils::QtBuild::new(qt_modules.clone())
             .expect("Could not find Qt installation");
         qtbuild.cargo_link_libraries(&mut self.cc_builder);
 
@@ -489,6 +749,17 @@ impl CxxQtBuilder {
             qtbuild.version().major
         );
 
+        // Evaluate #[cfg] attributes in bridges with the features and target of this crate,
+        // the cxx_qt::bridge macro leaves them to rustc instead
+        let mut cfg_set = CfgSet::from_build_env();
+        cfg_set.insert(
+            "cxxqt_qt_version_major",
+            Some(&qtbuild.version().major.to_string()),
+        );
+        cfg_set
+            .write(CfgSet::path(env::var("OUT_DIR").unwrap()))
+            .expect("Could not write CXX-Qt cfgs");
+
         // Write cxx-qt and cxx headers
         cxx_qt::write_headers(format!("{header_root}/cxx-qt"));
         std::fs::create_dir_all(format!("{header_root}/rust"))
@@ -550,10 +821,44 @@ impl CxxQtBuilder {
             builder.includes(qtbuild.include_paths());
             builder.include(&header_root);
             builder.include(&generated_header_dir);
+            for include_path in &dependencies.include_paths {
+                builder.include(include_path);
+                builder.include(include_path.join("cxx-qt-gen"));
+            }
         }
 
+        // Collect the types of all bridges so that bridges can import types from each other,
+        // the registry is also read by the cxx_qt::bridge macro when the crate is compiled
+        let mut type_registry = collect_type_registry(
+            self.rust_sources.iter().chain(
+                self.qml_modules
+                    .iter()
+                    .flat_map(|qml_module| qml_module.rust_files.iter()),
+            ),
+            &cfg_set,
+        );
+        for path in &dependencies.type_registries {
+            println!("cargo:rerun-if-changed={}", path.display());
+            type_registry.merge(
+                TypeRegistry::read(path)
+                    .expect("Could not read CXX-Qt type registry of dependency"),
+            );
+        }
+        let type_registry_path = TypeRegistry::path(env::var("OUT_DIR").unwrap());
+        type_registry
+            .write(&type_registry_path)
+            .expect("Could not write CXX-Qt type registry");
+
+        // The metatypes of all QObjects, which are exported for the QML modules of dependents
+        let mut metatypes_json = Vec::new();
+
         // Generate files
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &generated_header_dir) {
+        for files in generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &generated_header_dir,
+            &cfg_set,
+            &type_registry,
+        ) {
             self.cc_builder.file(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
                 self.cc_builder.file(&qobject);
@@ -569,19 +874,37 @@ impl CxxQtBuilder {
         {
             let moc_products = qtbuild.moc(&path, moc_arguments);
             self.cc_builder.file(moc_products.cpp);
+            metatypes_json.push(moc_products.metatypes_json);
         }
 
         let mut cc_builder_whole_archive_files_added = false;
 
-        let lib_name = "cxx-qt-generated";
+        // The library names include the package name, as the library search paths
+        // of CXX-Qt crates that are dependencies are also used when linking this crate
+        let package_name = env::var("CARGO_PKG_NAME").unwrap();
+        let lib_name = format!("cxx-qt-generated-{package_name}");
+
+        // QML modules can use the QObjects and import the QML modules of dependencies
+        qtbuild.qml_foreign_types(&dependencies.metatypes_json);
+        qtbuild.qml_import_paths(&dependencies.qml_import_paths);
+        let has_qml_modules = !self.qml_modules.is_empty();
 
         // Bridges for QML modules are handled separately because
         // the metatypes_json generated by moc needs to be passed to qmltyperegistrar
         for qml_module in self.qml_modules {
             let mut qml_metatypes_json = Vec::new();
+            let mut api_description = ApiDescription::default();
 
-            for files in generate_cxxqt_cpp_files(&qml_module.rust_files, &generated_header_dir) {
+            for files in generate_cxxqt_cpp_files(
+                &qml_module.rust_files,
+                &generated_header_dir,
+                &cfg_set,
+                &type_registry,
+            ) {
                 self.cc_builder.file(files.plain_cpp);
+                if let Some(mut description) = files.api_description {
+                    api_description.append(&mut description);
+                }
                 if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header)
                 {
                     self.cc_builder.file(&qobject);
@@ -593,16 +916,36 @@ impl CxxQtBuilder {
                     qml_metatypes_json.push(moc_products.metatypes_json);
                 }
             }
+            metatypes_json.extend(qml_metatypes_json.iter().cloned());
 
             let qml_module_registration_files = qtbuild.register_qml_module(
                 &qml_metatypes_json,
                 &qml_module.uri,
                 qml_module.version_major,
                 qml_module.version_minor,
-                lib_name,
+                &lib_name,
                 &qml_module.qml_files,
                 &qml_module.qrc_files,
             );
+
+            for (format, directory) in &self.qml_api_descriptions {
+                let contents = match format {
+                    QmlApiDescriptionFormat::Markdown => {
+                        write_api_markdown(&api_description, &qml_module.uri)
+                    }
+                    QmlApiDescriptionFormat::Json => {
+                        write_api_json(&api_description, &qml_module.uri)
+                    }
+                };
+                std::fs::create_dir_all(directory)
+                    .expect("Could not create QML API description directory");
+                std::fs::write(
+                    directory.join(format!("{}.{}", qml_module.uri, format.extension())),
+                    contents,
+                )
+                .expect("Could not write QML API description");
+            }
+
             self.cc_builder
                 .file(qml_module_registration_files.qmltyperegistrar);
             self.cc_builder.file(qml_module_registration_files.plugin);
@@ -666,13 +1009,49 @@ impl CxxQtBuilder {
         }
 
         if cc_builder_whole_archive_files_added {
-            cc_builder_whole_archive.compile("qt-static-initializers");
+            cc_builder_whole_archive.compile(&format!("qt-static-initializers-{package_name}"));
         }
 
         // Only compile if we have added files to the builder
         // otherwise we end up with no static library but ask cargo to link to it which causes an error
         if self.cc_builder.get_files().count() > 0 {
-            self.cc_builder.compile(lib_name);
+            self.cc_builder.compile(&lib_name);
+        }
+
+        // Export the headers, QML modules and types to dependents of this crate
+        let mut exported = Dependencies {
+            include_paths: vec![PathBuf::from(&header_root)],
+            qml_import_paths: Vec::new(),
+            metatypes_json,
+            type_registries: vec![type_registry_path],
+        };
+        if has_qml_modules {
+            exported
+                .qml_import_paths
+                .push(PathBuf::from(env::var("OUT_DIR").unwrap()).join("qml_modules"));
+        }
+        extend_unique(&mut exported.include_paths, dependencies.include_paths);
+        extend_unique(
+            &mut exported.qml_import_paths,
+            dependencies.qml_import_paths,
+        );
+        extend_unique(&mut exported.metatypes_json, dependencies.metatypes_json);
+        exported.export();
+
+        if let Some(export_dir) = self.export_dir {
+            let mut qt_modules = qt_modules;
+            qt_modules.sort();
+            let mut defines: Vec<String> = self.extra_defines.into_iter().collect();
+            defines.sort();
+            Export {
+                directory: &export_dir,
+                library_directory: self.export_library_dir.as_deref(),
+                header_root: Path::new(&header_root),
+                qt_version_major: qtbuild.version().major,
+                qt_modules,
+                defines,
+            }
+            .write();
         }
     }
 }
@@ -84,3 +84,52 @@ impl QObjectHeaderOpts {
         }
     }
 }
+
+/// Options for discovering bridges with [CxxQtBuilder::discover_bridges](crate::CxxQtBuilder::discover_bridges)
+///
+/// Paths are relative to the path of your crate's Cargo.toml file,
+/// and a directory allows or denies all of the files within it.
+/// ```
+/// # use cxx_qt_build::BridgeDiscoveryOpts;
+/// BridgeDiscoveryOpts::default()
+///     .allow("src/qobjects")
+///     .deny("src/qobjects/legacy.rs");
+/// ```
+#[derive(Default)]
+pub struct BridgeDiscoveryOpts {
+    pub(crate) root: Option<PathBuf>,
+    pub(crate) allow: Vec<PathBuf>,
+    pub(crate) deny: Vec<PathBuf>,
+}
+
+impl BridgeDiscoveryOpts {
+    /// Set the crate root to start following `mod` declarations from
+    ///
+    /// By default this is `src/lib.rs`, or `src/main.rs` if there is no `src/lib.rs`
+    pub fn root(self, root: impl AsRef<Path>) -> Self {
+        Self {
+            root: Some(root.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    /// Only register bridges in the given file or directory
+    ///
+    /// This can be called multiple times, if it is not called any bridge can be registered
+    pub fn allow(mut self, path: impl AsRef<Path>) -> Self {
+        self.allow.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Do not register bridges in the given file or directory, this takes precedence over [Self::allow]
+    pub fn deny(mut self, path: impl AsRef<Path>) -> Self {
+        self.deny.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Whether a bridge in the given file should be registered
+    pub(crate) fn is_allowed(&self, path: &Path) -> bool {
+        (self.allow.is_empty() || self.allow.iter().any(|allow| path.starts_with(allow)))
+            && !self.deny.iter().any(|deny| path.starts_with(deny))
+    }
+}
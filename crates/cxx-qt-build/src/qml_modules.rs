@@ -80,3 +80,22 @@ impl<A: AsRef<Path>, B: AsRef<Path>> From<QmlModule<'_, A, B>> for OwningQmlModu
         }
     }
 }
+
+/// The format of the API description of the QML types of a QML module,
+/// written with [crate::CxxQtBuilder::qml_api_description]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QmlApiDescriptionFormat {
+    /// A Markdown document, written to `<uri>.md`
+    Markdown,
+    /// A JSON document, written to `<uri>.json`
+    Json,
+}
+
+impl QmlApiDescriptionFormat {
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
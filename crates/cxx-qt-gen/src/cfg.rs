@@ -0,0 +1,444 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Evaluation of `#[cfg]` and `#[cfg_attr]` attributes inside a bridge
+//!
+//! cxx-qt-build collects the active cfgs, such as Cargo features and the target, from the
+//! environment of the build script. It removes the inactive items from the bridge before
+//! generating the C++ code, and evaluates the cfgs of the CXX bridge with them. The
+//! `cxx_qt::bridge` macro does not evaluate any cfgs, instead the `#[cfg]` attributes of the
+//! items are added to their generated Rust code, so that rustc evaluates them.
+
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream, Parser as _},
+    punctuated::Punctuated,
+    Attribute, Error, Fields, ForeignItem, Item, ItemMod, Meta, Result, Token,
+};
+
+use crate::syntax::{attribute::item_attrs, expr::expr_to_string, path::path_compare_str};
+
+/// The set of active cfgs, where a cfg is either a name or a name with a value
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CfgSet {
+    cfgs: BTreeSet<(String, Option<String>)>,
+}
+
+impl CfgSet {
+    /// The path of the cfg set relative to `OUT_DIR`
+    pub const FILE_NAME: &'static str = "cxx-qt-gen/cfg.txt";
+
+    /// The path of the cfg set for the given `OUT_DIR`
+    pub fn path(out_dir: impl AsRef<Path>) -> PathBuf {
+        out_dir.as_ref().join(Self::FILE_NAME)
+    }
+
+    /// Collect the active cfgs from the `CARGO_FEATURE_*` and `CARGO_CFG_*`
+    /// environment variables that Cargo sets for build scripts
+    pub fn from_build_env() -> Self {
+        let mut cfg_set = Self::default();
+        for (key, value) in env::vars() {
+            if let Some(feature) = key.strip_prefix("CARGO_FEATURE_") {
+                cfg_set.insert("feature", Some(feature));
+            } else if let Some(name) = key.strip_prefix("CARGO_CFG_") {
+                let name = name.to_lowercase();
+                if value.is_empty() {
+                    cfg_set.insert(&name, None);
+                } else {
+                    // Multiple values are separated by commas, eg target_feature
+                    for value in value.split(',') {
+                        cfg_set.insert(&name, Some(value));
+                    }
+                }
+            }
+        }
+        cfg_set
+    }
+
+    /// Read the cfg set that cxx-qt-build wrote into the `OUT_DIR` of the current crate
+    ///
+    /// This is [None] if the crate is not built with cxx-qt-build.
+    pub fn from_out_dir() -> Option<Self> {
+        Self::read(Self::path(env::var_os("OUT_DIR")?)).ok()
+    }
+
+    /// Read a cfg set from the given file
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut cfg_set = Self::default();
+        for line in fs::read_to_string(path)?.lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((name, value)) => cfg_set.insert(name, Some(value)),
+                None => cfg_set.insert(line, None),
+            }
+        }
+        Ok(cfg_set)
+    }
+
+    /// Write the cfg set to the given file, creating any parent directories
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = fs::File::create(path)?;
+        for (name, value) in &self.cfgs {
+            match value {
+                Some(value) => writeln!(file, "{name}={value}")?,
+                None => writeln!(file, "{name}")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Set a cfg as active, such as `insert("feature", Some("serde"))` or `insert("unix", None)`
+    pub fn insert(&mut self, name: &str, value: Option<&str>) {
+        self.cfgs.insert(normalise(name, value.map(str::to_owned)));
+    }
+
+    /// Evaluate a single cfg, such as `unix` or `feature = "serde"`
+    ///
+    /// This is an error for `test` and `debug_assertions`, as the build script is never built
+    /// with `test`, and can be built with a different `debug_assertions` than the crate.
+    pub fn eval_cfg(&self, name: &str, value: Option<&str>) -> std::result::Result<bool, String> {
+        if matches!(name, "test" | "debug_assertions") {
+            return Err(format!(
+                "cfg({name}) cannot be used in a bridge, as it can differ between the build script and the crate"
+            ));
+        }
+
+        Ok(self
+            .cfgs
+            .contains(&normalise(name, value.map(str::to_owned))))
+    }
+
+    /// Evaluate a cfg predicate, such as the contents of `#[cfg(...)]`
+    pub fn eval(&self, predicate: &Meta) -> Result<bool> {
+        match predicate {
+            Meta::Path(path) => self
+                .eval_cfg(&path_to_name(path)?, None)
+                .map_err(|message| Error::new_spanned(path, message)),
+            Meta::NameValue(name_value) => {
+                let value = expr_to_string(&name_value.value)?;
+                self.eval_cfg(&path_to_name(&name_value.path)?, Some(&value))
+                    .map_err(|message| Error::new_spanned(name_value, message))
+            }
+            Meta::List(list) => {
+                let predicates =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                // All of the predicates are evaluated, so that a cfg which cannot be used is an error
+                // regardless of the other cfgs
+                let values = predicates
+                    .iter()
+                    .map(|predicate| self.eval(predicate))
+                    .collect::<Result<Vec<bool>>>()?;
+                if path_compare_str(&list.path, &["all"]) {
+                    Ok(values.into_iter().all(|value| value))
+                } else if path_compare_str(&list.path, &["any"]) {
+                    Ok(values.into_iter().any(|value| value))
+                } else if path_compare_str(&list.path, &["not"]) && values.len() == 1 {
+                    Ok(!values[0])
+                } else {
+                    Err(Error::new_spanned(list, "Unsupported cfg predicate"))
+                }
+            }
+        }
+    }
+
+    /// Remove the items of a bridge module which are not active for this cfg set
+    ///
+    /// The `#[cfg]` attributes of active items are removed and `#[cfg_attr]` attributes are expanded,
+    /// this applies to the items of the module, and the items of extern blocks, enum variants and struct fields.
+    pub fn strip(&self, module: &mut ItemMod) -> Result<()> {
+        if let Some((_, items)) = &mut module.content {
+            *items = self.filter(std::mem::take(items), |item| match item_attrs(item) {
+                Some(attrs) => self.eval_attrs(attrs),
+                None => Ok(true),
+            })?;
+
+            for item in items.iter_mut() {
+                match item {
+                    Item::ForeignMod(foreign_mod) => {
+                        foreign_mod.items = self
+                            .filter(std::mem::take(&mut foreign_mod.items), |item| {
+                                self.eval_foreign_item(item)
+                            })?;
+                    }
+                    Item::Enum(item_enum) => {
+                        item_enum.variants = self
+                            .filter(std::mem::take(&mut item_enum.variants), |variant| {
+                                self.eval_attrs(&mut variant.attrs)
+                            })?
+                            .into_iter()
+                            .collect();
+                    }
+                    Item::Struct(item_struct) => {
+                        if let Fields::Named(fields) = &mut item_struct.fields {
+                            fields.named = self
+                                .filter(std::mem::take(&mut fields.named), |field| {
+                                    self.eval_attrs(&mut field.attrs)
+                                })?
+                                .into_iter()
+                                .collect();
+                        }
+                    }
+                    _others => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep the elements which are active
+    fn filter<T>(
+        &self,
+        elements: impl IntoIterator<Item = T>,
+        mut is_active: impl FnMut(&mut T) -> Result<bool>,
+    ) -> Result<Vec<T>> {
+        let mut retained = vec![];
+        for mut element in elements {
+            if is_active(&mut element)? {
+                retained.push(element);
+            }
+        }
+        Ok(retained)
+    }
+
+    /// Expand `#[cfg_attr]` attributes and remove `#[cfg]` attributes, returning if they are all active
+    fn eval_attrs(&self, attrs: &mut Vec<Attribute>) -> Result<bool> {
+        let mut expanded = Vec::with_capacity(attrs.len());
+        let mut queue: Vec<Attribute> = std::mem::take(attrs);
+        queue.reverse();
+
+        while let Some(attr) = queue.pop() {
+            if path_compare_str(attr.path(), &["cfg"]) {
+                if !self.eval(&attr.parse_args()?)? {
+                    return Ok(false);
+                }
+            } else if path_compare_str(attr.path(), &["cfg_attr"]) {
+                let CfgAttr {
+                    predicate,
+                    attrs: nested,
+                } = attr.parse_args()?;
+                if self.eval(&predicate)? {
+                    // The expanded attributes can themselves be cfg or cfg_attr
+                    for meta in nested.into_iter().rev() {
+                        queue.push(Attribute {
+                            meta,
+                            ..attr.clone()
+                        });
+                    }
+                }
+            } else {
+                expanded.push(attr);
+            }
+        }
+
+        *attrs = expanded;
+        Ok(true)
+    }
+
+    /// Evaluate the attributes of an item in an extern block, returning if it is active
+    fn eval_foreign_item(&self, item: &mut ForeignItem) -> Result<bool> {
+        match item {
+            ForeignItem::Fn(item) => self.eval_attrs(&mut item.attrs),
+            ForeignItem::Static(item) => self.eval_attrs(&mut item.attrs),
+            ForeignItem::Type(item) => self.eval_attrs(&mut item.attrs),
+            ForeignItem::Macro(item) => self.eval_attrs(&mut item.attrs),
+            // Verbatim items, such as `type T = super::T;`, need their attributes to be parsed
+            ForeignItem::Verbatim(tokens) => {
+                let (mut attrs, rest) =
+                    (|input: ParseStream| -> Result<(Vec<Attribute>, TokenStream)> {
+                        Ok((input.call(Attribute::parse_outer)?, input.parse()?))
+                    })
+                    .parse2(tokens.clone())?;
+                if !self.eval_attrs(&mut attrs)? {
+                    return Ok(false);
+                }
+
+                let mut stripped = TokenStream::new();
+                for attr in attrs {
+                    attr.to_tokens(&mut stripped);
+                }
+                stripped.extend(rest);
+                *tokens = stripped;
+                Ok(true)
+            }
+            _others => Ok(true),
+        }
+    }
+}
+
+/// The contents of a `#[cfg_attr(predicate, attrs...)]` attribute
+pub(crate) struct CfgAttr {
+    pub predicate: Meta,
+    pub attrs: Punctuated<Meta, Token![,]>,
+}
+
+impl Parse for CfgAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let predicate = input.parse()?;
+        input.parse::<Token![,]>()?;
+        Ok(Self {
+            predicate,
+            attrs: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// Feature names are compared with hyphens and underscores as equal and ignoring case,
+/// as Cargo provides them to build scripts as `CARGO_FEATURE_<NAME>`
+fn normalise(name: &str, value: Option<String>) -> (String, Option<String>) {
+    let name = name.to_lowercase();
+    let value = if name == "feature" {
+        value.map(|value| value.to_lowercase().replace('-', "_"))
+    } else {
+        value
+    };
+    (name, value)
+}
+
+fn path_to_name(path: &syn::Path) -> Result<String> {
+    path.get_ident()
+        .map(ToString::to_string)
+        .ok_or_else(|| Error::new_spanned(path, "Expected an identifier in cfg predicate"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::TempDir;
+
+    use syn::parse_quote;
+
+    fn cfg_set() -> CfgSet {
+        let mut cfg_set = CfgSet::default();
+        cfg_set.insert("feature", Some("FEATURE_A"));
+        cfg_set.insert("target_os", Some("linux"));
+        cfg_set.insert("unix", None);
+        cfg_set
+    }
+
+    #[test]
+    fn test_eval() {
+        let cfg_set = cfg_set();
+        assert!(cfg_set
+            .eval(&parse_quote! { feature = "feature-a" })
+            .unwrap());
+        assert!(!cfg_set.eval(&parse_quote! { feature = "b" }).unwrap());
+        assert!(cfg_set.eval(&parse_quote! { unix }).unwrap());
+        assert!(!cfg_set.eval(&parse_quote! { windows }).unwrap());
+        assert!(cfg_set
+            .eval(&parse_quote! { all(unix, target_os = "linux") })
+            .unwrap());
+        assert!(cfg_set.eval(&parse_quote! { any(windows, unix) }).unwrap());
+        assert!(cfg_set.eval(&parse_quote! { not(windows) }).unwrap());
+        assert!(cfg_set.eval(&parse_quote! { all() }).unwrap());
+        assert!(!cfg_set.eval(&parse_quote! { any() }).unwrap());
+        assert!(cfg_set.eval(&parse_quote! { unknown(unix) }).is_err());
+    }
+
+    #[test]
+    fn test_eval_differs_from_build_script() {
+        let mut cfg_set = cfg_set();
+        cfg_set.insert("debug_assertions", None);
+        assert!(cfg_set.eval(&parse_quote! { test }).is_err());
+        assert!(cfg_set.eval(&parse_quote! { not(test) }).is_err());
+        assert!(cfg_set.eval(&parse_quote! { debug_assertions }).is_err());
+        assert!(cfg_set
+            .eval(&parse_quote! { any(unix, debug_assertions) })
+            .is_err());
+    }
+
+    #[test]
+    fn test_read_write() {
+        let cfg_set = cfg_set();
+        let temp_dir = TempDir::new("cxx-qt-gen-cfg");
+        let path = temp_dir.path().join(CfgSet::FILE_NAME);
+        cfg_set.write(&path).unwrap();
+        assert_eq!(CfgSet::read(&path).unwrap(), cfg_set);
+    }
+
+    #[test]
+    fn test_strip() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[qenum(MyObject)]
+                enum MyEnum {
+                    A,
+                    #[cfg(windows)]
+                    B,
+                }
+
+                #[cfg(feature = "b")]
+                extern "RustQt" {
+                    #[qobject]
+                    type Disabled = super::DisabledRust;
+                }
+
+                extern "RustQt" {
+                    #[cfg(windows)]
+                    #[qobject]
+                    type AlsoDisabled = super::AlsoDisabledRust;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[cfg_attr(feature = "feature_a", qproperty(i32, a))]
+                    #[cfg_attr(feature = "b", qproperty(i32, b))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[cfg(not(unix))]
+                    #[qinvokable]
+                    fn disabled(self: &MyObject);
+
+                    #[cfg(unix)]
+                    #[qinvokable]
+                    fn enabled(self: &MyObject);
+                }
+            }
+        };
+        cfg_set().strip(&mut module).unwrap();
+
+        let expected: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[qenum(MyObject)]
+                enum MyEnum {
+                    A
+                }
+
+                extern "RustQt" {}
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, a)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn enabled(self: &MyObject);
+                }
+            }
+        };
+        assert_eq!(
+            module.to_token_stream().to_string(),
+            expected.to_token_stream().to_string()
+        );
+    }
+}
@@ -0,0 +1,422 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::naming::{
+        method::QMethodName, property::QPropertyName, qobject::QObjectName, signals::QSignalName,
+    },
+    naming::{
+        cpp::{syn_type_to_cpp_return_type, syn_type_to_cpp_type},
+        TypeNames,
+    },
+    parser::{
+        parameter::ParsedFunctionParameter, qenum::ParsedQEnum, qobject::ParsedQObject, Parser,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use syn::Result;
+
+/// A description of the API of the QObjects and QEnums of a bridge, including their doc comments
+///
+/// The types are described with their C++ names, as seen from C++ and QML.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ApiDescription {
+    /// The QObjects of the bridge
+    pub qobjects: Vec<QObjectDescription>,
+    /// The QEnums of the bridge which are declared in a namespace rather than a QObject
+    pub qenums: Vec<QEnumDescription>,
+}
+
+/// A description of a QObject
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct QObjectDescription {
+    /// The name of the C++ class
+    pub name: String,
+    /// The C++ namespace of the class, empty if there is none
+    pub namespace: String,
+    /// The C++ base class
+    pub base_class: String,
+    /// The name of the QML element, if the QObject is a `#[qml_element]`
+    pub qml_name: Option<String>,
+    /// Whether the QML element is a `#[qml_singleton]`
+    pub qml_singleton: bool,
+    /// Whether the QML element is `#[qml_uncreatable]`
+    pub qml_uncreatable: bool,
+    /// The lines of the doc comments of the QObject
+    pub docs: Vec<String>,
+    /// The Q_PROPERTYs of the QObject
+    pub properties: Vec<PropertyDescription>,
+    /// The Q_INVOKABLE methods of the QObject
+    pub invokables: Vec<MethodDescription>,
+    /// The Q_SIGNALs of the QObject, excluding those of its properties and base class
+    pub signals: Vec<MethodDescription>,
+    /// The QEnums of the QObject
+    pub qenums: Vec<QEnumDescription>,
+}
+
+impl QObjectDescription {
+    /// The C++ name of the class including its namespace, as used by moc
+    pub fn qualified_name(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}::{}", self.namespace, self.name)
+        }
+    }
+}
+
+/// A description of a Q_PROPERTY
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct PropertyDescription {
+    /// The name of the property
+    pub name: String,
+    /// The C++ type of the property
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub ty: String,
+    /// The lines of the doc comments of the property
+    pub docs: Vec<String>,
+}
+
+/// A description of a Q_INVOKABLE or Q_SIGNAL
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct MethodDescription {
+    /// The C++ name of the method
+    pub name: String,
+    /// The C++ return type of the method, this is `void` for signals
+    pub return_type: String,
+    /// The parameters of the method
+    pub parameters: Vec<ParameterDescription>,
+    /// The lines of the doc comments of the method
+    pub docs: Vec<String>,
+}
+
+/// A description of a parameter of a method
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ParameterDescription {
+    /// The name of the parameter
+    pub name: String,
+    /// The C++ type of the parameter
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub ty: String,
+}
+
+/// A description of a QEnum
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct QEnumDescription {
+    /// The C++ name of the enum
+    pub name: String,
+    /// The C++ namespace of the enum, empty if there is none
+    pub namespace: String,
+    /// Whether the namespace of the enum is registered as a QML element with `qnamespace!`
+    pub qml_namespace: bool,
+    /// The lines of the doc comments of the enum
+    pub docs: Vec<String>,
+    /// The values of the enum
+    pub values: Vec<QEnumValueDescription>,
+}
+
+/// A description of a value of a QEnum
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct QEnumValueDescription {
+    /// The name of the value
+    pub name: String,
+    /// The lines of the doc comments of the value
+    pub docs: Vec<String>,
+}
+
+impl ApiDescription {
+    /// Create an [ApiDescription] from the given [Parser] object
+    pub fn from(parser: &Parser) -> Result<Self> {
+        let type_names = &parser.type_names;
+        let qml_namespaces: Vec<&str> = parser
+            .cxx_qt_data
+            .qnamespaces
+            .iter()
+            .filter(|qnamespace| qnamespace.qml_element)
+            .map(|qnamespace| qnamespace.namespace.as_str())
+            .collect();
+
+        Ok(Self {
+            qobjects: parser
+                .cxx_qt_data
+                .qobjects
+                .values()
+                .map(|qobject| describe_qobject(qobject, type_names))
+                .collect::<Result<_>>()?,
+            qenums: parser
+                .cxx_qt_data
+                .qenums
+                .iter()
+                .map(|qenum| {
+                    let mut description = describe_qenum(qenum, type_names)?;
+                    description.qml_namespace =
+                        qml_namespaces.contains(&description.namespace.as_str());
+                    Ok(description)
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// Append the QObjects and QEnums of another [ApiDescription], eg of another bridge of a QML module
+    pub fn append(&mut self, other: &mut Self) {
+        self.qobjects.append(&mut other.qobjects);
+        self.qenums.append(&mut other.qenums);
+    }
+}
+
+fn describe_qobject(qobject: &ParsedQObject, type_names: &TypeNames) -> Result<QObjectDescription> {
+    let properties = qobject
+        .properties
+        .iter()
+        .map(|property| {
+            Ok(PropertyDescription {
+                name: QPropertyName::from(property).name.cpp.to_string(),
+                ty: syn_type_to_cpp_type(&property.ty, type_names)?,
+                docs: property.docs.clone(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    // Only Q_INVOKABLEs can be used from QML
+    let invokables = qobject
+        .methods
+        .iter()
+        .filter(|method| method.is_qinvokable)
+        .map(|method| {
+            Ok(MethodDescription {
+                name: QMethodName::from(method).name.cpp.to_string(),
+                return_type: syn_type_to_cpp_return_type(&method.method.sig.output, type_names)?
+                    .unwrap_or_else(|| "void".to_owned()),
+                parameters: describe_parameters(&method.parameters, type_names)?,
+                docs: method.docs.clone(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    // Private signals and those of the base class are not part of the API of this QObject
+    let signals = qobject
+        .signals
+        .iter()
+        .filter(|signal| !signal.inherit && !signal.private)
+        .map(|signal| {
+            Ok(MethodDescription {
+                name: QSignalName::from(signal).name.cpp.to_string(),
+                return_type: "void".to_owned(),
+                parameters: describe_parameters(&signal.parameters, type_names)?,
+                docs: signal.docs.clone(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(QObjectDescription {
+        name: QObjectName::from(qobject).cpp_class.cpp.to_string(),
+        namespace: qobject.namespace.clone(),
+        base_class: qobject
+            .base_class
+            .clone()
+            .unwrap_or_else(|| "QObject".to_owned()),
+        qml_name: qobject
+            .qml_metadata
+            .as_ref()
+            .map(|qml_metadata| qml_metadata.name.clone()),
+        qml_singleton: qobject
+            .qml_metadata
+            .as_ref()
+            .is_some_and(|qml_metadata| qml_metadata.singleton),
+        qml_uncreatable: qobject
+            .qml_metadata
+            .as_ref()
+            .is_some_and(|qml_metadata| qml_metadata.uncreatable),
+        docs: qobject.docs.clone(),
+        properties,
+        invokables,
+        signals,
+        qenums: qobject
+            .qenums
+            .iter()
+            .map(|qenum| describe_qenum(qenum, type_names))
+            .collect::<Result<_>>()?,
+    })
+}
+
+fn describe_parameters(
+    parameters: &[ParsedFunctionParameter],
+    type_names: &TypeNames,
+) -> Result<Vec<ParameterDescription>> {
+    parameters
+        .iter()
+        .map(|parameter| {
+            Ok(ParameterDescription {
+                name: parameter.ident.to_string(),
+                ty: syn_type_to_cpp_type(&parameter.ty, type_names)?,
+            })
+        })
+        .collect()
+}
+
+fn describe_qenum(qenum: &ParsedQEnum, type_names: &TypeNames) -> Result<QEnumDescription> {
+    Ok(QEnumDescription {
+        name: type_names.cxx_unqualified(&qenum.ident)?,
+        namespace: type_names.namespace(&qenum.ident)?.unwrap_or_default(),
+        qml_namespace: false,
+        docs: qenum.docs.clone(),
+        values: qenum
+            .variants
+            .iter()
+            .zip(qenum.variant_docs.iter())
+            .map(|(variant, docs)| QEnumValueDescription {
+                name: variant.to_string(),
+                docs: docs.clone(),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_api_description() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+            mod ffi {
+                /// A namespaced enum
+                #[qenum]
+                enum MyNamespacedEnum {
+                    A,
+                }
+
+                #[qml_element]
+                qnamespace!("cxx_qt::my_object");
+
+                /// An enum of MyObject
+                #[qenum(MyObject)]
+                enum MyEnum {
+                    /// The A value
+                    A,
+                    B,
+                }
+
+                #[namespace = ""]
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                unsafe extern "RustQt" {
+                    /// My object
+                    #[qobject]
+                    #[qml_element = "MyQmlObject"]
+                    #[qml_singleton]
+                    /// The number
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+
+                    /// Say hi
+                    #[qinvokable]
+                    #[cxx_name = "sayHi"]
+                    fn say_hi(self: &MyObject, string: &QString) -> bool;
+
+                    /// Not an invokable
+                    fn rust_only(self: &MyObject);
+
+                    /// Emitted when ready
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, value: i32);
+
+                    #[qsignal]
+                    pub(self) fn private_signal(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let description = ApiDescription::from(&parser).unwrap();
+
+        assert_eq!(
+            description.qenums,
+            vec![QEnumDescription {
+                name: "MyNamespacedEnum".to_owned(),
+                namespace: "cxx_qt::my_object".to_owned(),
+                qml_namespace: true,
+                docs: vec!["A namespaced enum".to_owned()],
+                values: vec![QEnumValueDescription {
+                    name: "A".to_owned(),
+                    docs: vec![],
+                }],
+            }]
+        );
+
+        assert_eq!(description.qobjects.len(), 1);
+        let qobject = &description.qobjects[0];
+        assert_eq!(qobject.qualified_name(), "cxx_qt::my_object::MyObject");
+        assert_eq!(qobject.base_class, "QObject");
+        assert_eq!(qobject.qml_name.as_deref(), Some("MyQmlObject"));
+        assert!(qobject.qml_singleton);
+        assert!(!qobject.qml_uncreatable);
+        assert_eq!(qobject.docs, vec!["My object"]);
+        assert_eq!(
+            qobject.properties,
+            vec![PropertyDescription {
+                name: "number".to_owned(),
+                ty: "::std::int32_t".to_owned(),
+                docs: vec!["The number".to_owned()],
+            }]
+        );
+        assert_eq!(
+            qobject.invokables,
+            vec![MethodDescription {
+                name: "sayHi".to_owned(),
+                return_type: "bool".to_owned(),
+                parameters: vec![ParameterDescription {
+                    name: "string".to_owned(),
+                    ty: "QString const&".to_owned(),
+                }],
+                docs: vec!["Say hi".to_owned()],
+            }]
+        );
+        assert_eq!(
+            qobject.signals,
+            vec![MethodDescription {
+                name: "ready".to_owned(),
+                return_type: "void".to_owned(),
+                parameters: vec![ParameterDescription {
+                    name: "value".to_owned(),
+                    ty: "::std::int32_t".to_owned(),
+                }],
+                docs: vec!["Emitted when ready".to_owned()],
+            }]
+        );
+        assert_eq!(
+            qobject.qenums,
+            vec![QEnumDescription {
+                name: "MyEnum".to_owned(),
+                namespace: "cxx_qt::my_object".to_owned(),
+                qml_namespace: false,
+                docs: vec!["An enum of MyObject".to_owned()],
+                values: vec![
+                    QEnumValueDescription {
+                        name: "A".to_owned(),
+                        docs: vec!["The A value".to_owned()],
+                    },
+                    QEnumValueDescription {
+                        name: "B".to_owned(),
+                        docs: vec![],
+                    },
+                ],
+            }]
+        );
+    }
+}
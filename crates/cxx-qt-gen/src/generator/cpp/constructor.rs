@@ -10,6 +10,7 @@ use crate::{
 };
 
 use indoc::formatdoc;
+use std::collections::BTreeSet;
 use syn::{Result, Type};
 
 fn default_constructor(
@@ -156,6 +157,56 @@ pub fn generate(
     Ok(generated)
 }
 
+/// Generate the static `create` function which QML uses to create a singleton,
+/// the inner Rust struct is created by the `cxx_qt::QmlSingletonFactory` implementation.
+pub fn generate_singleton_factory(
+    qobject: &GeneratedCppQObject,
+    base_class: String,
+    class_initializers: &[String],
+) -> GeneratedCppQObjectBlocks {
+    let initializers = class_initializers
+        .iter()
+        .map(|initializer| format!("\n  , {initializer}"))
+        .collect::<Vec<_>>()
+        .join("");
+    let class_name = qobject.ident.as_str();
+    let rust_obj = qobject.rust_ident.as_str();
+    let namespace_internals = &qobject.namespace_internals;
+
+    GeneratedCppQObjectBlocks {
+        includes: BTreeSet::from(["#include <QtQml/QQmlEngine>".to_owned()]),
+        methods: vec![CppFragment::Pair {
+            header: format!(
+                "static {class_name}* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+            ),
+            source: formatdoc! {
+                r#"
+                {class_name}*
+                {class_name}::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+                {{
+                  Q_UNUSED(qmlEngine);
+                  Q_UNUSED(jsEngine);
+                  return new {class_name}(::{namespace_internals}::createSingletonRs());
+                }}
+                "#,
+            },
+        }],
+        // The constructor which takes the Rust struct is private so that only the factory can use it
+        private_methods: vec![CppFragment::Pair {
+            header: format!("explicit {class_name}(::rust::Box<{rust_obj}>&& rustObj);"),
+            source: formatdoc! {
+                r#"
+                {class_name}::{class_name}(::rust::Box<{rust_obj}>&& rustObj)
+                  : {base_class}()
+                  , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::std::move(rustObj)){initializers}
+                {{ }}
+                "#,
+            },
+        }],
+        ..Default::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,6 +227,7 @@ mod tests {
             namespace_internals: "rust".to_string(),
             blocks: GeneratedCppQObjectBlocks::default(),
             has_qobject_macro: true,
+            doc_comment: "".to_string(),
         }
     }
 
@@ -468,4 +520,52 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn singleton_factory() {
+        let blocks = generate_singleton_factory(
+            &qobject_for_testing(),
+            "BaseClass".to_owned(),
+            &["initializer".to_string()],
+        );
+
+        assert_empty_blocks(&blocks);
+        assert_eq!(
+            blocks.includes,
+            BTreeSet::from(["#include <QtQml/QQmlEngine>".to_owned()])
+        );
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "static MyObject* create(QQmlEngine* qmlEngine, QJSEngine* jsEngine);"
+                    .to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject*
+                    MyObject::create(QQmlEngine* qmlEngine, QJSEngine* jsEngine)
+                    {{
+                      Q_UNUSED(qmlEngine);
+                      Q_UNUSED(jsEngine);
+                      return new MyObject(::rust::createSingletonRs());
+                    }}
+                    "
+                ),
+            }]
+        );
+        assert_eq!(
+            blocks.private_methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(::rust::Box<MyObjectRust>&& rustObj);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(::rust::Box<MyObjectRust>&& rustObj)
+                      : BaseClass()
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::std::move(rustObj))
+                      , initializer
+                    {{ }}
+                    "
+                ),
+            }]
+        );
+    }
 }
@@ -6,7 +6,7 @@
 use crate::generator::cpp::qobject::GeneratedCppQObjectBlocks;
 use syn::Result;
 
-pub fn generate() -> Result<(String, GeneratedCppQObjectBlocks)> {
+pub fn generate(shared_locking: bool) -> Result<(String, GeneratedCppQObjectBlocks)> {
     let mut result = GeneratedCppQObjectBlocks::default();
 
     result
@@ -17,7 +17,11 @@ pub fn generate() -> Result<(String, GeneratedCppQObjectBlocks)> {
         .base_classes
         .push("::rust::cxxqt1::CxxQtLocking".to_owned());
 
-    let class_initializer = "::rust::cxxqt1::CxxQtLocking()".to_owned();
+    let class_initializer = if shared_locking {
+        "::rust::cxxqt1::CxxQtLocking(::rust::cxxqt1::CxxQtLockingMode::Shared)".to_owned()
+    } else {
+        "::rust::cxxqt1::CxxQtLocking()".to_owned()
+    };
 
     Ok((class_initializer, result))
 }
@@ -28,7 +32,7 @@ mod tests {
 
     #[test]
     fn test_generate_cpp_locking() {
-        let (initializer, generated) = generate().unwrap();
+        let (initializer, generated) = generate(false).unwrap();
 
         // initializer
         assert_eq!(initializer, "::rust::cxxqt1::CxxQtLocking()");
@@ -41,4 +45,19 @@ mod tests {
         assert_eq!(generated.base_classes.len(), 1);
         assert_eq!(generated.base_classes[0], "::rust::cxxqt1::CxxQtLocking");
     }
+
+    #[test]
+    fn test_generate_cpp_locking_shared() {
+        let (initializer, generated) = generate(true).unwrap();
+
+        // initializer
+        assert_eq!(
+            initializer,
+            "::rust::cxxqt1::CxxQtLocking(::rust::cxxqt1::CxxQtLockingMode::Shared)"
+        );
+
+        // base class
+        assert_eq!(generated.base_classes.len(), 1);
+        assert_eq!(generated.base_classes[0], "::rust::cxxqt1::CxxQtLocking");
+    }
 }
@@ -8,6 +8,7 @@ use crate::{
         cpp::{
             fragment::{CppFragment, CppNamedType},
             qobject::GeneratedCppQObjectBlocks,
+            utils::doxygen_comment,
         },
         naming::{method::QMethodName, qobject::QObjectName},
     },
@@ -18,6 +19,7 @@ use crate::{
     parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
 };
 use indoc::formatdoc;
+use std::collections::HashSet;
 use syn::{spanned::Spanned, Error, FnArg, Pat, PatIdent, PatType, Result};
 
 pub fn generate_cpp_methods(
@@ -27,6 +29,8 @@ pub fn generate_cpp_methods(
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
     let qobject_ident = qobject_idents.cpp_class.cpp.to_string();
+    // Methods sharing a C++ name are overloads, so their signatures must differ
+    let mut overloads = HashSet::new();
     for invokable in invokables {
         let idents = QMethodName::from(invokable);
         let return_cxx_ty = syn_type_to_cpp_return_type(&invokable.method.sig.output, type_names)?;
@@ -61,7 +65,7 @@ pub fn generate_cpp_methods(
             .filter_map(|result| result.map_or_else(|e| Some(Err(e)), |v| v.map(Ok)))
             .collect::<Result<Vec<CppNamedType>>>()?;
 
-        let body = format!(
+        let call = format!(
             "{ident}({parameter_names})",
             ident = idents.wrapper.cpp,
             parameter_names = parameters
@@ -77,16 +81,82 @@ pub fn generate_cpp_methods(
             .join(", ");
         let is_const = if !invokable.mutable { " const" } else { "" };
 
+        // Set the QML ownership of any QObject pointers passed in or returned
+        let mut body = vec![];
+        for (parameter, parsed) in parameters.iter().zip(invokable.parameters.iter()) {
+            if let Some(ownership) = &parsed.ownership {
+                body.push(format!(
+                    "QQmlEngine::setObjectOwnership({ident}, {ownership});",
+                    ident = parameter.ident,
+                    ownership = ownership.as_cpp_str()
+                ));
+            }
+        }
+        match (&return_cxx_ty, &invokable.return_ownership) {
+            (Some(return_cxx_ty), Some(ownership)) => {
+                body.push(format!("{return_cxx_ty} result = {call};"));
+                body.push(format!(
+                    "QQmlEngine::setObjectOwnership(result, {ownership});",
+                    ownership = ownership.as_cpp_str()
+                ));
+                body.push("return result;".to_owned());
+            }
+            (Some(_), None) => body.push(format!("return {call};")),
+            (None, _) => body.push(format!("{call};")),
+        }
+        if invokable.return_ownership.is_some()
+            || invokable
+                .parameters
+                .iter()
+                .any(|parameter| parameter.ownership.is_some())
+        {
+            generated
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+        }
+
+        if !overloads.insert((
+            idents.name.cpp.to_string(),
+            parameters
+                .iter()
+                .map(|parameter| parameter.ty.clone())
+                .collect::<Vec<String>>(),
+            is_const,
+        )) {
+            return Err(Error::new(
+                invokable.method.span(),
+                "Methods with the same C++ name are overloads and must have differing parameter types",
+            ));
+        }
+
+        // Default values are only specified in the declaration
+        let parameter_declarations = parameters
+            .iter()
+            .zip(invokable.parameters.iter())
+            .map(|(parameter, parsed)| {
+                if let Some(default) = &parsed.default {
+                    format!(
+                        "{ty} {ident} = {default}",
+                        ident = parameter.ident,
+                        ty = parameter.ty
+                    )
+                } else {
+                    format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
         generated.methods.push(CppFragment::Pair {
             header: format!(
-                "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
+                "{docs}{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_declarations}){is_const}{is_final}{is_override};",
+                docs = doxygen_comment(&invokable.docs),
                 return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
                     return_cxx_ty
                 } else {
                     "void"
                 },
                 ident = idents.name.cpp,
-                parameter_types = parameter_types,
                 is_qinvokable = if invokable.is_qinvokable {
                     "Q_INVOKABLE "
                 } else {
@@ -114,7 +184,7 @@ pub fn generate_cpp_methods(
                     {qobject_ident}::{ident}({parameter_types}){is_const}
                     {{
                         const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                        {body};
+                        {body}
                     }}
                     "#,
                 return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
@@ -123,11 +193,7 @@ pub fn generate_cpp_methods(
                     "void"
                 },
                 ident = idents.name.cpp,
-                body = if return_cxx_ty.is_some() {
-                    format!("return {body}", body = body)
-                } else {
-                    body
-                },
+                body = body.join("\n    "),
             },
         });
 
@@ -155,7 +221,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{ownership::ParsedQmlOwnership, parameter::ParsedFunctionParameter};
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -173,6 +239,9 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -182,9 +251,14 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -194,9 +268,14 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { &QColor },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn specifiers_invokable(self: &MyObject, param: i32) -> i32; },
@@ -206,6 +285,8 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: {
                     let mut specifiers = HashSet::new();
@@ -215,6 +296,9 @@ mod tests {
                     specifiers
                 },
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn cpp_method(self: &MyObject); },
@@ -224,6 +308,9 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: false,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -393,9 +480,14 @@ mod tests {
             parameters: vec![ParsedFunctionParameter {
                 ident: format_ident!("param"),
                 ty: parse_quote! { i32 },
+                default: None,
+                ownership: None,
             }],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            return_ownership: None,
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -439,4 +531,206 @@ mod tests {
             "B2 trivialInvokableWrapper(A1 param) const noexcept;"
         );
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_overloads_and_defaults() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! {
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_int(self: &MyObject, param: i32, flag: bool);
+                },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![
+                    ParsedFunctionParameter {
+                        ident: format_ident!("param"),
+                        ty: parse_quote! { i32 },
+                        default: None,
+                        ownership: None,
+                    },
+                    ParsedFunctionParameter {
+                        ident: format_ident!("flag"),
+                        ty: parse_quote! { bool },
+                        default: Some("false".to_owned()),
+                        ownership: None,
+                    },
+                ],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+            ParsedMethod {
+                method: parse_quote! {
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_float(self: &MyObject, param: f32);
+                },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { f32 },
+                    default: None,
+                    ownership: None,
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, &TypeNames::default()).unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 2);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void overloaded(::std::int32_t param, bool flag = false) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::overloaded(::std::int32_t param, bool flag) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                overloadedIntWrapper(param, flag);
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void overloaded(float param) const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::overloaded(float param) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                overloadedFloatWrapper(param);
+            }
+            "#}
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 2);
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "void overloadedIntWrapper(::std::int32_t param, bool flag) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_overloads_same_parameters() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! {
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_a(self: &MyObject);
+                },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+            ParsedMethod {
+                method: parse_quote! {
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_b(self: &MyObject);
+                },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        assert!(generate_cpp_methods(&invokables, &qobject_idents, &TypeNames::default()).is_err());
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_ownership() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { unsafe fn create_child(self: Pin<&mut MyObject>, parent: *mut QObject) -> *mut QObject; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: false,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("parent"),
+                ty: parse_quote! { *mut QObject },
+                default: None,
+                ownership: Some(ParsedQmlOwnership::Cpp),
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            return_ownership: Some(ParsedQmlOwnership::JavaScript),
+            docs: vec![],
+            cfgs: vec![],
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.insert("QObject", None, None, None);
+
+        let generated = generate_cpp_methods(&invokables, &qobject_idents, &type_names).unwrap();
+
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE QObject* createChild(QObject* parent);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QObject*
+            MyObject::createChild(QObject* parent)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                QQmlEngine::setObjectOwnership(parent, QQmlEngine::CppOwnership);
+                QObject* result = createChildWrapper(parent);
+                QQmlEngine::setObjectOwnership(result, QQmlEngine::JavaScriptOwnership);
+                return result;
+            }
+            "#}
+        );
+    }
 }
@@ -3,10 +3,35 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyName};
+use crate::{
+    generator::{cpp::fragment::CppFragment, naming::property::QPropertyName},
+    parser::ownership::ParsedQmlOwnership,
+};
 use indoc::formatdoc;
 
-pub fn generate(idents: &QPropertyName, qobject_ident: &str, return_cxx_ty: &str) -> CppFragment {
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    return_cxx_ty: &str,
+    ownership: Option<&ParsedQmlOwnership>,
+) -> CppFragment {
+    // Set the QML ownership of a QObject pointer before it is handed out
+    let body = if let Some(ownership) = ownership {
+        formatdoc!(
+            r#"
+            {return_cxx_ty} const& value = {ident_getter_wrapper}();
+                QQmlEngine::setObjectOwnership(value, {ownership});
+                return value;"#,
+            ident_getter_wrapper = idents.getter_wrapper.cpp,
+            ownership = ownership.as_cpp_str(),
+        )
+    } else {
+        format!(
+            "return {ident_getter_wrapper}();",
+            ident_getter_wrapper = idents.getter_wrapper.cpp
+        )
+    };
+
     CppFragment::Pair {
         header: format!(
             "{return_cxx_ty} const& {ident_getter}() const;",
@@ -17,12 +42,11 @@ pub fn generate(idents: &QPropertyName, qobject_ident: &str, return_cxx_ty: &str
             {return_cxx_ty} const&
             {qobject_ident}::{ident_getter}() const
             {{
-                const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                return {ident_getter_wrapper}();
+                const ::rust::cxxqt1::MaybeSharedLockGuard<{qobject_ident}> guard(*this);
+                {body}
             }}
             "#,
             ident_getter = idents.getter.cpp.to_string(),
-            ident_getter_wrapper = idents.getter_wrapper.cpp.to_string(),
         ),
     }
 }
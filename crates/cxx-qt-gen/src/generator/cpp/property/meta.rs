@@ -3,12 +3,13 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::naming::property::QPropertyName;
+use crate::generator::{cpp::utils::doxygen_comment, naming::property::QPropertyName};
 
-/// Generate the metaobject line for a given property
-pub fn generate(idents: &QPropertyName, cxx_ty: &str) -> String {
+/// Generate the metaobject line for a given property, with any doc comments of the property
+pub fn generate(idents: &QPropertyName, cxx_ty: &str, docs: &[String]) -> String {
     format!(
-        "Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify})",
+        "{docs}Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify})",
+        docs = doxygen_comment(docs),
         ty = cxx_ty,
         ident = idents.name.cpp,
         ident_getter = idents.getter.cpp,
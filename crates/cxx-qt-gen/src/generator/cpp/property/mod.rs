@@ -31,20 +31,34 @@ pub fn generate_cpp_properties(
         let idents = QPropertyName::from(property);
         let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
 
-        generated.metaobjects.push(meta::generate(&idents, &cxx_ty));
         generated
-            .methods
-            .push(getter::generate(&idents, &qobject_ident, &cxx_ty));
+            .metaobjects
+            .push(meta::generate(&idents, &cxx_ty, &property.docs));
+        generated.methods.push(getter::generate(
+            &idents,
+            &qobject_ident,
+            &cxx_ty,
+            property.ownership.as_ref(),
+        ));
         generated
             .private_methods
             .push(getter::generate_wrapper(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(setter::generate(&idents, &qobject_ident, &cxx_ty));
+        generated.methods.push(setter::generate(
+            &idents,
+            &qobject_ident,
+            &cxx_ty,
+            property.ownership.as_ref(),
+        ));
         generated
             .private_methods
             .push(setter::generate_wrapper(&idents, &cxx_ty));
         signals.push(signal::generate(&idents, qobject_idents));
+
+        if property.ownership.is_some() {
+            generated
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+        }
     }
 
     generated.append(&mut generate_cpp_signals(
@@ -61,6 +75,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::ownership::ParsedQmlOwnership;
     use crate::CppFragment;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
@@ -73,10 +88,16 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -104,7 +125,7 @@ mod tests {
             ::std::int32_t const&
             MyObject::getTrivialProperty() const
             {
-                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
                 return getTrivialPropertyWrapper();
             }
             "#}
@@ -146,7 +167,7 @@ mod tests {
             ::std::unique_ptr<QColor> const&
             MyObject::getOpaqueProperty() const
             {
-                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
                 return getOpaquePropertyWrapper();
             }
             "#}
@@ -358,6 +379,9 @@ mod tests {
         let properties = vec![ParsedQProperty {
             ident: format_ident!("mapped_property"),
             ty: parse_quote! { A },
+            ownership: None,
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -384,7 +408,7 @@ mod tests {
             A1 const&
             MyObject::getMappedProperty() const
             {
-                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
                 return getMappedPropertyWrapper();
             }
             "#}
@@ -495,4 +519,63 @@ mod tests {
         };
         assert_str_eq!(header, "void setMappedPropertyWrapper(A1 value) noexcept;");
     }
+
+    #[test]
+    fn test_generate_cpp_properties_ownership() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("child"),
+            ty: parse_quote! { *mut QObject },
+            ownership: Some(ParsedQmlOwnership::Cpp),
+            docs: vec![],
+            cfgs: vec![],
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.insert("QObject", None, None, None);
+
+        let generated = generate_cpp_properties(&properties, &qobject_idents, &type_names).unwrap();
+
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+
+        // methods
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QObject* const& getChild() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QObject* const&
+            MyObject::getChild() const
+            {
+                const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
+                QObject* const& value = getChildWrapper();
+                QQmlEngine::setObjectOwnership(value, QQmlEngine::CppOwnership);
+                return value;
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setChild(QObject* const& value);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setChild(QObject* const& value)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                QQmlEngine::setObjectOwnership(value, QQmlEngine::CppOwnership);
+                setChildWrapper(value);
+            }
+            "#}
+        );
+    }
 }
@@ -3,10 +3,34 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyName};
+use crate::{
+    generator::{cpp::fragment::CppFragment, naming::property::QPropertyName},
+    parser::ownership::ParsedQmlOwnership,
+};
 use indoc::formatdoc;
 
-pub fn generate(idents: &QPropertyName, qobject_ident: &str, cxx_ty: &str) -> CppFragment {
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    ownership: Option<&ParsedQmlOwnership>,
+) -> CppFragment {
+    // Set the QML ownership of a QObject pointer as it is stored
+    let body = if let Some(ownership) = ownership {
+        formatdoc!(
+            r#"
+            QQmlEngine::setObjectOwnership(value, {ownership});
+                {ident_setter_wrapper}(value);"#,
+            ident_setter_wrapper = idents.setter_wrapper.cpp,
+            ownership = ownership.as_cpp_str(),
+        )
+    } else {
+        format!(
+            "{ident_setter_wrapper}(value);",
+            ident_setter_wrapper = idents.setter_wrapper.cpp
+        )
+    };
+
     CppFragment::Pair {
         header: format!(
             "Q_SLOT void {ident_setter}({cxx_ty} const& value);",
@@ -18,11 +42,10 @@ pub fn generate(idents: &QPropertyName, qobject_ident: &str, cxx_ty: &str) -> Cp
             {qobject_ident}::{ident_setter}({cxx_ty} const& value)
             {{
                 const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                {ident_setter_wrapper}(value);
+                {body}
             }}
             "#,
             ident_setter = idents.setter.cpp,
-            ident_setter_wrapper = idents.setter_wrapper.cpp.to_string(),
         },
     }
 }
@@ -10,7 +10,10 @@ use syn::Result;
 
 use crate::{naming::TypeNames, parser::qenum::ParsedQEnum, writer::cpp::namespaced};
 
-use super::{qobject::GeneratedCppQObjectBlocks, utils::Indent};
+use super::{
+    qobject::GeneratedCppQObjectBlocks,
+    utils::{doxygen_comment, Indent},
+};
 
 fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_name = &qenum.ident.to_string();
@@ -18,15 +21,16 @@ fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_values = qenum
         .variants
         .iter()
-        .map(ToString::to_string)
+        .zip(qenum.variant_docs.iter())
+        .map(|(variant, docs)| format!("{docs}{variant}", docs = doxygen_comment(docs)))
         .collect::<Vec<_>>()
         .join(",\n");
 
     formatdoc! { r#"
-        enum class {enum_name} : ::std::int32_t {{
+        {docs}enum class {enum_name} : ::std::int32_t {{
         {enum_values}
         }};
-        "#, enum_values = enum_values.indented(2) }
+        "#, docs = doxygen_comment(&qenum.docs), enum_values = enum_values.indented(2) }
 }
 
 pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>) -> String {
@@ -131,4 +135,33 @@ mod tests {
             generated.forward_declares[0],
         );
     }
+
+    #[test]
+    fn generates_docs() {
+        let qenum = ParsedQEnum::parse(parse_quote! {
+            /// My enum
+            enum MyEnum {
+                /// The A value
+                ///
+                /// Which is first
+                A,
+                B,
+            }
+        })
+        .unwrap();
+
+        assert_str_eq!(
+            indoc! { r#"
+                /// My enum
+                enum class MyEnum : ::std::int32_t {
+                  /// The A value
+                  ///
+                  /// Which is first
+                  A,
+                  B
+                };
+            "# },
+            generate_definition(&qenum),
+        );
+    }
 }
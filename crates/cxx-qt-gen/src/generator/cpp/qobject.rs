@@ -7,7 +7,7 @@ use crate::generator::{
     cpp::{
         constructor, cxxqttype, fragment::CppFragment, inherit, locking,
         method::generate_cpp_methods, property::generate_cpp_properties, qenum,
-        signal::generate_cpp_signals, threading,
+        signal::generate_cpp_signals, threading, utils::doxygen_comment,
     },
     naming::{namespace::NamespaceName, qobject::QObjectName},
 };
@@ -36,6 +36,8 @@ pub struct GeneratedCppQObjectBlocks {
     pub metaobjects: Vec<String>,
     /// List of public methods for the QObject
     pub methods: Vec<CppFragment>,
+    /// List of protected methods for the QObject
+    pub protected_methods: Vec<CppFragment>,
     /// List of private methods for the QObject
     pub private_methods: Vec<CppFragment>,
 }
@@ -50,6 +52,7 @@ impl GeneratedCppQObjectBlocks {
         self.base_classes.append(&mut other.base_classes);
         self.metaobjects.append(&mut other.metaobjects);
         self.methods.append(&mut other.methods);
+        self.protected_methods.append(&mut other.protected_methods);
         self.private_methods.append(&mut other.private_methods);
     }
 
@@ -94,6 +97,8 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// The Doxygen comment of the class, from the doc comments of the QObject
+    pub doc_comment: String,
 }
 
 impl GeneratedCppQObject {
@@ -109,6 +114,7 @@ impl GeneratedCppQObject {
             namespace_internals: namespace_idents.internal,
             blocks: GeneratedCppQObjectBlocks::from(qobject),
             has_qobject_macro: qobject.has_qobject_macro,
+            doc_comment: doxygen_comment(&qobject.docs),
         };
 
         // Ensure that we include MaybeLockGuard<T> that is used in multiple places
@@ -169,23 +175,41 @@ impl GeneratedCppQObject {
             // The parser phase should check that this is true
             debug_assert!(qobject.locking);
 
-            let (initializer, mut blocks) = threading::generate(&qobject_idents)?;
+            let (initializer, mut blocks) =
+                threading::generate(&qobject_idents, qobject.shared_locking)?;
             generated.blocks.append(&mut blocks);
             class_initializers.push(initializer);
         // If this type has locking enabled then add generation
         } else if qobject.locking {
-            let (initializer, mut blocks) = locking::generate()?;
+            let (initializer, mut blocks) = locking::generate(qobject.shared_locking)?;
             generated.blocks.append(&mut blocks);
             class_initializers.push(initializer);
         }
 
-        generated.blocks.append(&mut constructor::generate(
+        // If the QML singleton has a Rust factory then add the static create function
+        if qobject.singleton_factory {
+            let mut factory = constructor::generate_singleton_factory(
+                &generated,
+                base_class.clone(),
+                &class_initializers,
+            );
+            generated.blocks.append(&mut factory);
+        }
+
+        let mut constructors = constructor::generate(
             &generated,
             &qobject.constructors,
             base_class,
             &class_initializers,
             type_names,
-        )?);
+        )?;
+        // A subclassable QObject is only constructed by C++ subclasses
+        if qobject.subclassable {
+            constructors
+                .protected_methods
+                .append(&mut constructors.methods);
+        }
+        generated.blocks.append(&mut constructors);
 
         Ok(generated)
     }
@@ -347,4 +371,34 @@ mod tests {
             "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
         );
     }
+
+    #[test]
+    fn test_generated_cpp_qobject_subclassable() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[cxx_subclassable]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &TypeNames::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.protected_methods.len(), 1);
+        assert!(matches!(
+            &cpp.blocks.protected_methods[0],
+            CppFragment::Pair { header, .. } if header == "explicit MyObject(QObject* parent = nullptr);"
+        ));
+        assert!(!cpp.blocks.methods.iter().any(|method| matches!(
+            method,
+            CppFragment::Pair { header, .. } if header.starts_with("explicit MyObject(")
+        )));
+    }
 }
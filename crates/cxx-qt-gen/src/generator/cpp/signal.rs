@@ -5,7 +5,7 @@
 
 use crate::{
     generator::{
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, utils::doxygen_comment},
         naming::{
             qobject::QObjectName,
             signals::{QSignalHelperName, QSignalName},
@@ -128,7 +128,8 @@ pub fn generate_cpp_signal(
     // Generate the Q_SIGNAL if this is not an existing signal
     if !signal.inherit {
         generated.methods.push(CppFragment::Header(format!(
-            "Q_SIGNAL void {signal_ident}({parameters_named_types});"
+            "{docs}Q_SIGNAL void {signal_ident}({parameters_named_types});",
+            docs = doxygen_comment(&signal.docs),
         )));
     }
 
@@ -234,10 +235,14 @@ mod tests {
                 ParsedFunctionParameter {
                     ident: format_ident!("trivial"),
                     ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
                 },
                 ParsedFunctionParameter {
                     ident: format_ident!("opaque"),
                     ty: parse_quote! { UniquePtr<QColor> },
+                    default: None,
+                    ownership: None,
                 },
             ],
             ident: CombinedIdent {
@@ -247,6 +252,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -339,6 +346,8 @@ mod tests {
             parameters: vec![ParsedFunctionParameter {
                 ident: format_ident!("mapped"),
                 ty: parse_quote! { A },
+                default: None,
+                ownership: None,
             }],
             ident: CombinedIdent {
                 cpp: format_ident!("dataChanged"),
@@ -347,6 +356,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -443,6 +454,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         }];
         let qobject_idents = create_qobjectname();
         let generated =
@@ -529,6 +542,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let mut type_names = TypeNames::default();
@@ -619,6 +634,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let mut type_names = TypeNames::default();
@@ -10,7 +10,10 @@ use crate::generator::{
 use indoc::formatdoc;
 use syn::Result;
 
-pub fn generate(qobject_idents: &QObjectName) -> Result<(String, GeneratedCppQObjectBlocks)> {
+pub fn generate(
+    qobject_idents: &QObjectName,
+    shared_locking: bool,
+) -> Result<(String, GeneratedCppQObjectBlocks)> {
     let mut result = GeneratedCppQObjectBlocks::default();
 
     let cpp_class = &qobject_idents.cpp_class.cpp;
@@ -36,7 +39,11 @@ pub fn generate(qobject_idents: &QObjectName) -> Result<(String, GeneratedCppQOb
         .base_classes
         .push(format!("::rust::cxxqt1::CxxQtThreading<{cpp_class}>"));
 
-    let class_initializer = format!("::rust::cxxqt1::CxxQtThreading<{cpp_class}>(this)");
+    let class_initializer = if shared_locking {
+        format!("::rust::cxxqt1::CxxQtThreading<{cpp_class}>(this, ::rust::cxxqt1::CxxQtLockingMode::Shared)")
+    } else {
+        format!("::rust::cxxqt1::CxxQtThreading<{cpp_class}>(this)")
+    };
 
     Ok((class_initializer, result))
 }
@@ -53,7 +60,7 @@ mod tests {
     fn test_generate_cpp_threading() {
         let qobject_idents = create_qobjectname();
 
-        let (initializer, generated) = generate(&qobject_idents).unwrap();
+        let (initializer, generated) = generate(&qobject_idents, false).unwrap();
 
         // forward declares
         assert_eq!(generated.forward_declares.len(), 1);
@@ -96,4 +103,16 @@ mod tests {
             "::rust::cxxqt1::CxxQtThreading<MyObject>"
         );
     }
+
+    #[test]
+    fn test_generate_cpp_threading_shared_locking() {
+        let qobject_idents = create_qobjectname();
+
+        let (initializer, _) = generate(&qobject_idents, true).unwrap();
+
+        assert_str_eq!(
+            initializer,
+            "::rust::cxxqt1::CxxQtThreading<MyObject>(this, ::rust::cxxqt1::CxxQtLockingMode::Shared)"
+        );
+    }
 }
@@ -20,6 +20,22 @@ impl Indent for str {
     }
 }
 
+/// Create a Doxygen comment from the lines of a Rust doc comment
+///
+/// This is empty if there are no lines, otherwise each line is terminated by a newline,
+/// so that it can be placed before the declaration it documents.
+pub(crate) fn doxygen_comment(docs: &[String]) -> String {
+    docs.iter()
+        .map(|line| {
+            if line.is_empty() {
+                "///\n".to_owned()
+            } else {
+                format!("/// {line}\n")
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +64,13 @@ mod tests {
         "#}
         );
     }
+
+    #[test]
+    fn doxygen_comment_lines() {
+        assert_str_eq!(doxygen_comment(&[]), "");
+        assert_str_eq!(
+            doxygen_comment(&["A doc".to_owned(), "".to_owned(), "comment".to_owned()]),
+            "/// A doc\n///\n/// comment\n"
+        );
+    }
 }
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod api;
 pub mod cpp;
 pub mod naming;
 pub mod rust;
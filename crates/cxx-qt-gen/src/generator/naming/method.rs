@@ -2,7 +2,11 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::{generator::naming::CombinedIdent, parser::method::ParsedMethod};
+use crate::{
+    generator::naming::CombinedIdent,
+    parser::method::ParsedMethod,
+    syntax::{attribute::attribute_find_path, expr::expr_to_string},
+};
 use convert_case::{Case, Casing};
 use quote::format_ident;
 use syn::{ForeignItemFn, Ident};
@@ -11,6 +15,8 @@ use syn::{ForeignItemFn, Ident};
 pub struct QMethodName {
     pub name: CombinedIdent,
     pub wrapper: CombinedIdent,
+    /// Rust name of the method which calls the C++ virtual method
+    pub dispatch: Ident,
 }
 
 impl From<&ParsedMethod> for QMethodName {
@@ -22,9 +28,25 @@ impl From<&ParsedMethod> for QMethodName {
 impl From<&ForeignItemFn> for QMethodName {
     fn from(method: &ForeignItemFn) -> Self {
         let ident = &method.sig.ident;
+        let mut name = CombinedIdent::from_rust_function(ident.clone());
+
+        // Several methods can share a cxx_name to become overloads in C++
+        //
+        // Note that the parser has already ensured that the cxx_name is a string
+        if let Some(index) = attribute_find_path(&method.attrs, &["cxx_name"]) {
+            if let Ok(cxx_name) = method.attrs[index]
+                .meta
+                .require_name_value()
+                .and_then(|name_value| expr_to_string(&name_value.value))
+            {
+                name.cpp = format_ident!("{cxx_name}");
+            }
+        }
+
         Self {
-            name: CombinedIdent::from_rust_function(ident.clone()),
+            name,
             wrapper: CombinedIdent::wrapper_from_invokable(ident),
+            dispatch: format_ident!("{ident}_dispatch"),
         }
     }
 }
@@ -60,6 +82,9 @@ mod tests {
             parameters: vec![],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            return_ownership: None,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let invokable = QMethodName::from(&parsed);
@@ -70,5 +95,33 @@ mod tests {
             invokable.wrapper.rust,
             format_ident!("my_invokable_wrapper")
         );
+        assert_eq!(invokable.dispatch, format_ident!("my_invokable_dispatch"));
+    }
+
+    #[test]
+    fn test_from_impl_method_cxx_name() {
+        let parsed = ParsedMethod {
+            method: parse_quote! {
+                #[cxx_name = "myOverload"]
+                fn my_invokable_int(self: &MyObject, value: i32);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            return_ownership: None,
+            docs: vec![],
+            cfgs: vec![],
+        };
+
+        let invokable = QMethodName::from(&parsed);
+        assert_eq!(invokable.name.cpp, format_ident!("myOverload"));
+        assert_eq!(invokable.name.rust, format_ident!("my_invokable_int"));
+        assert_eq!(
+            invokable.wrapper.cpp,
+            format_ident!("myInvokableIntWrapper")
+        );
     }
 }
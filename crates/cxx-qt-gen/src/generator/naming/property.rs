@@ -94,6 +94,9 @@ pub mod tests {
         let property = ParsedQProperty {
             ident: format_ident!("my_property"),
             ty,
+            ownership: None,
+            docs: vec![],
+            cfgs: vec![],
         };
         QPropertyName::from(&property)
     }
@@ -120,6 +120,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let names = QSignalName::from(&qsignal);
@@ -150,6 +152,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let names = QSignalName::from(&qsignal);
@@ -91,6 +91,39 @@ fn generate_default_constructor(
     }
 }
 
+/// Generate the function which is called by the C++ `create` function of a QML singleton
+/// to construct the inner Rust struct with the `cxx_qt::QmlSingletonFactory` implementation.
+pub fn generate_singleton_factory(
+    qobject_idents: &QObjectName,
+    namespace: &NamespaceName,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let rust_struct_ident = &qobject_idents.rust_struct.rust;
+    let qualified_impl = type_names.rust_qualified(&qobject_idents.cpp_class.rust)?;
+
+    let create_singleton_rs_ident = format_ident!(
+        "create_singleton_rs_{object_name}",
+        object_name = rust_struct_ident.to_string().to_case(Case::Snake)
+    );
+    let namespace_internals = &namespace.internal;
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+        extern "Rust" {
+            #[cxx_name = "createSingletonRs"]
+            #[namespace = #namespace_internals]
+            fn #create_singleton_rs_ident() -> Box<#rust_struct_ident>;
+        }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            #[doc(hidden)]
+            pub fn #create_singleton_rs_ident() -> std::boxed::Box<#rust_struct_ident> {
+                std::boxed::Box::new(<#qualified_impl as cxx_qt::QmlSingletonFactory>::create())
+            }
+        }],
+    })
+}
+
 fn generate_arguments_struct(
     namespace_internals: &str,
     struct_name: &CombinedIdent,
@@ -508,6 +541,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn singleton_factory() {
+        let mut type_names = TypeNames::mock();
+        type_names.insert("MyObject", None, None, None);
+        let blocks =
+            generate_singleton_factory(&mock_name(), &mock_namespace(), &type_names).unwrap();
+
+        assert_eq!(blocks.cxx_mod_contents.len(), 1);
+        assert_eq!(blocks.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name="createSingletonRs"]
+                    #[namespace="qobject::cxx_qt_my_object"]
+                    fn create_singleton_rs_my_object_rust() -> Box<MyObjectRust>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn create_singleton_rs_my_object_rust() -> std::boxed::Box<MyObjectRust>
+                {
+                    std::boxed::Box::new(<MyObject as cxx_qt::QmlSingletonFactory>::create())
+                }
+            },
+        );
+    }
+
     fn assert_empty_argument_struct<T: quote::ToTokens>(
         tokens: &T,
         rust_name: &str,
@@ -51,6 +51,6 @@ impl GeneratedRustFragment {
             )?);
         }
 
-        Ok(generated)
+        Ok(generated.with_cfgs(&extern_cxxqt_block.cfgs))
     }
 }
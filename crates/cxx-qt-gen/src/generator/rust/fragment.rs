@@ -3,8 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::syntax::attribute::item_attrs;
 use proc_macro2::TokenStream;
-use syn::{Item, Result};
+use quote::ToTokens;
+use syn::{Attribute, Item, Result};
 
 #[derive(Default)]
 pub struct GeneratedRustFragment {
@@ -20,6 +22,32 @@ impl GeneratedRustFragment {
         self.cxx_qt_mod_contents
             .append(&mut other.cxx_qt_mod_contents);
     }
+
+    /// Add the `#[cfg]` attributes of the parsed item to each of the generated items,
+    /// so that rustc evaluates them for the generated code
+    pub fn with_cfgs(mut self, cfgs: &[Attribute]) -> Self {
+        for item in self
+            .cxx_mod_contents
+            .iter_mut()
+            .chain(self.cxx_qt_mod_contents.iter_mut())
+        {
+            if let Some(attrs) = item_attrs(item) {
+                // Items can already have the same cfg, eg from the extern "RustQt" block of their QObject
+                let cfgs: Vec<Attribute> = cfgs
+                    .iter()
+                    .filter(|cfg| {
+                        let cfg = cfg.to_token_stream().to_string();
+                        !attrs
+                            .iter()
+                            .any(|attr| attr.to_token_stream().to_string() == cfg)
+                    })
+                    .cloned()
+                    .collect();
+                attrs.splice(0..0, cfgs);
+            }
+        }
+        self
+    }
 }
 
 /// A generic Rust CXX bridge definition and the corresponding implementation
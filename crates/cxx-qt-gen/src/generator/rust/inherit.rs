@@ -45,8 +45,10 @@ pub fn generate(
                 std::mem::swap(&mut unsafe_call, &mut unsafe_block);
             }
             let attrs = &method.method.attrs;
+            let cfgs = &method.cfgs;
             syn::parse2(quote_spanned! {
                 method.method.span() =>
+                #(#cfgs)*
                 #unsafe_block extern "C++" {
                     #(#attrs)*
                     #[cxx_name = #cxx_name_string]
@@ -17,6 +17,7 @@ use syn::{spanned::Spanned, Result};
 pub fn generate_rust_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectName,
+    subclassable: bool,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
@@ -56,28 +57,47 @@ pub fn generate_rust_methods(
             std::mem::swap(&mut unsafe_call, &mut unsafe_block);
         }
 
-        let fragment = RustFragmentPair {
-            cxx_bridge: vec![quote_spanned! {
+        let mut cxx_bridge = vec![quote_spanned! {
+            invokable.method.span() =>
+            // Note: extern "Rust" block does not need to be unsafe
+            extern "Rust" {
+                // Note that we are exposing a Rust method on the C++ type to C++
+                //
+                // CXX ends up generating the source, then we generate the matching header.
+                #[doc(hidden)]
+                #[cxx_name = #wrapper_ident_cpp]
+                #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+            }
+        }];
+
+        // A virtual method of a subclassable QObject can be overridden by a C++ subclass,
+        // so expose the C++ method to Rust as well, this then goes through the C++ dynamic dispatch.
+        if subclassable && invokable.is_overridable() {
+            let invokable_ident_cpp = idents.name.cpp.to_string();
+            let dispatch_ident_rust = &idents.dispatch;
+            let doc = format!("Call the C++ virtual method {invokable_ident_cpp}, this calls any override from a C++ subclass.\n\nDo not call this from the Rust implementation of {invokable_ident_rust}, as it would then call itself forever.");
+            cxx_bridge.push(quote_spanned! {
                 invokable.method.span() =>
-                // Note: extern "Rust" block does not need to be unsafe
-                extern "Rust" {
-                    // Note that we are exposing a Rust method on the C++ type to C++
-                    //
-                    // CXX ends up generating the source, then we generate the matching header.
-                    #[doc(hidden)]
-                    #[cxx_name = #wrapper_ident_cpp]
-                    #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+                unsafe extern "C++" {
+                    #[doc = #doc]
+                    #[cxx_name = #invokable_ident_cpp]
+                    #unsafe_call fn #dispatch_ident_rust(#parameter_signatures) #return_type;
                 }
-            }],
+            });
+        }
+
+        let fragment = RustFragmentPair {
+            cxx_bridge,
             implementation: vec![],
         };
 
-        generated
-            .cxx_mod_contents
-            .append(&mut fragment.cxx_bridge_as_items()?);
-        generated
-            .cxx_qt_mod_contents
-            .append(&mut fragment.implementation_as_items()?);
+        generated.append(
+            &mut GeneratedRustFragment {
+                cxx_mod_contents: fragment.cxx_bridge_as_items()?,
+                cxx_qt_mod_contents: fragment.implementation_as_items()?,
+            }
+            .with_cfgs(&invokable.cfgs),
+        );
     }
 
     Ok(generated)
@@ -88,7 +108,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{method::ParsedQInvokableSpecifiers, parameter::ParsedFunctionParameter};
     use crate::tests::assert_tokens_eq;
     use quote::format_ident;
     use std::collections::HashSet;
@@ -105,6 +125,9 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -114,9 +137,14 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -126,9 +154,14 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { &QColor },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedMethod {
                 method: parse_quote! { unsafe fn unsafe_invokable(self: &MyObject, param: *mut T) -> *mut T; },
@@ -138,16 +171,59 @@ mod tests {
                 parameters: vec![ParsedFunctionParameter {
                     ident: format_ident!("param"),
                     ty: parse_quote! { *mut T },
+                    default: None,
+                    ownership: None,
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+            ParsedMethod {
+                method: parse_quote! { fn virtual_invokable(self: &MyObject, param: i32) -> i32; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
+                }],
+                specifiers: {
+                    let mut specifiers = HashSet::new();
+                    specifiers.insert(ParsedQInvokableSpecifiers::Virtual);
+                    specifiers
+                },
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
+            },
+            ParsedMethod {
+                method: parse_quote! { fn final_invokable(self: &MyObject); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: {
+                    let mut specifiers = HashSet::new();
+                    specifiers.insert(ParsedQInvokableSpecifiers::Final);
+                    specifiers.insert(ParsedQInvokableSpecifiers::Override);
+                    specifiers
+                },
+                is_qinvokable: true,
+                return_ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
         ];
         let qobject_idents = create_qobjectname();
 
-        let generated = generate_rust_methods(&invokables, &qobject_idents).unwrap();
+        let generated = generate_rust_methods(&invokables, &qobject_idents, true).unwrap();
 
-        assert_eq!(generated.cxx_mod_contents.len(), 4);
+        assert_eq!(generated.cxx_mod_contents.len(), 7);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
 
         // void_invokable
@@ -197,5 +273,74 @@ mod tests {
                 }
             },
         );
+
+        // virtual_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[4],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "virtualInvokableWrapper"]
+                    fn virtual_invokable(self: &MyObject, param: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[5],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "Call the C++ virtual method virtualInvokable, this calls any override from a C++ subclass.\n\nDo not call this from the Rust implementation of virtual_invokable, as it would then call itself forever."]
+                    #[cxx_name = "virtualInvokable"]
+                    fn virtual_invokable_dispatch(self: &MyObject, param: i32) -> i32;
+                }
+            },
+        );
+
+        // final_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[6],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "finalInvokableWrapper"]
+                    fn final_invokable(self: &MyObject);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_not_subclassable() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn virtual_invokable(self: &MyObject); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: {
+                let mut specifiers = HashSet::new();
+                specifiers.insert(ParsedQInvokableSpecifiers::Virtual);
+                specifiers
+            },
+            is_qinvokable: true,
+            return_ownership: None,
+            docs: vec![],
+            cfgs: vec![],
+        }];
+        let qobject_idents = create_qobjectname();
+
+        // Without #[cxx_subclassable] there are no overrides to dispatch to
+        let generated = generate_rust_methods(&invokables, &qobject_idents, false).unwrap();
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "virtualInvokableWrapper"]
+                    fn virtual_invokable(self: &MyObject);
+                }
+            },
+        );
     }
 }
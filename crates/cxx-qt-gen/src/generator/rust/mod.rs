@@ -90,6 +90,7 @@ fn generate_include(parser: &Parser) -> Result<Item> {
 
 #[cfg(test)]
 mod tests {
+    use quote::ToTokens;
     use syn::parse_quote;
 
     use super::*;
@@ -171,4 +172,51 @@ mod tests {
         assert_eq!(rust.namespace, "");
         assert_eq!(rust.fragments.len(), 1);
     }
+
+    #[test]
+    fn test_generated_rust_blocks_cfgs() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cfg(feature = "a")]
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[cfg_attr(unix, qproperty(i32, number))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[cfg(feature = "b")]
+                    #[qinvokable]
+                    fn invokable(self: &MyObject);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        let items: Vec<String> = rust.fragments[0]
+            .cxx_mod_contents
+            .iter()
+            .chain(rust.fragments[0].cxx_qt_mod_contents.iter())
+            .map(|item| item.to_token_stream().to_string())
+            .collect();
+        // Every generated item of the QObject has the cfg of its extern "RustQt" block
+        assert!(items
+            .iter()
+            .all(|item| item.starts_with("# [cfg (feature = \"a\")]")));
+        // The cfg is not repeated for the items of the invokable, which also have their own cfg
+        let invokable: Vec<&String> = items
+            .iter()
+            .filter(|item| item.contains("fn invokable"))
+            .collect();
+        assert_eq!(invokable.len(), 1);
+        assert_eq!(invokable[0].matches("cfg (feature = \"a\")").count(), 1);
+        assert!(invokable[0].contains("# [cfg (feature = \"b\")]"));
+        // The property is enabled by the predicate of its #[cfg_attr]
+        let property: Vec<&String> = items
+            .iter()
+            .filter(|item| item.contains("fn number") || item.contains("fn set_number"))
+            .collect();
+        assert!(!property.is_empty());
+        assert!(property.iter().all(|item| item.contains("# [cfg (unix)]")));
+    }
 }
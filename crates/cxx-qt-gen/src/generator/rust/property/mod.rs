@@ -30,27 +30,32 @@ pub fn generate_rust_properties(
 
     for property in properties {
         let idents = QPropertyName::from(property);
+        let mut accessors = GeneratedRustFragment::default();
 
         // Getters
         let getter = getter::generate(&idents, qobject_idents, &property.ty, type_names)?;
-        generated
+        accessors
             .cxx_mod_contents
             .append(&mut getter.cxx_bridge_as_items()?);
-        generated
+        accessors
             .cxx_qt_mod_contents
             .append(&mut getter.implementation_as_items()?);
 
         // Setters
         let setter = setter::generate(&idents, qobject_idents, &property.ty, type_names)?;
-        generated
+        accessors
             .cxx_mod_contents
             .append(&mut setter.cxx_bridge_as_items()?);
-        generated
+        accessors
             .cxx_qt_mod_contents
             .append(&mut setter.implementation_as_items()?);
 
+        generated.append(&mut accessors.with_cfgs(&property.cfgs));
+
         // Signals
-        signals.push(signal::generate(&idents, qobject_idents));
+        let mut signal = signal::generate(&idents, qobject_idents);
+        signal.cfgs.clone_from(&property.cfgs);
+        signals.push(signal);
     }
 
     generated.append(&mut generate_rust_signals(
@@ -77,14 +82,23 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
             ParsedQProperty {
                 ident: format_ident!("unsafe_property"),
                 ty: parse_quote! { *mut T },
+                ownership: None,
+                docs: vec![],
+                cfgs: vec![],
             },
         ];
         let qobject_idents = create_qobjectname();
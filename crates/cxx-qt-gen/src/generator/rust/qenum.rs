@@ -13,6 +13,7 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
         .flat_map(|qenum| {
             let qenum_item = &qenum.item;
             let qenum_ident = &qenum.ident;
+            let cfgs = &qenum.cfgs;
             let namespace = &qenum.namespace;
             let namespace = if namespace.is_empty() {
                 quote! {}
@@ -27,6 +28,7 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
                 },
                 parse_quote_spanned! {
                     qenum.item.span() =>
+                    #(#cfgs)*
                     extern "C++" {
                         #namespace
                         type #qenum_ident;
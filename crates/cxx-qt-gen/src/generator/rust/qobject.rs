@@ -19,7 +19,8 @@ use crate::{
     naming::TypeNames,
     parser::qobject::ParsedQObject,
 };
-use quote::quote;
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
 use syn::{Ident, Result};
 
 use super::qenum;
@@ -40,6 +41,31 @@ impl GeneratedRustFragment {
             &namespace_idents.namespace,
         )?);
 
+        // If the QObject is an instance of a generic type then name the instance,
+        // as the extern "Rust" type in the CXX bridge needs to be a single ident
+        //
+        // The alias is declared in a module next to the bridge, so that the path resolves
+        // as it was written inside the bridge
+        if let Some(generic_instance) = &qobject.qobject_ty.generic_instance {
+            let rust_struct_name_rust = &qobject_idents.rust_struct.rust;
+            let instance_module = format_ident!(
+                "cxx_qt_{}",
+                rust_struct_name_rust.to_string().to_case(Case::Snake)
+            );
+            generated.cxx_qt_mod_contents.push(syn::parse_quote! {
+                #[doc(hidden)]
+                mod #instance_module {
+                    #[allow(unused_imports)]
+                    use super::#module_ident::*;
+
+                    pub type #rust_struct_name_rust = #generic_instance;
+                }
+            });
+            generated.cxx_qt_mod_contents.push(syn::parse_quote! {
+                pub use #instance_module::#rust_struct_name_rust;
+            });
+        }
+
         // Generate methods for the properties, invokables, signals
         generated.append(&mut generate_rust_properties(
             &qobject.properties,
@@ -50,6 +76,7 @@ impl GeneratedRustFragment {
         generated.append(&mut generate_rust_methods(
             &qobject.methods,
             &qobject_idents,
+            qobject.subclassable,
         )?);
         generated.append(&mut inherit::generate(
             &qobject_idents,
@@ -99,6 +126,13 @@ impl GeneratedRustFragment {
             generated.cxx_qt_mod_contents.push(syn::parse_quote! {
                 impl cxx_qt::Locking for #qualified_impl {}
             });
+
+            // The trait requires the Rust struct to be Sync as it can be read from multiple threads at once
+            if qobject.shared_locking {
+                generated.cxx_qt_mod_contents.push(syn::parse_quote! {
+                    impl cxx_qt::SharedLocking for #qualified_impl {}
+                });
+            }
         }
 
         generated.append(&mut constructor::generate(
@@ -109,9 +143,18 @@ impl GeneratedRustFragment {
             module_ident,
         )?);
 
+        // If the QML singleton has a Rust factory then add the function used by the C++ create
+        if qobject.singleton_factory {
+            generated.append(&mut constructor::generate_singleton_factory(
+                &qobject_idents,
+                &namespace_idents,
+                type_names,
+            )?);
+        }
+
         generated.append(&mut cxxqttype::generate(&qobject_idents, type_names)?);
 
-        Ok(generated)
+        Ok(generated.with_cfgs(&qobject.cfgs))
     }
 }
 
@@ -253,4 +296,51 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generated_rust_qobject_blocks_generic() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type ContactModel = super::ListModel<Contact>;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustFragment::from_qobject(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &parser.type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    type ContactModelRust;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                mod cxx_qt_contact_model_rust {
+                    #[allow(unused_imports)]
+                    use super::ffi::*;
+
+                    pub type ContactModelRust = super::ListModel<Contact>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[1],
+            quote! {
+                pub use cxx_qt_contact_model_rust::ContactModelRust;
+            },
+        );
+    }
 }
@@ -202,7 +202,7 @@ pub fn generate_rust_signal(
         .cxx_qt_mod_contents
         .append(&mut fragment.implementation_as_items()?);
 
-    Ok(generated)
+    Ok(generated.with_cfgs(&signal.cfgs))
 }
 
 pub fn generate_rust_signals(
@@ -271,6 +271,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
         let qobject_idents = create_qobjectname();
 
@@ -422,10 +424,14 @@ mod tests {
                 ParsedFunctionParameter {
                     ident: format_ident!("trivial"),
                     ty: parse_quote! { i32 },
+                    default: None,
+                    ownership: None,
                 },
                 ParsedFunctionParameter {
                     ident: format_ident!("opaque"),
                     ty: parse_quote! { UniquePtr<QColor> },
+                    default: None,
+                    ownership: None,
                 },
             ],
             ident: CombinedIdent {
@@ -435,6 +441,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
         let qobject_idents = create_qobjectname();
 
@@ -589,6 +597,8 @@ mod tests {
             parameters: vec![ParsedFunctionParameter {
                 ident: format_ident!("param"),
                 ty: parse_quote! { *mut T },
+                default: None,
+                ownership: None,
             }],
             ident: CombinedIdent {
                 cpp: format_ident!("unsafeSignal"),
@@ -597,6 +607,8 @@ mod tests {
             safe: false,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
         let qobject_idents = create_qobjectname();
 
@@ -755,6 +767,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
         let qobject_idents = create_qobjectname();
 
@@ -910,6 +924,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let generated = generate_rust_signal(
@@ -1062,6 +1078,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: true,
+            docs: vec![],
+            cfgs: vec![],
         };
 
         let generated = generate_rust_signal(
@@ -13,7 +13,7 @@ use crate::{
     },
     naming::TypeNames,
 };
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Ident, Result};
 
 use super::fragment::RustFragmentPair;
@@ -29,7 +29,10 @@ pub fn generate(
     let cpp_struct_ident = &qobject_ident.cpp_class.rust;
     let cxx_qt_thread_ident = &qobject_ident.cxx_qt_thread_class;
     let cxx_qt_thread_queued_fn_ident = &qobject_ident.cxx_qt_thread_queued_fn_struct;
+    let cxx_qt_thread_queue_status_ident = format_ident!("{cxx_qt_thread_ident}QueueStatus");
     let cxx_qt_thread_queue_fn = qobject_ident.cxx_qt_thread_method("queue_boxed_fn");
+    let cxx_qt_thread_is_current_thread = qobject_ident.cxx_qt_thread_method("is_current_thread");
+    let cxx_qt_thread_is_destroyed = qobject_ident.cxx_qt_thread_method("is_destroyed");
     let cxx_qt_thread_clone = qobject_ident.cxx_qt_thread_method("threading_clone");
     let cxx_qt_thread_drop = qobject_ident.cxx_qt_thread_method("threading_drop");
     let namespace_internals = &namespace_ident.internal;
@@ -51,6 +54,11 @@ pub fn generate(
                     type #cxx_qt_thread_ident = cxx_qt::CxxQtThread<#cpp_struct_ident>;
                     include!("cxx-qt/thread.h");
 
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "CxxQtThreadQueueStatus"]
+                    type #cxx_qt_thread_queue_status_ident = cxx_qt::CxxQtThreadQueueStatus;
+
                     #[doc(hidden)]
                     #[cxx_name = "qtThread"]
                     fn cxx_qt_ffi_qt_thread(self: &#cpp_struct_ident) -> #cxx_qt_thread_ident;
@@ -65,7 +73,17 @@ pub fn generate(
                         cxx_qt_thread: &#cxx_qt_thread_ident,
                         func: fn(Pin<&mut #cpp_struct_ident>, Box<#cxx_qt_thread_queued_fn_ident>),
                         arg: Box<#cxx_qt_thread_queued_fn_ident>,
-                    ) -> Result<()>;
+                    ) -> #cxx_qt_thread_queue_status_ident;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "cxxQtThreadIsCurrentThread"]
+                    fn #cxx_qt_thread_is_current_thread(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "cxxQtThreadIsDestroyed"]
+                    fn #cxx_qt_thread_is_destroyed(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
 
                     #[doc(hidden)]
                     #[namespace = "rust::cxxqt1"]
@@ -97,7 +115,7 @@ pub fn generate(
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx_qt::CxxQtThreadError>
                     where
                         F: FnOnce(core::pin::Pin<&mut #qualified_impl>),
                         F: Send + 'static,
@@ -114,7 +132,19 @@ pub fn generate(
                             (arg.inner)(obj)
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
-                        #module_ident::#cxx_qt_thread_queue_fn(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        #module_ident::#cxx_qt_thread_queue_fn(cxx_qt_thread, func, std::boxed::Box::new(arg)).into()
+                    }
+
+                    #[doc(hidden)]
+                    fn is_current_thread(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #module_ident::#cxx_qt_thread_is_current_thread(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    fn is_destroyed(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #module_ident::#cxx_qt_thread_is_destroyed(cxx_qt_thread)
                     }
 
                     #[doc(hidden)]
@@ -189,6 +219,11 @@ mod tests {
                     type MyObjectCxxQtThread = cxx_qt::CxxQtThread<MyObject>;
                     include!("cxx-qt/thread.h");
 
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "CxxQtThreadQueueStatus"]
+                    type MyObjectCxxQtThreadQueueStatus = cxx_qt::CxxQtThreadQueueStatus;
+
                     #[doc(hidden)]
                     #[cxx_name = "qtThread"]
                     fn cxx_qt_ffi_qt_thread(self: &MyObject) -> MyObjectCxxQtThread;
@@ -203,7 +238,17 @@ mod tests {
                         cxx_qt_thread: &MyObjectCxxQtThread,
                         func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
                         arg: Box<MyObjectCxxQtThreadQueuedFn>,
-                    ) -> Result<()>;
+                    ) -> MyObjectCxxQtThreadQueueStatus;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "cxxQtThreadIsCurrentThread"]
+                    fn cxx_qt_ffi_my_object_is_current_thread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "cxxQtThreadIsDestroyed"]
+                    fn cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
 
                     #[doc(hidden)]
                     #[namespace = "rust::cxxqt1"]
@@ -241,7 +286,7 @@ mod tests {
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx_qt::CxxQtThreadError>
                     where
                         F: FnOnce(core::pin::Pin<&mut qobject::MyObject>),
                         F: Send + 'static,
@@ -258,7 +303,19 @@ mod tests {
                             (arg.inner)(obj)
                         }
                         let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
-                        qobject::cxx_qt_ffi_my_object_queue_boxed_fn(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        qobject::cxx_qt_ffi_my_object_queue_boxed_fn(cxx_qt_thread, func, std::boxed::Box::new(arg)).into()
+                    }
+
+                    #[doc(hidden)]
+                    fn is_current_thread(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool
+                    {
+                        qobject::cxx_qt_ffi_my_object_is_current_thread(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    fn is_destroyed(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool
+                    {
+                        qobject::cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread)
                     }
 
                     #[doc(hidden)]
@@ -0,0 +1,219 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A hash of the generated Rust code of a bridge
+//!
+//! The `cxx_qt::bridge` macro and cxx-qt-build both parse the bridge and generate its code
+//! independently. cxx-qt-build writes the hash of its Rust code into `OUT_DIR`, and the macro
+//! asserts at compile time that its own code has the same hash, so that a bridge which differs
+//! between the two is reported at the bridge rather than as a linker error.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::quote_spanned;
+use syn::{Lit, LitStr};
+
+use crate::CfgSet;
+
+/// Compute the hash of the generated Rust code of a bridge
+///
+/// The hash only depends on the tokens, and not on their spans, spacing,
+/// or how string literals are written, as these differ between the macro and cxx-qt-build.
+pub fn bridge_hash(tokens: &TokenStream) -> u64 {
+    let mut hasher = Fnv1a::default();
+    hash_tokens(tokens, &mut hasher);
+    hasher.0
+}
+
+/// The path of the file in the given `OUT_DIR` containing the hash of a bridge
+pub fn bridge_hash_path(out_dir: impl AsRef<Path>, cxx_file_stem: &str) -> PathBuf {
+    out_dir
+        .as_ref()
+        .join("cxx-qt-gen")
+        .join(format!("{cxx_file_stem}.cxxqt.hash"))
+}
+
+/// Write the hash of a bridge so that it can be included by [bridge_hash_check]
+pub fn write_bridge_hash(path: impl AsRef<Path>, hash: u64) -> std::io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{hash:#018x}_u64\n"))
+}
+
+/// Generate a compile time assertion that the given generated Rust code has the same hash as the
+/// code generated by cxx-qt-build for the bridge
+///
+/// There is no assertion if the crate is not built with cxx-qt-build, for example doc tests.
+/// If cxx-qt-build has been used but has not written a hash for the bridge, then the file of the
+/// bridge has not been given to cxx-qt-build, so a compile error saying so is generated instead.
+pub fn bridge_hash_check(
+    tokens: &TokenStream,
+    cxx_file_stem: &str,
+    span: Span,
+) -> Option<TokenStream> {
+    check_in_out_dir(
+        Path::new(&env::var_os("OUT_DIR")?),
+        tokens,
+        cxx_file_stem,
+        span,
+    )
+}
+
+fn check_in_out_dir(
+    out_dir: &Path,
+    tokens: &TokenStream,
+    cxx_file_stem: &str,
+    span: Span,
+) -> Option<TokenStream> {
+    let path = bridge_hash_path(out_dir, cxx_file_stem);
+    if !path.exists() {
+        // cxx-qt-build always writes the cfg set, so it has not been used if that is missing
+        if !CfgSet::path(out_dir).exists() {
+            return None;
+        }
+
+        let message = format!("The CXX-Qt bridge {cxx_file_stem} has not been built by cxx-qt-build! Add the file containing this bridge to the CxxQtBuilder in the build script of this crate, with CxxQtBuilder::file or a QmlModule.");
+        return Some(quote_spanned! { span =>
+            ::core::compile_error!(#message);
+        });
+    }
+
+    let hash = bridge_hash(tokens);
+    let path = LitStr::new(&path.to_string_lossy(), span);
+    let message = format!("The C++ code generated by cxx-qt-build for the CXX-Qt bridge {cxx_file_stem} does not match this bridge! Ensure that the build script has been rerun and that the file passed to CxxQtBuilder is the one containing this bridge, without different cfg attributes or macros.");
+    Some(quote_spanned! { span =>
+        #[doc(hidden)]
+        const _: () = ::core::assert!(#hash == ::core::include!(#path), #message);
+    })
+}
+
+/// A 64-bit FNV-1a hasher, which unlike [std::hash::DefaultHasher] is stable
+#[derive(Debug)]
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+        // Separate each token
+        self.0 ^= 0xff;
+        self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+    }
+}
+
+fn hash_tokens(tokens: &TokenStream, hasher: &mut Fnv1a) {
+    for token in tokens.clone() {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    // Invisible groups are only created by macro_rules, so are transparent
+                    Delimiter::None => ("", ""),
+                };
+                hasher.write(open.as_bytes());
+                hash_tokens(&group.stream(), hasher);
+                hasher.write(close.as_bytes());
+            }
+            TokenTree::Ident(ident) => hasher.write(ident.to_string().as_bytes()),
+            TokenTree::Punct(punct) => hasher.write(punct.as_char().to_string().as_bytes()),
+            // Doc comments are raw strings in a macro, but not when parsed from a file
+            TokenTree::Literal(literal) => match Lit::new(literal.clone()) {
+                Lit::Str(lit_str) => hasher.write(format!("{:?}", lit_str.value()).as_bytes()),
+                _ => hasher.write(literal.to_string().as_bytes()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::TempDir;
+    use quote::quote;
+
+    #[test]
+    fn test_bridge_hash_ignores_formatting() {
+        let tokens: TokenStream =
+            syn::parse_str("#[doc = r\" A doc\"] mod ffi { type A :: B; }").unwrap();
+        let expected = quote! {
+            #[doc = " A doc"]
+            mod ffi {
+                type A::B;
+            }
+        };
+        assert_eq!(bridge_hash(&tokens), bridge_hash(&expected));
+    }
+
+    #[test]
+    fn test_bridge_hash_differs() {
+        let hash = bridge_hash(&quote! { mod ffi { fn a(); } });
+        assert_ne!(hash, bridge_hash(&quote! { mod ffi { fn b(); } }));
+        assert_ne!(hash, bridge_hash(&quote! { mod ffi { fn a() } }));
+        assert_ne!(hash, bridge_hash(&quote! { mod ffi { fn a(); fn a(); } }));
+        assert_ne!(hash, bridge_hash(&quote! { mod ffi { fn a(""); } }));
+    }
+
+    #[test]
+    fn test_write_bridge_hash() {
+        let out_dir = TempDir::new("cxx-qt-gen-hash");
+        let path = bridge_hash_path(out_dir.path(), "my_object");
+        assert!(path.ends_with("cxx-qt-gen/my_object.cxxqt.hash"));
+
+        write_bridge_hash(&path, 0x1234).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "0x0000000000001234_u64\n");
+        assert_eq!(
+            syn::parse_str::<syn::LitInt>(contents.trim())
+                .unwrap()
+                .base10_parse::<u64>()
+                .unwrap(),
+            0x1234
+        );
+    }
+
+    #[test]
+    fn test_bridge_hash_check() {
+        let temp_dir = TempDir::new("cxx-qt-gen-check");
+        let out_dir = temp_dir.path();
+        let tokens = quote! { mod ffi {} };
+        let span = Span::call_site();
+
+        // Without cxx-qt-build there is no check
+        assert!(check_in_out_dir(out_dir, &tokens, "my_object", span).is_none());
+
+        // With cxx-qt-build but without the bridge it is a compile error
+        CfgSet::default().write(CfgSet::path(out_dir)).unwrap();
+        let check = check_in_out_dir(out_dir, &tokens, "my_object", span)
+            .unwrap()
+            .to_string();
+        assert!(check.contains("compile_error"));
+        assert!(check.contains("CxxQtBuilder"));
+
+        // With the hash of the bridge it is an assertion
+        write_bridge_hash(bridge_hash_path(out_dir, "my_object"), bridge_hash(&tokens)).unwrap();
+        let check = check_in_out_dir(out_dir, &tokens, "my_object", span)
+            .unwrap()
+            .to_string();
+        assert!(check.contains("assert"));
+        assert!(check.contains("my_object.cxxqt.hash"));
+    }
+}
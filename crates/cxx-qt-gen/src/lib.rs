@@ -8,19 +8,34 @@
 
 //! The cxx-qt-gen crate provides methods for generated C++ and Rust code from a TokenStream.
 
+mod cfg;
 mod generator;
+mod hash;
 mod naming;
 mod parser;
+mod registry;
 mod syntax;
+mod tempdir;
 mod writer;
 
+pub use cfg::CfgSet;
 pub use generator::{
+    api::{
+        ApiDescription, MethodDescription, ParameterDescription, PropertyDescription,
+        QEnumDescription, QEnumValueDescription, QObjectDescription,
+    },
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
     rust::GeneratedRustBlocks,
 };
+pub use hash::{bridge_hash, bridge_hash_check, bridge_hash_path, write_bridge_hash};
 pub use parser::Parser;
+pub use registry::{RegisteredType, TypeRegistry};
 pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
-pub use writer::{cpp::write_cpp, rust::write_rust};
+#[doc(hidden)]
+pub use tempdir::TempDir;
+#[cfg(feature = "serde")]
+pub use writer::api::write_api_json;
+pub use writer::{api::write_api_markdown, cpp::write_cpp, rust::write_rust};
 
 pub use syn::{Error, Result};
 
@@ -201,6 +216,11 @@ mod tests {
         test_code_generation!("inheritance");
     }
 
+    #[test]
+    fn generates_subclassable() {
+        test_code_generation!("subclassable");
+    }
+
     #[test]
     fn generates_qenum() {
         test_code_generation!("qenum");
@@ -3,9 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::syntax::attribute::{attribute_find_path, attribute_take_path};
+use crate::syntax::attribute::{attribute_find_path, attribute_take_cfgs, attribute_take_path};
 use crate::syntax::foreignmod::ForeignTypeIdentAlias;
-use crate::syntax::path::path_compare_str;
+use crate::syntax::path::{path_compare_str, path_normalize};
 use crate::syntax::safety::Safety;
 use crate::{
     parser::{
@@ -71,22 +71,30 @@ impl ParsedCxxQtData {
                         .transpose()?
                         .or_else(|| self.namespace.clone());
 
+                    // The cfgs of the block apply to each of the QObjects in it
+                    let cfgs = attribute_take_cfgs(&mut foreign_mod.attrs.clone())?;
+
                     for foreign_item in &foreign_mod.items {
                         match foreign_item {
                             // Fn are parsed later in parse_foreign_mod_rust_qt
                             ForeignItem::Fn(_) => {}
                             ForeignItem::Verbatim(tokens) => {
-                                let mut foreign_alias: ForeignTypeIdentAlias =
+                                let foreign_alias: ForeignTypeIdentAlias =
                                     syn::parse2(tokens.clone())?;
 
                                 // Check this type is tagged with a #[qobject]
                                 let has_qobject_macro =
-                                    attribute_take_path(&mut foreign_alias.attrs, &["qobject"])
+                                    attribute_find_path(&foreign_alias.attrs, &["qobject"])
                                         .is_some();
 
                                 // Load the QObject
+                                //
+                                // The #[qobject] attribute is removed afterwards, so that doc comments
+                                // between it and a #[qproperty] are known to document the property
                                 let mut qobject = ParsedQObject::try_from(&foreign_alias)?;
+                                attribute_take_path(&mut qobject.qobject_ty.attrs, &["qobject"]);
                                 qobject.has_qobject_macro = has_qobject_macro;
+                                qobject.cfgs.splice(0..0, cfgs.iter().cloned());
 
                                 // Ensure that the base class attribute is not empty, as this is not valid in both cases
                                 // - when there is a qobject macro it is not valid
@@ -114,6 +122,25 @@ impl ParsedCxxQtData {
                                     qobject.namespace = namespace.clone().unwrap();
                                 }
 
+                                // Each generic instance gets its own name, so ensure that two QObjects
+                                // do not instantiate the same type as the instances would conflict
+                                if let Some(generic_instance) = &qobject.qobject_ty.generic_instance
+                                {
+                                    let instance = path_normalize(generic_instance);
+                                    if self.qobjects.values().any(|other| {
+                                        other
+                                            .qobject_ty
+                                            .generic_instance
+                                            .as_ref()
+                                            .is_some_and(|other| path_normalize(other) == instance)
+                                    }) {
+                                        return Err(Error::new(
+                                            generic_instance.span(),
+                                            "A generic type instance can only be used by one QObject",
+                                        ));
+                                    }
+                                }
+
                                 // Note that we assume a compiler error will occur later
                                 // if you had two structs with the same name
                                 self.qobjects
@@ -228,11 +255,15 @@ impl ParsedCxxQtData {
             Safety::Unsafe
         };
 
+        // The cfgs of the block apply to each of the methods in it
+        let cfgs = attribute_take_cfgs(&mut foreign_mod.attrs)?;
+
         for item in foreign_mod.items.drain(..) {
             if let ForeignItem::Fn(mut foreign_fn) = item {
                 // Test if the function is a signal
                 if attribute_take_path(&mut foreign_fn.attrs, &["qsignal"]).is_some() {
-                    let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                    let mut parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                    parsed_signal_method.cfgs.splice(0..0, cfgs.iter().cloned());
 
                     self.with_qobject(&parsed_signal_method.qobject_ident)?
                         .signals
@@ -241,15 +272,19 @@ impl ParsedCxxQtData {
                 //
                 // Note that we need to test for qsignal first as qsignals have their own inherit meaning
                 } else if attribute_take_path(&mut foreign_fn.attrs, &["inherit"]).is_some() {
-                    let parsed_inherited_method =
+                    let mut parsed_inherited_method =
                         ParsedInheritedMethod::parse(foreign_fn, safe_call)?;
+                    parsed_inherited_method
+                        .cfgs
+                        .splice(0..0, cfgs.iter().cloned());
 
                     self.with_qobject(&parsed_inherited_method.qobject_ident)?
                         .inherited_methods
                         .push(parsed_inherited_method);
                 // Remaining methods are either C++ methods or invokables
                 } else {
-                    let parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
+                    let mut parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
+                    parsed_method.cfgs.splice(0..0, cfgs.iter().cloned());
                     self.with_qobject(&parsed_method.qobject_ident)?
                         .methods
                         .push(parsed_method);
@@ -365,6 +400,68 @@ mod tests {
             .contains_key(&format_ident!("SecondObject")));
     }
 
+    #[test]
+    fn test_find_qobjects_generic_instances() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    type ContactModel = super::ListModel<Contact>;
+                    #[qobject]
+                    type MessageModel = super::ListModel<Message>;
+                }
+            }
+        };
+        cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .unwrap();
+        assert_eq!(cxx_qt_data.qobjects.len(), 2);
+        let qobject = cxx_qt_data
+            .qobjects
+            .get(&format_ident!("MessageModel"))
+            .unwrap();
+        assert_eq!(qobject.qobject_ty.ident_right, "MessageModelRust");
+        assert!(qobject.qobject_ty.generic_instance.is_some());
+    }
+
+    #[test]
+    fn test_find_qobjects_generic_instance_duplicate() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    type ContactModel = super::ListModel<Contact>;
+                    #[qobject]
+                    type OtherContactModel = super::ListModel<Contact>;
+                }
+            }
+        };
+        let result = cxx_qt_data.find_qobject_types(&module.content.unwrap().1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_qobjects_generic_instance_duplicate_normalized() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    type ContactModel = super::ListModel<super::Contact>;
+                    #[qobject]
+                    type OtherContactModel = super::ListModel<super::Contact,>;
+                }
+            }
+        };
+        let result = cxx_qt_data.find_qobject_types(&module.content.unwrap().1);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_find_qobjects_namespace() {
         let mut cxx_qt_data =
@@ -5,7 +5,10 @@
 
 use crate::{
     parser::signals::ParsedSignal,
-    syntax::{attribute::attribute_find_path, safety::Safety},
+    syntax::{
+        attribute::{attribute_find_path, attribute_take_cfgs},
+        safety::Safety,
+    },
 };
 use syn::{spanned::Spanned, Attribute, Error, ForeignItem, ItemForeignMod, Result, Token};
 
@@ -14,6 +17,8 @@ use syn::{spanned::Spanned, Attribute, Error, ForeignItem, ItemForeignMod, Resul
 pub struct ParsedExternCxxQt {
     /// Attributes for the extern "C++Qt" block
     pub attrs: Vec<Attribute>,
+    /// The `#[cfg]` attributes of the extern "C++Qt" block
+    pub cfgs: Vec<Attribute>,
     /// Whether this block has an unsafe token
     pub unsafety: Option<Token![unsafe]>,
     /// Items which can be passed into the extern "C++Qt" block
@@ -25,6 +30,7 @@ pub struct ParsedExternCxxQt {
 impl ParsedExternCxxQt {
     pub fn parse(mut foreign_mod: ItemForeignMod) -> Result<Self> {
         let mut extern_cxx_block = ParsedExternCxxQt {
+            cfgs: attribute_take_cfgs(&mut foreign_mod.attrs)?,
             attrs: foreign_mod.attrs.clone(),
             unsafety: foreign_mod.unsafety,
             ..Default::default()
@@ -7,11 +7,15 @@ use crate::{
     generator::naming::CombinedIdent,
     parser::parameter::ParsedFunctionParameter,
     syntax::{
-        attribute::attribute_take_path, expr::expr_to_string, foreignmod, safety::Safety, types,
+        attribute::{attribute_take_cfgs, attribute_take_path},
+        expr::expr_to_string,
+        foreignmod,
+        safety::Safety,
+        types,
     },
 };
 use quote::format_ident;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result};
 
 /// Describes a method found in an extern "RustQt" with #[inherit]
 pub struct ParsedInheritedMethod {
@@ -27,6 +31,8 @@ pub struct ParsedInheritedMethod {
     pub parameters: Vec<ParsedFunctionParameter>,
     /// the name of the function in Rust, as well as C++
     pub ident: CombinedIdent,
+    /// The `#[cfg]` attributes of the method and its extern "RustQt" block
+    pub cfgs: Vec<Attribute>,
 }
 
 impl ParsedInheritedMethod {
@@ -38,6 +44,8 @@ impl ParsedInheritedMethod {
             ));
         }
 
+        let cfgs = attribute_take_cfgs(&mut method.attrs)?;
+
         let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
         let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
         let mutable = mutability.is_some();
@@ -62,6 +70,7 @@ impl ParsedInheritedMethod {
             parameters,
             ident,
             safe,
+            cfgs,
         })
     }
 
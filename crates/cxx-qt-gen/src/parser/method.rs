@@ -4,11 +4,22 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::parameter::ParsedFunctionParameter,
-    syntax::{attribute::attribute_take_path, foreignmod, safety::Safety, types},
+    parser::{ownership::ParsedQmlOwnership, parameter::ParsedFunctionParameter},
+    syntax::{
+        attribute::{
+            attribute_docs, attribute_find_path, attribute_take_cfgs, attribute_take_path,
+        },
+        expr::expr_to_string,
+        foreignmod,
+        safety::Safety,
+        types,
+    },
 };
 use std::collections::HashSet;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{
+    spanned::Spanned, Attribute, Error, FnArg, ForeignItemFn, Ident, Meta, Pat, PatIdent, Result,
+    ReturnType,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -44,6 +55,12 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
+    /// The QML ownership to set on a returned QObject pointer, if there is one
+    pub return_ownership: Option<ParsedQmlOwnership>,
+    /// The lines of the doc comments of the method
+    pub docs: Vec<String>,
+    /// The `#[cfg]` attributes of the method and its extern "RustQt" block
+    pub cfgs: Vec<Attribute>,
 }
 
 impl ParsedMethod {
@@ -55,8 +72,36 @@ impl ParsedMethod {
             ));
         }
 
+        let cfgs = attribute_take_cfgs(&mut method.attrs)?;
+
+        // Ensure that any cxx_name is a string, as this is read when naming the method
+        if let Some(index) = attribute_find_path(&method.attrs, &["cxx_name"]) {
+            expr_to_string(&method.attrs[index].meta.require_name_value()?.value)?;
+        }
+
         // Determine if the method is invokable
-        let is_qinvokable = attribute_take_path(&mut method.attrs, &["qinvokable"]).is_some();
+        //
+        // An invokable returning a QObject pointer can specify the ownership, eg #[qinvokable(js_owned)]
+        let mut return_ownership = None;
+        let is_qinvokable = if let Some(attr) =
+            attribute_take_path(&mut method.attrs, &["qinvokable"])
+        {
+            if let Meta::List(list) = &attr.meta {
+                let ownership = ParsedQmlOwnership::parse(&list.parse_args::<Ident>()?)?;
+                if let ReturnType::Type(_, ty) = &method.sig.output {
+                    ParsedQmlOwnership::check_type(ty)?;
+                } else {
+                    return Err(Error::new_spanned(
+                            &attr,
+                            "An ownership can only be specified for an invokable returning a QObject pointer",
+                        ));
+                }
+                return_ownership = Some(ownership);
+            }
+            true
+        } else {
+            false
+        };
 
         // Parse any C++ specifiers
         let mut specifiers = HashSet::new();
@@ -75,9 +120,11 @@ impl ParsedMethod {
         let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
         let mutable = mutability.is_some();
 
-        let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+        let mut parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+        Self::parse_parameter_attributes(&mut method, &mut parameters)?;
 
         let safe = method.sig.unsafety.is_none();
+        let docs = attribute_docs(&method.attrs);
 
         Ok(ParsedMethod {
             method,
@@ -87,6 +134,147 @@ impl ParsedMethod {
             specifiers,
             safe,
             is_qinvokable,
+            return_ownership,
+            docs,
+            cfgs,
         })
     }
+
+    /// Whether the method is virtual and not final, so a C++ subclass can override it
+    pub fn is_overridable(&self) -> bool {
+        (self
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Virtual)
+            || self
+                .specifiers
+                .contains(&ParsedQInvokableSpecifiers::Override))
+            && !self.specifiers.contains(&ParsedQInvokableSpecifiers::Final)
+    }
+
+    /// Take any `#[cxx_default = "..."]` and ownership attributes from the parameters of the method
+    ///
+    /// As in C++ only trailing parameters can have a default value.
+    fn parse_parameter_attributes(
+        method: &mut ForeignItemFn,
+        parameters: &mut [ParsedFunctionParameter],
+    ) -> Result<()> {
+        // Find the attributes of the same parameters as ParsedFunctionParameter, ignoring self
+        let parameter_attrs = method
+            .sig
+            .inputs
+            .iter_mut()
+            .filter_map(|input| match input {
+                FnArg::Typed(type_pattern) => match &*type_pattern.pat {
+                    Pat::Ident(PatIdent { ident, .. }) if ident == "self" => None,
+                    _ => Some(&mut type_pattern.attrs),
+                },
+                FnArg::Receiver(_) => None,
+            });
+
+        let mut has_default = false;
+        for (parameter, attrs) in parameters.iter_mut().zip(parameter_attrs) {
+            parameter.ownership = ParsedQmlOwnership::take_from_attrs(attrs)?;
+            if parameter.ownership.is_some() {
+                ParsedQmlOwnership::check_type(&parameter.ty)?;
+            }
+
+            if let Some(attr) = attribute_take_path(attrs, &["cxx_default"]) {
+                parameter.default = Some(expr_to_string(&attr.meta.require_name_value()?.value)?);
+                has_default = true;
+            } else if has_default {
+                return Err(Error::new(
+                    parameter.ident.span(),
+                    "Parameters after a parameter with a #[cxx_default] must also have a #[cxx_default]",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_parameter_defaults() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            fn invokable(self: &MyObject, a: i32, #[cxx_default = "1"] b: i32, #[cxx_default = "QStringLiteral(\"c\")"] c: QString);
+        };
+        let parsed = ParsedMethod::parse(method, Safety::Safe).unwrap();
+        assert_eq!(parsed.parameters.len(), 3);
+        assert_eq!(parsed.parameters[0].default, None);
+        assert_eq!(parsed.parameters[1].default, Some("1".to_owned()));
+        assert_eq!(
+            parsed.parameters[2].default,
+            Some("QStringLiteral(\"c\")".to_owned())
+        );
+        // The attributes are removed from the parameters
+        assert!(parsed.method.sig.inputs.iter().all(|input| match input {
+            FnArg::Typed(type_pattern) => type_pattern.attrs.is_empty(),
+            FnArg::Receiver(_) => true,
+        }));
+    }
+
+    #[test]
+    fn test_parse_parameter_defaults_not_trailing() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            fn invokable(self: &MyObject, #[cxx_default = "1"] a: i32, b: i32);
+        };
+        assert!(ParsedMethod::parse(method, Safety::Safe).is_err());
+    }
+
+    #[test]
+    fn test_parse_ownership() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable(js_owned)]
+            unsafe fn invokable(self: &MyObject, #[cpp_owned] parent: *mut QObject) -> *mut MyObject;
+        };
+        let parsed = ParsedMethod::parse(method, Safety::Safe).unwrap();
+        assert!(parsed.is_qinvokable);
+        assert_eq!(
+            parsed.return_ownership,
+            Some(ParsedQmlOwnership::JavaScript)
+        );
+        assert_eq!(
+            parsed.parameters[0].ownership,
+            Some(ParsedQmlOwnership::Cpp)
+        );
+    }
+
+    #[test]
+    fn test_parse_ownership_invalid() {
+        // Ownership without a return type
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable(js_owned)]
+            fn invokable(self: &MyObject);
+        };
+        assert!(ParsedMethod::parse(method, Safety::Safe).is_err());
+
+        // Ownership of a value which is not a pointer
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable(cpp_owned)]
+            fn invokable(self: &MyObject) -> i32;
+        };
+        assert!(ParsedMethod::parse(method, Safety::Safe).is_err());
+
+        // Ownership of a parameter which is not a pointer
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            fn invokable(self: &MyObject, #[js_owned] value: i32);
+        };
+        assert!(ParsedMethod::parse(method, Safety::Safe).is_err());
+
+        // Unknown ownership
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable(rust_owned)]
+            unsafe fn invokable(self: &MyObject) -> *mut MyObject;
+        };
+        assert!(ParsedMethod::parse(method, Safety::Safe).is_err());
+    }
 }
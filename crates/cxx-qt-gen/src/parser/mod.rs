@@ -8,6 +8,7 @@ pub mod cxxqtdata;
 pub mod externcxxqt;
 pub mod inherit;
 pub mod method;
+pub mod ownership;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
@@ -17,11 +18,13 @@ pub mod signals;
 
 use crate::{
     naming::TypeNames,
+    registry::TypeRegistry,
     syntax::{attribute::attribute_take_path, expr::expr_to_string},
 };
 use cxxqtdata::ParsedCxxQtData;
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, ItemMod, Meta, Result, Token,
+    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, Item, ItemMod, Meta, Result,
+    Token,
 };
 
 /// A struct representing a module block with CXX-Qt relevant [syn::Item]'s
@@ -78,6 +81,7 @@ impl Parser {
     fn parse_module_contents(
         mut module: ItemMod,
         namespace: Option<String>,
+        type_registry: &TypeRegistry,
     ) -> Result<(ParsedCxxQtData, ItemMod)> {
         let mut others = vec![];
 
@@ -90,6 +94,15 @@ impl Parser {
 
             // Loop through items and load into qobject or others and populate mappings
             for item in items.1.drain(..) {
+                // Types imported from sibling bridges are declared as CXX types,
+                // other use items are passed through to CXX
+                if let Item::Use(item_use) = &item {
+                    if let Some(mut imported) = type_registry.import(item_use)? {
+                        others.append(&mut imported);
+                        continue;
+                    }
+                }
+
                 // Try to find any CXX-Qt items, if found add them to the relevant
                 // qobject or extern C++Qt block. Otherwise return them to be added to other
                 if let Some(other) = cxx_qt_data.parse_cxx_qt_item(item)? {
@@ -99,15 +112,29 @@ impl Parser {
             }
         }
 
+        // Now that all of the methods are known check the QObjects
+        for qobject in cxx_qt_data.qobjects.values() {
+            qobject.check_subclassable_overloads()?;
+        }
+
         // Create a new module using only items that are not CXX-Qt items
         module.content = Some((Brace::default(), others));
         Ok((cxx_qt_data, module))
     }
 
     /// Constructs a Parser object from a given [syn::ItemMod] block
-    pub fn from(mut module: ItemMod) -> Result<Self> {
+    pub fn from(module: ItemMod) -> Result<Self> {
+        Self::from_with_type_registry(module, &TypeRegistry::default())
+    }
+
+    /// Constructs a Parser object from a given [syn::ItemMod] block,
+    /// resolving any `use` items in the block with the given [TypeRegistry]
+    pub fn from_with_type_registry(
+        mut module: ItemMod,
+        type_registry: &TypeRegistry,
+    ) -> Result<Self> {
         let (namespace, cxx_file_stem) = Self::parse_mod_attributes(&mut module)?;
-        let (cxx_qt_data, module) = Self::parse_module_contents(module, namespace)?;
+        let (cxx_qt_data, module) = Self::parse_module_contents(module, namespace, type_registry)?;
         let type_names = TypeNames::from_parsed_data(
             &cxx_qt_data,
             module
@@ -127,6 +154,14 @@ impl Parser {
             cxx_file_stem,
         })
     }
+
+    /// Whether any QObject in the module is a `#[qml_element]`, which needs to be part of a QML module
+    pub fn has_qml_elements(&self) -> bool {
+        self.cxx_qt_data
+            .qobjects
+            .values()
+            .any(|qobject| qobject.qml_metadata.is_some())
+    }
 }
 
 #[cfg(test)]
@@ -134,7 +169,7 @@ mod tests {
     use super::*;
 
     use quote::format_ident;
-    use syn::{parse_quote, ItemMod, Type};
+    use syn::{parse_quote, Attribute, ItemMod, Type};
 
     /// Helper which returns a f64 as a [syn::Type]
     pub fn f64_type() -> Type {
@@ -201,6 +236,7 @@ mod tests {
 
         assert_eq!(parser.passthrough_module.attrs.len(), 0);
         assert_eq!(parser.passthrough_module.ident, "ffi");
+        assert!(!parser.has_qml_elements());
         assert_eq!(parser.passthrough_module.content.unwrap().1.len(), 0);
         assert_eq!(parser.cxx_qt_data.namespace, Some("cxx_qt".to_owned()));
         assert_eq!(parser.cxx_qt_data.qobjects.len(), 1);
@@ -326,4 +362,211 @@ mod tests {
             "extern_namespace"
         );
     }
+
+    #[test]
+    fn test_parser_has_qml_elements() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        assert!(parser.has_qml_elements());
+    }
+
+    #[test]
+    fn test_parser_from_imported_types() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry
+            .register(parse_quote! {
+                #[cxx_qt::bridge(namespace = "other_namespace")]
+                mod other_bridge {
+                    extern "RustQt" {
+                        #[qobject]
+                        type OtherObject = super::OtherObjectRust;
+                    }
+                }
+            })
+            .unwrap();
+
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "bridge_namespace")]
+            mod ffi {
+                use super::other_bridge::OtherObject;
+
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from_with_type_registry(module, &type_registry).unwrap();
+        assert_eq!(parser.type_names.num_types(), 18);
+        assert_eq!(
+            parser
+                .type_names
+                .cxx_qualified(&format_ident!("OtherObject"))
+                .unwrap(),
+            "other_namespace::OtherObject"
+        );
+        let expected_module: ItemMod = parse_quote! {
+            mod ffi {
+                #[namespace = "other_namespace"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-gen/other_bridge.cxxqt.h");
+                    #[cxx_name = "OtherObject"]
+                    type OtherObject = super::other_bridge::OtherObject;
+                }
+            }
+        };
+        assert_eq!(parser.passthrough_module, expected_module);
+    }
+
+    #[test]
+    fn test_parser_from_unknown_imported_type() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                use super::other_bridge::OtherObject;
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let expected_module: ItemMod = parse_quote! {
+            mod ffi {
+                use super::other_bridge::OtherObject;
+            }
+        };
+        assert_eq!(parser.passthrough_module, expected_module);
+    }
+
+    #[test]
+    fn test_parser_from_cfgs() {
+        // The cfgs are not evaluated by the parser, they are kept for the generated Rust code
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cfg(feature = "a")]
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[cfg_attr(unix, qproperty(i32, number))]
+                    #[qproperty(i32, always)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[cfg(test)]
+                    #[qinvokable]
+                    fn test_only(self: &MyObject);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+
+                #[cfg(not(feature = "a"))]
+                #[qenum]
+                #[namespace = "my_namespace"]
+                enum MyEnum {
+                    A,
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let feature_a: Attribute = parse_quote! { #[cfg(feature = "a")] };
+        let unix: Attribute = parse_quote! { #[cfg(unix)] };
+        let test: Attribute = parse_quote! { #[cfg(test)] };
+        let not_feature_a: Attribute = parse_quote! { #[cfg(not(feature = "a"))] };
+        let qobject = parser
+            .cxx_qt_data
+            .qobjects
+            .get(&format_ident!("MyObject"))
+            .unwrap();
+        assert_eq!(qobject.cfgs.len(), 1);
+        assert_eq!(qobject.cfgs[0], feature_a);
+        assert_eq!(qobject.properties.len(), 2);
+        assert_eq!(qobject.properties[0].cfgs, [unix]);
+        assert!(qobject.properties[1].cfgs.is_empty());
+        assert_eq!(qobject.methods[0].cfgs, [feature_a.clone(), test]);
+        assert_eq!(qobject.signals[0].cfgs, [feature_a]);
+        assert_eq!(parser.cxx_qt_data.qenums[0].cfgs, [not_feature_a]);
+    }
+
+    #[test]
+    fn test_parser_from_cfg_attr_error() {
+        // Only a #[qproperty] can be enabled with a #[cfg_attr], as the other attributes
+        // would change the generated code in a way that is only known to rustc
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[cfg_attr(feature = "a", qinvokable)]
+                    fn invokable(self: &MyObject);
+                }
+            }
+        };
+        assert!(Parser::from(module).is_err());
+
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[cfg_attr(feature = "a", qproperty(i32, number), qml_element)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        assert!(Parser::from(module).is_err());
+    }
+
+    #[test]
+    fn test_parser_subclassable_overloaded_virtual() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[cxx_subclassable]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    #[cxx_virtual]
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_int(self: &MyObject, value: i32);
+
+                    #[qinvokable]
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_float(self: &MyObject, value: f32);
+                }
+            }
+        };
+        assert!(Parser::from(module).is_err());
+
+        // Without #[cxx_subclassable] the virtual method is not bound to Rust, so can be overloaded
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    #[cxx_virtual]
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_int(self: &MyObject, value: i32);
+
+                    #[qinvokable]
+                    #[cxx_name = "overloaded"]
+                    fn overloaded_float(self: &MyObject, value: f32);
+                }
+            }
+        };
+        assert!(Parser::from(module).is_ok());
+    }
 }
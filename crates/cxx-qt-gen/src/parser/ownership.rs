@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_take_path;
+use syn::{Attribute, Error, Ident, Result, Type, TypePath, TypePtr};
+
+/// Describes who owns a QObject pointer that is passed between QML and C++
+///
+/// This is set on the object with `QQmlEngine::setObjectOwnership`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParsedQmlOwnership {
+    /// The object is owned by C++ and is never deleted by the QML engine
+    Cpp,
+    /// The object is owned by the QML engine and is deleted when garbage collected
+    JavaScript,
+}
+
+impl ParsedQmlOwnership {
+    const CPP_OWNED: &'static str = "cpp_owned";
+    const JS_OWNED: &'static str = "js_owned";
+    const PRIMITIVES: [&'static str; 17] = [
+        "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+        "u32", "u64", "u128", "usize", "str",
+    ];
+
+    /// Parse the ownership from an ident, either `cpp_owned` or `js_owned`
+    pub fn parse(ident: &Ident) -> Result<Self> {
+        if ident == Self::CPP_OWNED {
+            Ok(Self::Cpp)
+        } else if ident == Self::JS_OWNED {
+            Ok(Self::JavaScript)
+        } else {
+            Err(Error::new(
+                ident.span(),
+                "Unknown ownership, expected either cpp_owned or js_owned",
+            ))
+        }
+    }
+
+    /// Take a `#[cpp_owned]` or `#[js_owned]` attribute from the given attributes
+    pub fn take_from_attrs(attrs: &mut Vec<Attribute>) -> Result<Option<Self>> {
+        let cpp_owned = attribute_take_path(attrs, &[Self::CPP_OWNED]);
+        let js_owned = attribute_take_path(attrs, &[Self::JS_OWNED]);
+        match (cpp_owned, js_owned) {
+            (Some(_), None) => Ok(Some(Self::Cpp)),
+            (None, Some(_)) => Ok(Some(Self::JavaScript)),
+            (None, None) => Ok(None),
+            (Some(attr), Some(_)) => Err(Error::new_spanned(
+                attr,
+                "Only one of #[cpp_owned] or #[js_owned] can be specified",
+            )),
+        }
+    }
+
+    /// Ensure that the given type is a QObject pointer, `*mut T`, as only these have an ownership
+    ///
+    /// Whether `T` is a QObject is only known to the C++ compiler, so this rejects the pointee
+    /// types which can never be a QObject, such as primitives, pointers and references.
+    pub fn check_type(ty: &Type) -> Result<()> {
+        if let Type::Ptr(TypePtr {
+            mutability: Some(_),
+            elem,
+            ..
+        }) = ty
+        {
+            if let Type::Path(TypePath { qself: None, path }) = elem.as_ref() {
+                let is_primitive = path.get_ident().is_some_and(|ident| {
+                    Self::PRIMITIVES.iter().any(|primitive| ident == primitive)
+                });
+                if !is_primitive {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(Error::new_spanned(
+            ty,
+            "An ownership can only be specified for a QObject pointer, *mut T where T is a QObject",
+        ))
+    }
+
+    /// The C++ value of the `QQmlEngine::ObjectOwnership` enum
+    pub fn as_cpp_str(&self) -> &'static str {
+        match self {
+            Self::Cpp => "QQmlEngine::CppOwnership",
+            Self::JavaScript => "QQmlEngine::JavaScriptOwnership",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::{parse_quote, ItemStruct};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            ParsedQmlOwnership::parse(&format_ident!("cpp_owned")).unwrap(),
+            ParsedQmlOwnership::Cpp
+        );
+        assert_eq!(
+            ParsedQmlOwnership::parse(&format_ident!("js_owned")).unwrap(),
+            ParsedQmlOwnership::JavaScript
+        );
+        assert!(ParsedQmlOwnership::parse(&format_ident!("rust_owned")).is_err());
+    }
+
+    #[test]
+    fn test_take_from_attrs() {
+        let mut item: ItemStruct = parse_quote! {
+            #[js_owned]
+            #[other]
+            struct T;
+        };
+        assert_eq!(
+            ParsedQmlOwnership::take_from_attrs(&mut item.attrs).unwrap(),
+            Some(ParsedQmlOwnership::JavaScript)
+        );
+        assert_eq!(item.attrs.len(), 1);
+        assert_eq!(
+            ParsedQmlOwnership::take_from_attrs(&mut item.attrs).unwrap(),
+            None
+        );
+
+        let mut item: ItemStruct = parse_quote! {
+            #[cpp_owned]
+            #[js_owned]
+            struct T;
+        };
+        assert!(ParsedQmlOwnership::take_from_attrs(&mut item.attrs).is_err());
+    }
+
+    #[test]
+    fn test_check_type() {
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *mut MyObject }).is_ok());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *const MyObject }).is_err());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { MyObject }).is_err());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *mut i32 }).is_err());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *mut *mut MyObject }).is_err());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *mut [MyObject] }).is_err());
+        assert!(ParsedQmlOwnership::check_type(&parse_quote! { *mut ffi::MyObject }).is_ok());
+    }
+}
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::parser::ownership::ParsedQmlOwnership;
 use crate::syntax::types;
 use syn::{
     spanned::Spanned, Error, FnArg, Ident, Pat, PatIdent, PatType, Receiver, Result, Signature,
@@ -16,6 +17,10 @@ pub struct ParsedFunctionParameter {
     pub ident: Ident,
     /// The [syn::Type] of the parameter
     pub ty: Type,
+    /// The C++ default value of the parameter, if there is one
+    pub default: Option<String>,
+    /// The QML ownership to set on a QObject pointer parameter, if there is one
+    pub ownership: Option<ParsedQmlOwnership>,
 }
 
 impl ParsedFunctionParameter {
@@ -92,6 +97,8 @@ impl ParsedFunctionParameter {
         Ok(ParsedFunctionParameter {
             ident,
             ty: (*type_pattern.ty).clone(),
+            default: None,
+            ownership: None,
         })
     }
 }
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::parser::ownership::ParsedQmlOwnership;
 use syn::{parse::ParseStream, Attribute, Ident, Result, Token, Type};
 
 /// Describes a single Q_PROPERTY for a struct
@@ -11,6 +12,12 @@ pub struct ParsedQProperty {
     pub ident: Ident,
     /// The [syn::Type] of the property
     pub ty: Type,
+    /// The QML ownership to set on a QObject pointer property, if there is one
+    pub ownership: Option<ParsedQmlOwnership>,
+    /// The lines of the doc comments directly before the `#[qproperty]` attribute
+    pub docs: Vec<String>,
+    /// The `#[cfg]` of a property which is enabled with `#[cfg_attr(predicate, qproperty(...))]`
+    pub cfgs: Vec<Attribute>,
 }
 
 impl ParsedQProperty {
@@ -20,10 +27,26 @@ impl ParsedQProperty {
             let _comma = input.parse::<Token![,]>()?;
             let ident = input.parse()?;
 
+            // A QObject pointer property can specify the ownership, eg #[qproperty(*mut T, name, cpp_owned)]
+            let ownership = if input.is_empty() {
+                None
+            } else {
+                let _comma = input.parse::<Token![,]>()?;
+                let ownership = ParsedQmlOwnership::parse(&input.parse()?)?;
+                ParsedQmlOwnership::check_type(&ty)?;
+                Some(ownership)
+            };
+
             // TODO: later we'll need to parse setters and getters here
             // which are key-value, hence this not being parsed as a list
 
-            Ok(Self { ident, ty })
+            Ok(Self {
+                ident,
+                ty,
+                ownership,
+                docs: vec![],
+                cfgs: vec![],
+            })
         })
     }
 }
@@ -46,6 +69,27 @@ mod tests {
         assert_eq!(property.ty, parse_quote! { T });
     }
 
+    #[test]
+    fn test_parse_property_ownership() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(*mut MyObject, child, cpp_owned)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("child"));
+        assert_eq!(property.ownership, Some(ParsedQmlOwnership::Cpp));
+    }
+
+    #[test]
+    fn test_parse_property_ownership_not_pointer() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, number, js_owned)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
+    }
+
     #[test]
     fn test_parse_property_arg_extra() {
         let mut input: ItemStruct = parse_quote! {
@@ -4,9 +4,13 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use quote::ToTokens;
-use syn::{Ident, ItemEnum, Result, Variant};
+use syn::{Attribute, Ident, ItemEnum, Result, Variant};
 
-use crate::syntax::{attribute::attribute_find_path, expr::expr_to_string, path::path_compare_str};
+use crate::syntax::{
+    attribute::{attribute_docs, attribute_find_path},
+    expr::expr_to_string,
+    path::path_compare_str,
+};
 
 pub struct ParsedQEnum {
     /// The ident of the QEnum
@@ -15,6 +19,12 @@ pub struct ParsedQEnum {
     pub namespace: String,
     /// the values of the QEnum
     pub variants: Vec<Ident>,
+    /// The lines of the doc comments of the QEnum
+    pub docs: Vec<String>,
+    /// The lines of the doc comments of each of the values, in the same order as the values
+    pub variant_docs: Vec<Vec<String>>,
+    /// The `#[cfg]` attributes of the QEnum, these are also part of the original enum item
+    pub cfgs: Vec<Attribute>,
     /// The original enum item
     pub item: ItemEnum,
 }
@@ -66,7 +76,7 @@ impl ParsedQEnum {
 
         // TODO: Add support for `cxx_name` and `rust_name` attributes.
         if let Some(attr) = qenum.attrs.iter().find(|attr| {
-            !["doc", "namespace"]
+            !["cfg", "doc", "namespace"]
                 .iter()
                 .any(|allowed_attr| path_compare_str(attr.path(), &[allowed_attr]))
         }) {
@@ -81,11 +91,24 @@ impl ParsedQEnum {
             .iter()
             .map(Self::parse_variant)
             .collect::<Result<_>>()?;
+        let variant_docs = qenum
+            .variants
+            .iter()
+            .map(|variant| attribute_docs(&variant.attrs))
+            .collect();
 
         Ok(Self {
             namespace,
             ident: qenum.ident.clone(),
             variants,
+            docs: attribute_docs(&qenum.attrs),
+            variant_docs,
+            cfgs: qenum
+                .attrs
+                .iter()
+                .filter(|attr| path_compare_str(attr.path(), &["cfg"]))
+                .cloned()
+                .collect(),
             item: qenum,
         })
     }
@@ -125,6 +148,11 @@ mod tests {
         assert_eq!(parsed.namespace, "");
 
         assert_eq!(*variants_to_strings(&parsed), ["Variant1", "Variant2"],);
+        assert_eq!(parsed.docs, ["My doc comment"]);
+        assert_eq!(
+            parsed.variant_docs,
+            [["Variant1 doc comment"], ["Variant2 doc comment"]]
+        );
         assert_tokens_eq(&parsed.item, original_item);
     }
 
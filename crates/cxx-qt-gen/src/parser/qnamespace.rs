@@ -5,7 +5,7 @@
 
 use syn::{ItemMacro, LitStr, Result};
 
-use crate::syntax::attribute::attribute_take_path;
+use crate::syntax::attribute::{attribute_take_cfgs, attribute_take_path};
 
 pub struct ParsedQNamespace {
     /// The name of the namespace
@@ -33,6 +33,9 @@ impl ParsedQNamespace {
 
         let qml_element = attribute_take_path(&mut mac.attrs, &["qml_element"]).is_some();
 
+        // A qnamespace! only generates C++ code, so any cfg has already been evaluated by cxx-qt-build
+        attribute_take_cfgs(&mut mac.attrs)?;
+
         if let Some(attr) = mac.attrs.first() {
             return Err(syn::Error::new_spanned(
                 attr,
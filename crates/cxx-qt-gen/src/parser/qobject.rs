@@ -4,16 +4,20 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
+    cfg::CfgAttr,
+    generator::naming::method::QMethodName,
     parser::{
         constructor::Constructor, inherit::ParsedInheritedMethod, method::ParsedMethod,
         property::ParsedQProperty, qenum::ParsedQEnum, signals::ParsedSignal,
     },
     syntax::{
-        attribute::attribute_take_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
+        attribute::{attribute_docs, attribute_take_cfgs, attribute_take_path},
+        expr::expr_to_string,
+        foreignmod::ForeignTypeIdentAlias,
         path::path_compare_str,
     },
 };
-use syn::{Attribute, Error, Ident, ItemImpl, Meta, Result};
+use syn::{parse_quote, Attribute, Error, Ident, ItemImpl, Meta, Result};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -55,10 +59,22 @@ pub struct ParsedQObject {
     pub qml_metadata: Option<QmlElementMetadata>,
     /// Whether locking is enabled for this QObject
     pub locking: bool,
+    /// Whether const access takes a shared lock, from `impl cxx_qt::SharedLocking`
+    pub shared_locking: bool,
     /// Whether threading has been enabled for this QObject
     pub threading: bool,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// Whether this type is designed to be subclassed from C++
+    ///
+    /// The constructors are then protected, so only C++ subclasses can create the type
+    pub subclassable: bool,
+    /// Whether the QML singleton is created by a Rust factory, from `impl cxx_qt::QmlSingletonFactory`
+    pub singleton_factory: bool,
+    /// The lines of the doc comments of the QObject
+    pub docs: Vec<String>,
+    /// The `#[cfg]` attributes of the QObject and its extern "RustQt" block
+    pub cfgs: Vec<Attribute>,
 }
 
 impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
@@ -68,6 +84,10 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
     fn try_from(qobject_ty: &ForeignTypeIdentAlias) -> Result<Self> {
         let mut qobject_ty = qobject_ty.clone();
 
+        // Parse any properties in the type and remove the #[qproperty] attribute,
+        // this is first so that the doc comments of the properties are not those of the type
+        let properties = Self::parse_property_attributes(&mut qobject_ty.attrs)?;
+
         // Find any QML metadata
         let qml_metadata = Self::parse_qml_metadata(&qobject_ty.ident_left, &mut qobject_ty.attrs)?;
 
@@ -82,9 +102,28 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             .transpose()?
             .unwrap_or_else(|| "".to_owned());
 
-        // Parse any properties in the type
-        // and remove the #[qproperty] attribute
-        let properties = Self::parse_property_attributes(&mut qobject_ty.attrs)?;
+        // Find if the type is designed to be subclassed from C++
+        let subclassable = if let Some(attr) =
+            attribute_take_path(&mut qobject_ty.attrs, &["cxx_subclassable"])
+        {
+            // The constructors are protected so QML cannot create the type
+            if qml_metadata
+                .as_ref()
+                .is_some_and(|qml_metadata| !qml_metadata.uncreatable)
+            {
+                return Err(Error::new_spanned(
+                        attr,
+                        "A #[cxx_subclassable] QObject has protected constructors, so a #[qml_element] must also be #[qml_uncreatable]",
+                    ));
+            }
+
+            true
+        } else {
+            false
+        };
+
+        let cfgs = attribute_take_cfgs(&mut qobject_ty.attrs)?;
+        let docs = attribute_docs(&qobject_ty.attrs);
 
         Ok(Self {
             base_class,
@@ -98,8 +137,13 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             properties,
             qml_metadata,
             locking: true,
+            shared_locking: false,
             threading: false,
             has_qobject_macro: false,
+            subclassable,
+            singleton_factory: false,
+            docs,
+            cfgs,
         })
     }
 }
@@ -133,6 +177,34 @@ impl ParsedQObject {
         Ok(None)
     }
 
+    /// Ensure that the overridable methods of a `#[cxx_subclassable]` QObject are not overloaded
+    ///
+    /// Each of them is bound to Rust by its C++ name to call the C++ virtual method,
+    /// and CXX cannot bind a C++ name which is overloaded.
+    pub fn check_subclassable_overloads(&self) -> Result<()> {
+        if !self.subclassable {
+            return Ok(());
+        }
+
+        for method in self.methods.iter().filter(|method| method.is_overridable()) {
+            let name = QMethodName::from(method).name.cpp;
+            if self
+                .methods
+                .iter()
+                .filter(|other| QMethodName::from(*other).name.cpp == name)
+                .count()
+                > 1
+            {
+                return Err(Error::new_spanned(
+                    &method.method.sig.ident,
+                    "Virtual methods of a #[cxx_subclassable] QObject cannot be overloaded with #[cxx_name]",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn parse_trait_impl(&mut self, imp: ItemImpl) -> Result<()> {
         let (not, trait_path, _) = &imp
             .trait_
@@ -169,8 +241,34 @@ impl ParsedQObject {
                 ));
             }
 
+            // Check that cxx_qt::SharedLocking is not enabled
+            if self.shared_locking {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "cxx_qt::Locking must be enabled if cxx_qt::SharedLocking is enabled",
+                ));
+            }
+
             self.locking = false;
             Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "SharedLocking"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::SharedLocking are not allowed",
+                ));
+            }
+
+            // Check that cxx_qt::Locking is not disabled
+            if !self.locking {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "cxx_qt::Locking must be enabled if cxx_qt::SharedLocking is enabled",
+                ));
+            }
+
+            self.shared_locking = true;
+            Ok(())
         } else if path_compare_str(trait_path, &["cxx_qt", "Threading"]) {
             if not.is_some() {
                 return Err(Error::new_spanned(
@@ -192,11 +290,33 @@ impl ParsedQObject {
         } else if path_compare_str(trait_path, &["cxx_qt", "Constructor"]) {
             self.constructors.push(Constructor::parse(imp)?);
             Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "QmlSingletonFactory"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::QmlSingletonFactory are not allowed",
+                ));
+            }
+
+            // The factory is only used by QML to create a singleton
+            if !self
+                .qml_metadata
+                .as_ref()
+                .is_some_and(|qml_metadata| qml_metadata.singleton)
+            {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "cxx_qt::QmlSingletonFactory can only be implemented for a #[qml_singleton]",
+                ));
+            }
+
+            self.singleton_factory = true;
+            Ok(())
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
                 trait_path,
-                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\nNote that the trait must always be fully-qualified."
+                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\n- cxx_qt::SharedLocking\n- cxx_qt::QmlSingletonFactory\nNote that the trait must always be fully-qualified."
             ))
         }
     }
@@ -204,12 +324,62 @@ impl ParsedQObject {
     fn parse_property_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<ParsedQProperty>> {
         let mut properties = vec![];
 
-        // Note that once extract_if is stable, this would allow for comparing all the
-        // elements once using path_compare_str and then building ParsedQProperty
-        // from the extracted elements.
-        // https://doc.rust-lang.org/nightly/std/vec/struct.Vec.html#method.extract_if
-        while let Some(attr) = attribute_take_path(attrs, &["qproperty"]) {
-            properties.push(ParsedQProperty::parse(attr)?);
+        let mut index = 0;
+        while index < attrs.len() {
+            // A #[cfg_attr(predicate, qproperty(...))] is a property with a #[cfg(predicate)],
+            // which is added to the generated Rust code of the property
+            let (property_attrs, cfgs) = if path_compare_str(attrs[index].path(), &["qproperty"]) {
+                (vec![attrs[index].clone()], vec![])
+            } else if path_compare_str(attrs[index].path(), &["cfg_attr"]) {
+                let CfgAttr {
+                    predicate,
+                    attrs: metas,
+                } = attrs[index].parse_args()?;
+                if !metas
+                    .iter()
+                    .any(|meta| path_compare_str(meta.path(), &["qproperty"]))
+                {
+                    index += 1;
+                    continue;
+                }
+                if let Some(meta) = metas
+                    .iter()
+                    .find(|meta| !path_compare_str(meta.path(), &["qproperty"]))
+                {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "A #[cfg_attr] with a #[qproperty] can only contain #[qproperty] attributes",
+                    ));
+                }
+                let property_attrs = metas
+                    .into_iter()
+                    .map(|meta| Attribute {
+                        meta,
+                        ..attrs[index].clone()
+                    })
+                    .collect();
+                (property_attrs, vec![parse_quote! { #[cfg(#predicate)] }])
+            } else {
+                index += 1;
+                continue;
+            };
+            attrs.remove(index);
+
+            // Doc comments directly before the #[qproperty] document the property,
+            // unless they are the first attributes, which document the type
+            let docs_start = attrs[..index]
+                .iter()
+                .rposition(|attr| !path_compare_str(attr.path(), &["doc"]))
+                .map_or(index, |position| position + 1);
+            let docs = attribute_docs(&attrs.drain(docs_start..index).collect::<Vec<_>>());
+            index = docs_start;
+
+            for attr in property_attrs {
+                let mut property = ParsedQProperty::parse(attr)?;
+                property.docs = docs.clone();
+                property.cfgs = cfgs.clone();
+                properties.push(property);
+            }
         }
 
         Ok(properties)
@@ -268,6 +438,35 @@ pub mod tests {
         assert_eq!(qobject.properties.len(), 2);
     }
 
+    #[test]
+    fn test_from_struct_docs() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            /// The object
+            #[qobject]
+            /// The number
+            ///
+            /// Which is an integer
+            #[qproperty(i32, number)]
+            #[qproperty(i32, undocumented)]
+            /// The string
+            #[qproperty(QString, string)]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject = ParsedQObject::try_from(&qobject_struct).unwrap();
+        assert_eq!(qobject.docs, ["The object"]);
+        assert_eq!(qobject.properties.len(), 3);
+        assert_eq!(
+            qobject.properties[0].docs,
+            ["The number", "", "Which is an integer"]
+        );
+        assert!(qobject.properties[1].docs.is_empty());
+        assert_eq!(qobject.properties[2].docs, ["The string"]);
+
+        // The doc comments of the properties are removed from the type
+        assert_eq!(qobject.qobject_ty.attrs.len(), 2);
+    }
+
     #[test]
     fn test_from_struct_fields() {
         let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
@@ -321,6 +520,70 @@ pub mod tests {
         assert!(qobject.parse_trait_impl(item).is_err());
     }
 
+    #[test]
+    fn test_parse_trait_impl_shared_locking() {
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::SharedLocking for MyObject {}
+        };
+        assert!(!qobject.shared_locking);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.shared_locking);
+
+        // SharedLocking cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::SharedLocking for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Locking cannot be disabled with SharedLocking
+        let item: ItemImpl = parse_quote! {
+            unsafe impl !cxx_qt::Locking for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // SharedLocking requires Locking
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            unsafe impl !cxx_qt::Locking for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::SharedLocking for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+    }
+
+    #[test]
+    fn test_parse_trait_impl_singleton_factory() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_singleton]
+            type MyObject = super::MyObjectRust;
+        };
+        let mut qobject = ParsedQObject::try_from(&item).unwrap();
+        assert!(!qobject.singleton_factory);
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.singleton_factory);
+
+        // QmlSingletonFactory cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::QmlSingletonFactory for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // QmlSingletonFactory requires a singleton
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlSingletonFactory for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+    }
+
     #[test]
     fn test_parse_struct_fields_valid() {
         let item: ForeignTypeIdentAlias = parse_quote! {
@@ -339,6 +602,45 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_from_struct_subclassable() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&qobject_struct).unwrap();
+        assert!(!qobject.subclassable);
+
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[cxx_subclassable]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&qobject_struct).unwrap();
+        assert!(qobject.subclassable);
+    }
+
+    #[test]
+    fn test_from_struct_subclassable_qml_element() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_uncreatable]
+            #[cxx_subclassable]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&qobject_struct).is_ok());
+
+        // QML needs a public constructor to create the type
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[cxx_subclassable]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&qobject_struct).is_err());
+    }
+
     #[test]
     fn test_qml_metadata() {
         let item: ForeignTypeIdentAlias = parse_quote! {
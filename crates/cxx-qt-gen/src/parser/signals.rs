@@ -4,14 +4,16 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::parser::parameter::ParsedFunctionParameter;
-use crate::syntax::attribute::{attribute_find_path, attribute_take_path};
+use crate::syntax::attribute::{
+    attribute_docs, attribute_find_path, attribute_take_cfgs, attribute_take_path,
+};
 use crate::syntax::expr::expr_to_string;
 use crate::syntax::foreignmod;
 use crate::syntax::path::path_compare_str;
 use crate::syntax::safety::Safety;
 use crate::{generator::naming::CombinedIdent, syntax::types};
 use quote::format_ident;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result, Visibility};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result, Visibility};
 
 #[derive(Clone)]
 /// Describes an individual Signal
@@ -32,6 +34,10 @@ pub struct ParsedSignal {
     pub inherit: bool,
     /// Whether the signal is private
     pub private: bool,
+    /// The lines of the doc comments of the signal
+    pub docs: Vec<String>,
+    /// The `#[cfg]` attributes of the signal and its extern block
+    pub cfgs: Vec<Attribute>,
 }
 
 impl ParsedSignal {
@@ -50,6 +56,8 @@ impl ParsedSignal {
             ident,
             inherit: false,
             private: false,
+            docs: vec![],
+            cfgs: vec![],
         }
     }
 
@@ -61,6 +69,8 @@ impl ParsedSignal {
             ));
         }
 
+        let cfgs = attribute_take_cfgs(&mut method.attrs)?;
+
         let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
         let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
         let mutable = mutability.is_some();
@@ -96,6 +106,7 @@ impl ParsedSignal {
         } else {
             false
         };
+        let docs = attribute_docs(&method.attrs);
 
         Ok(Self {
             method,
@@ -106,6 +117,8 @@ impl ParsedSignal {
             safe,
             inherit,
             private,
+            docs,
+            cfgs,
         })
     }
 }
@@ -0,0 +1,497 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A crate-wide registry of the types declared in CXX-Qt bridges
+//!
+//! The registry is collected by cxx-qt-build from every bridge of a crate before any
+//! code is generated, and is written into `OUT_DIR` so that the `cxx_qt::bridge` macro
+//! sees the same types. This allows a bridge to import a QObject or QEnum from a sibling
+//! bridge, or from a bridge of a CXX-Qt crate that is a dependency, with a `use` item.
+
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use quote::ToTokens;
+use syn::{
+    parse_quote_spanned, spanned::Spanned, Error, Ident, Item, ItemMod, ItemUse, Path as SynPath,
+    Result, UseTree,
+};
+
+use crate::{syntax::path::path_compare_str, Parser};
+
+/// A type declared in a CXX-Qt bridge that can be imported into other bridges of the same crate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredType {
+    /// The Rust ident of the type
+    pub ident: String,
+    /// The C++ name of the type, without its namespace
+    pub cxx_name: String,
+    /// The C++ namespace of the type, empty if there is none
+    pub namespace: String,
+    /// The ident of the bridge module that declares the type
+    pub module: String,
+    /// The stem of the generated header that declares the type
+    pub cxx_file_stem: String,
+}
+
+impl RegisteredType {
+    /// The path of the generated header that declares this type
+    pub fn include_path(&self) -> String {
+        format!("cxx-qt-gen/{}.cxxqt.h", self.cxx_file_stem)
+    }
+}
+
+/// The QObjects and QEnums declared by all the CXX-Qt bridges of a crate
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeRegistry {
+    types: Vec<RegisteredType>,
+}
+
+impl TypeRegistry {
+    /// The path of the registry relative to `OUT_DIR`
+    pub const FILE_NAME: &'static str = "cxx-qt-gen/type-registry.txt";
+
+    /// The path of the registry for the given `OUT_DIR`
+    pub fn path(out_dir: impl AsRef<Path>) -> PathBuf {
+        out_dir.as_ref().join(Self::FILE_NAME)
+    }
+
+    /// Read the registry that cxx-qt-build wrote into the `OUT_DIR` of the current crate
+    ///
+    /// If the crate has no build script, or it did not write a registry, the registry is empty.
+    pub fn from_out_dir() -> Self {
+        env::var_os("OUT_DIR")
+            .and_then(|out_dir| Self::read(Self::path(out_dir)).ok())
+            .unwrap_or_default()
+    }
+
+    /// Read a registry from the given file
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let types = contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                if let [ident, cxx_name, namespace, module, cxx_file_stem] = fields[..] {
+                    Ok(RegisteredType {
+                        ident: ident.to_owned(),
+                        cxx_name: cxx_name.to_owned(),
+                        namespace: namespace.to_owned(),
+                        module: module.to_owned(),
+                        cxx_file_stem: cxx_file_stem.to_owned(),
+                    })
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Invalid line in CXX-Qt type registry: {line}"),
+                    ))
+                }
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { types })
+    }
+
+    /// Write the registry to the given file, creating any parent directories
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = fs::File::create(path)?;
+        for ty in &self.types {
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}",
+                ty.ident, ty.cxx_name, ty.namespace, ty.module, ty.cxx_file_stem
+            )?;
+        }
+        Ok(())
+    }
+
+    /// All of the types in the registry
+    pub fn types(&self) -> &[RegisteredType] {
+        &self.types
+    }
+
+    /// Add the types of another registry, such as the registry of a dependency,
+    /// skipping any types that are already registered
+    pub fn merge(&mut self, other: Self) {
+        for ty in other.types {
+            if !self.types.contains(&ty) {
+                self.types.push(ty);
+            }
+        }
+    }
+
+    /// Register the QObjects and QEnums declared in the given `cxx_qt::bridge` module
+    ///
+    /// Any `use` items in the bridge are ignored, as the types they import are not known yet.
+    pub fn register(&mut self, mut module: ItemMod) -> Result<()> {
+        if let Some((_, items)) = &mut module.content {
+            items.retain(|item| !matches!(item, Item::Use(_)));
+        }
+
+        let parser = Parser::from(module)?;
+        let module = parser.passthrough_module.ident.to_string();
+
+        let qobjects = parser
+            .cxx_qt_data
+            .qobjects
+            .values()
+            .map(|qobject| &qobject.qobject_ty.ident_left);
+        let qenums = parser
+            .cxx_qt_data
+            .qobjects
+            .values()
+            .flat_map(|qobject| qobject.qenums.iter())
+            .chain(parser.cxx_qt_data.qenums.iter())
+            .map(|qenum| &qenum.ident);
+
+        for ident in qobjects.chain(qenums) {
+            self.types.push(RegisteredType {
+                ident: ident.to_string(),
+                cxx_name: parser.type_names.cxx_unqualified(ident)?,
+                namespace: parser.type_names.namespace(ident)?.unwrap_or_default(),
+                module: module.clone(),
+                cxx_file_stem: parser.cxx_file_stem.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Find the registered type that the given path refers to
+    ///
+    /// The last segment of the path is the ident of the type, if multiple bridges declare
+    /// a type with that ident then the bridge module or file stem must be part of the path.
+    pub fn resolve(&self, path: &SynPath) -> Result<&RegisteredType> {
+        let ident = &path
+            .segments
+            .last()
+            .ok_or_else(|| Error::new_spanned(path, "Expected a path to a type"))?
+            .ident;
+        let candidates: Vec<&RegisteredType> = self
+            .types
+            .iter()
+            .filter(|ty| ident == ty.ident.as_str())
+            .collect();
+
+        match candidates[..] {
+            [] => Err(Error::new_spanned(
+                path,
+                format!("Could not find a QObject or QEnum named {ident} in the CXX-Qt bridges of this crate! Ensure that the bridge declaring it is built with cxx-qt-build."),
+            )),
+            [ty] => Ok(ty),
+            _ => {
+                let parents: Vec<String> = path
+                    .segments
+                    .iter()
+                    .rev()
+                    .skip(1)
+                    .map(|segment| segment.ident.to_string())
+                    .collect();
+                let matching: Vec<&RegisteredType> = candidates
+                    .into_iter()
+                    .filter(|ty| {
+                        parents
+                            .iter()
+                            .any(|parent| parent == &ty.module || parent == &ty.cxx_file_stem)
+                    })
+                    .collect();
+                if let [ty] = matching[..] {
+                    Ok(ty)
+                } else {
+                    Err(Error::new_spanned(
+                        path,
+                        format!("{ident} is declared in multiple CXX-Qt bridges of this crate! Include the bridge module or file stem in the path to disambiguate."),
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Convert a `use` item in a bridge into CXX blocks that declare the imported types
+    ///
+    /// This is [None] if the item does not import any registered types,
+    /// as then it is passed through to CXX. Otherwise a grouped item is split,
+    /// and the items it imports which are not registered types are passed through as `use` items.
+    pub(crate) fn import(&self, item: &ItemUse) -> Result<Option<Vec<Item>>> {
+        let mut leaves = vec![];
+        use_tree_leaves(String::new(), &item.tree, &mut leaves);
+        let is_registered = |ident: &Ident| self.types.iter().any(|ty| ident == ty.ident.as_str());
+        if !leaves
+            .iter()
+            .any(|(_, tree)| use_tree_ident(tree).is_some_and(is_registered))
+        {
+            return Ok(None);
+        }
+
+        leaves
+            .into_iter()
+            .map(|(prefix, tree)| match tree {
+                UseTree::Name(use_name) if is_registered(&use_name.ident) => {
+                    let ident = &use_name.ident;
+                    let leading_colon = item.leading_colon.map(|_| "::").unwrap_or_default();
+                    let path: SynPath = syn::parse_str(&format!("{leading_colon}{prefix}{ident}"))?;
+                    let ty = self
+                        .resolve(&path)
+                        .map_err(|err| Error::new(item.span(), err.to_string()))?;
+                    let include = ty.include_path();
+                    let namespace = &ty.namespace;
+                    let cxx_name = &ty.cxx_name;
+                    let cfgs = item
+                        .attrs
+                        .iter()
+                        .filter(|attr| path_compare_str(attr.path(), &["cfg"]));
+                    Ok(parse_quote_spanned! { item.span() =>
+                        #(#cfgs)*
+                        #[namespace = #namespace]
+                        unsafe extern "C++" {
+                            include!(#include);
+                            #[cxx_name = #cxx_name]
+                            type #ident = #path;
+                        }
+                    })
+                }
+                UseTree::Rename(use_rename) if is_registered(&use_rename.ident) => {
+                    Err(Error::new_spanned(
+                        use_rename,
+                        "Types of other CXX-Qt bridges can not be renamed when imported",
+                    ))
+                }
+                // Anything else, such as a trait, is passed through to CXX
+                tree => {
+                    let tree: UseTree =
+                        syn::parse_str(&format!("{prefix}{}", tree.to_token_stream()))?;
+                    Ok(Item::Use(ItemUse {
+                        tree,
+                        ..item.clone()
+                    }))
+                }
+            })
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+}
+
+/// The ident of the item a leaf of a [UseTree] imports, before any rename
+fn use_tree_ident(tree: &UseTree) -> Option<&Ident> {
+    match tree {
+        UseTree::Name(use_name) => Some(&use_name.ident),
+        UseTree::Rename(use_rename) => Some(&use_rename.ident),
+        _ => None,
+    }
+}
+
+/// Flatten a [UseTree] into the path prefix and the leaf of each item it imports
+fn use_tree_leaves<'a>(prefix: String, tree: &'a UseTree, leaves: &mut Vec<(String, &'a UseTree)>) {
+    match tree {
+        UseTree::Path(use_path) => use_tree_leaves(
+            format!("{prefix}{}::", use_path.ident),
+            &use_path.tree,
+            leaves,
+        ),
+        UseTree::Group(use_group) => use_group
+            .items
+            .iter()
+            .for_each(|tree| use_tree_leaves(prefix.clone(), tree, leaves)),
+        UseTree::Name(_) | UseTree::Rename(_) | UseTree::Glob(_) => leaves.push((prefix, tree)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{tests::assert_tokens_eq, TempDir};
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry
+            .register(parse_quote! {
+                #[cxx_qt::bridge(namespace = "cxx_qt::other", cxx_file_stem = "other_bridge")]
+                mod qobject {
+                    #[qenum(OtherObject)]
+                    enum OtherEnum {
+                        A,
+                    }
+
+                    extern "RustQt" {
+                        #[qobject]
+                        #[cxx_name = "OtherObjectCpp"]
+                        type OtherObject = super::OtherObjectRust;
+                    }
+                }
+            })
+            .unwrap();
+        registry
+            .register(parse_quote! {
+                #[cxx_qt::bridge(cxx_file_stem = "third")]
+                mod ffi {
+                    #[qenum]
+                    #[namespace = "cxx_qt::third"]
+                    enum OtherEnum {
+                        B,
+                    }
+                }
+            })
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = registry();
+        assert_eq!(registry.types().len(), 3);
+        assert_eq!(
+            registry.types()[0],
+            RegisteredType {
+                ident: "OtherObject".to_owned(),
+                cxx_name: "OtherObjectCpp".to_owned(),
+                namespace: "cxx_qt::other".to_owned(),
+                module: "qobject".to_owned(),
+                cxx_file_stem: "other_bridge".to_owned(),
+            }
+        );
+        assert_eq!(registry.types()[1].namespace, "cxx_qt::other");
+        assert_eq!(registry.types()[2].namespace, "cxx_qt::third");
+    }
+
+    #[test]
+    fn test_read_write() {
+        let registry = registry();
+        let temp_dir = TempDir::new("cxx-qt-gen-registry");
+        let path = temp_dir.path().join(TypeRegistry::FILE_NAME);
+        registry.write(&path).unwrap();
+        assert_eq!(TypeRegistry::read(&path).unwrap(), registry);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut merged = registry();
+        merged.merge(registry());
+        assert_eq!(merged, registry());
+
+        let mut merged = TypeRegistry::default();
+        merged.merge(registry());
+        assert_eq!(merged.types().len(), 3);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let registry = registry();
+        let path: SynPath = parse_quote! { super::other_bridge::OtherObject };
+        assert_eq!(registry.resolve(&path).unwrap().ident, "OtherObject");
+
+        let path: SynPath = parse_quote! { super::other_bridge::qobject::OtherEnum };
+        assert_eq!(registry.resolve(&path).unwrap().module, "qobject");
+        let path: SynPath = parse_quote! { crate::third::ffi::OtherEnum };
+        assert_eq!(registry.resolve(&path).unwrap().module, "ffi");
+    }
+
+    #[test]
+    fn test_resolve_invalid() {
+        let registry = registry();
+        // Unknown type
+        let path: SynPath = parse_quote! { super::other_bridge::Unknown };
+        assert!(registry.resolve(&path).is_err());
+        // Ambiguous type
+        let path: SynPath = parse_quote! { super::OtherEnum };
+        assert!(registry.resolve(&path).is_err());
+    }
+
+    #[test]
+    fn test_import() {
+        let registry = registry();
+        let items = registry
+            .import(&parse_quote! {
+                use super::other_bridge::{OtherObject, qobject::OtherEnum};
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                #[namespace = "cxx_qt::other"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-gen/other_bridge.cxxqt.h");
+                    #[cxx_name = "OtherObjectCpp"]
+                    type OtherObject = super::other_bridge::OtherObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                #[namespace = "cxx_qt::other"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-gen/other_bridge.cxxqt.h");
+                    #[cxx_name = "OtherEnum"]
+                    type OtherEnum = super::other_bridge::qobject::OtherEnum;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_import_invalid() {
+        let registry = registry();
+        assert!(registry
+            .import(&parse_quote! { use super::other_bridge::OtherObject as Renamed; })
+            .is_err());
+        assert!(registry
+            .import(&parse_quote! { use super::other_bridge::{OtherObject, OtherEnum as Renamed}; })
+            .is_err());
+    }
+
+    #[test]
+    fn test_import_split_group() {
+        let registry = registry();
+        let items = registry
+            .import(&parse_quote! {
+                pub use ::other_bridge::{OtherObject, traits::{MyTrait, *}};
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(items.len(), 3);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                #[namespace = "cxx_qt::other"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-gen/other_bridge.cxxqt.h");
+                    #[cxx_name = "OtherObjectCpp"]
+                    type OtherObject = ::other_bridge::OtherObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! { pub use ::other_bridge::traits::MyTrait; },
+        );
+        assert_tokens_eq(&items[2], quote! { pub use ::other_bridge::traits::*; });
+    }
+
+    #[test]
+    fn test_import_passthrough() {
+        let registry = registry();
+        assert!(registry
+            .import(&parse_quote! { use super::MyTrait; })
+            .unwrap()
+            .is_none());
+        assert!(registry
+            .import(&parse_quote! { use super::other_bridge::*; })
+            .unwrap()
+            .is_none());
+    }
+}
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::syntax::path::path_compare_str;
-use syn::Attribute;
+use syn::{Attribute, Error, Expr, ExprLit, Item, Lit, Meta, Result};
 
 /// Returns the index of the first [syn::Attribute] that matches a given path
 pub fn attribute_find_path(attrs: &[Attribute], path: &[&str]) -> Option<usize> {
@@ -22,6 +22,76 @@ pub fn attribute_take_path(attrs: &mut Vec<Attribute>, path: &[&str]) -> Option<
     attribute_find_path(attrs, path).map(|index| attrs.remove(index))
 }
 
+/// Takes and returns the `#[cfg(...)]` attributes, which are added to the generated Rust code
+///
+/// A `#[cfg_attr(...)]` is an error, as the attributes it adds are only known once rustc evaluates it.
+pub fn attribute_take_cfgs(attrs: &mut Vec<Attribute>) -> Result<Vec<Attribute>> {
+    if let Some(attr) = attrs
+        .iter()
+        .find(|attr| path_compare_str(attr.path(), &["cfg_attr"]))
+    {
+        return Err(Error::new_spanned(
+            attr,
+            "#[cfg_attr] can only be used for #[qproperty] in CXX-Qt items, use #[cfg] instead",
+        ));
+    }
+
+    let (cfgs, others) = std::mem::take(attrs)
+        .into_iter()
+        .partition(|attr| path_compare_str(attr.path(), &["cfg"]));
+    *attrs = others;
+    Ok(cfgs)
+}
+
+/// Returns the attributes of an item, if it is a kind of item which has attributes
+pub fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+/// Returns the lines of the `#[doc = "..."]` attributes, which `///` comments expand to
+///
+/// The leading space of each line which follows the `///` is removed.
+pub fn attribute_docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+                match &name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) => Some(lit_str.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .flat_map(|doc| {
+            // Note that lines() would skip an empty `///` line
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +128,49 @@ mod tests {
         assert!(attribute_take_path(&mut module.attrs, &["qinvokable"]).is_some());
         assert_eq!(module.attrs.len(), 3);
     }
+
+    #[test]
+    fn test_attribute_docs() {
+        let module: ItemMod = parse_quote! {
+            /// A doc comment
+            ///
+            ///   with indentation
+            #[cxx_qt::bridge]
+            #[doc = "multiple\n lines"]
+            mod module;
+        };
+
+        assert_eq!(
+            attribute_docs(&module.attrs),
+            vec![
+                "A doc comment",
+                "",
+                "  with indentation",
+                "multiple",
+                "lines"
+            ]
+        );
+        assert!(attribute_docs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_attribute_take_cfgs() {
+        let mut module: ItemMod = parse_quote! {
+            #[cfg(feature = "a")]
+            #[cxx_qt::bridge]
+            #[cfg(unix)]
+            mod module;
+        };
+
+        let cfgs = attribute_take_cfgs(&mut module.attrs).unwrap();
+        assert_eq!(cfgs.len(), 2);
+        assert_eq!(module.attrs.len(), 1);
+        assert!(attribute_find_path(&module.attrs, &["cxx_qt", "bridge"]).is_some());
+
+        let mut module: ItemMod = parse_quote! {
+            #[cfg_attr(feature = "a", qml_element)]
+            mod module;
+        };
+        assert!(attribute_take_cfgs(&mut module.attrs).is_err());
+    }
 }
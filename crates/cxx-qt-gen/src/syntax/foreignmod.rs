@@ -4,12 +4,12 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use proc_macro2::{TokenStream, TokenTree};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream, Parser},
     spanned::Spanned,
-    Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod, Path, Receiver,
-    Result, Signature, Token, Visibility,
+    Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod, Path,
+    PathArguments, Receiver, Result, Signature, Token, Visibility,
 };
 
 /// For a given [syn::ForeignItem] return the [syn::ForeignItemType] if there is one
@@ -90,6 +90,9 @@ fn verbatim_to_foreign_type(tokens: &TokenStream) -> Result<Option<ForeignItemTy
 /// Representation of a specific type alias for CXX-Qt where we map between two idents with a single super.
 ///
 /// `type A = super::B`
+///
+/// The right side can also be an instance of a generic type, `type A = super::B<T>`,
+/// in which case the right ident is a generated name for the instance.
 #[derive(Clone)]
 pub struct ForeignTypeIdentAlias {
     /// Attributes on the alias
@@ -98,6 +101,8 @@ pub struct ForeignTypeIdentAlias {
     pub ident_left: Ident,
     /// The right side of the alias
     pub ident_right: Ident,
+    /// The path of the right side as written if it is a generic instance, `super::B<T>`
+    pub generic_instance: Option<Path>,
 }
 
 impl Parse for ForeignTypeIdentAlias {
@@ -114,7 +119,7 @@ impl Parse for ForeignTypeIdentAlias {
             let path: Path = input.parse()?;
             let _semi_colon: Token![;] = input.parse()?;
 
-            // Convert the path (super::T) to an ident (T)
+            // Take the last segment of the path (super::T)
             let ident_right = {
                 // We only support super::T for now due to CXX only supporting type T with no alias
                 // as an extern "Rust" type.
@@ -139,7 +144,21 @@ impl Parse for ForeignTypeIdentAlias {
                     ));
                 }
 
-                path.segments[1].ident.clone()
+                path.segments[1].clone()
+            };
+
+            // A generic instance is given a name of its own, as CXX needs a single ident for the type
+            let (ident_right, generic_instance) = match &ident_right.arguments {
+                PathArguments::None => (ident_right.ident, None),
+                PathArguments::AngleBracketed(_) => {
+                    (format_ident!("{ident_left}Rust"), Some(path.clone()))
+                }
+                PathArguments::Parenthesized(_) => {
+                    return Err(Error::new(
+                        path.span(),
+                        "Type alias path must be a type or generic type instance, super::T or super::T<A>",
+                    ));
+                }
             };
 
             if ident_left == ident_right {
@@ -153,6 +172,7 @@ impl Parse for ForeignTypeIdentAlias {
                 attrs,
                 ident_left,
                 ident_right,
+                generic_instance,
             })
         } else {
             // Error as we have parsed the attributes and visiblity but have an unknown stream
@@ -274,6 +294,7 @@ mod tests {
         assert_eq!(alias.attrs.len(), 1);
         assert_eq!(alias.ident_left, "A");
         assert_eq!(alias.ident_right, "B");
+        assert!(alias.generic_instance.is_none());
     }
 
     #[test]
@@ -308,6 +329,26 @@ mod tests {
         assert!(parse.is_err());
     }
 
+    #[test]
+    fn test_foreign_type_ident_alias_generic() {
+        let alias = syn::parse2::<ForeignTypeIdentAlias>(quote! {
+            type A = super::B<C>;
+        })
+        .unwrap();
+        assert_eq!(alias.ident_left, "A");
+        assert_eq!(alias.ident_right, "ARust");
+        let generic_instance = alias.generic_instance.unwrap();
+        assert_eq!(generic_instance, syn::parse_quote! { super::B<C> });
+    }
+
+    #[test]
+    fn test_foreign_type_ident_alias_parenthesized() {
+        let parse = syn::parse2::<ForeignTypeIdentAlias>(quote! {
+            type A = super::B(C);
+        });
+        assert!(parse.is_err());
+    }
+
     #[test]
     fn test_foreign_type_ident_visibility() {
         // Ensure that visibility does not error, later it might be stored
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::Path;
+use syn::{GenericArgument, Path, PathArguments, Type};
 
 /// Returns whether the [syn::Path] matches a given string slice
 pub fn path_compare_str(path: &Path, string: &[&str]) -> bool {
@@ -18,6 +18,26 @@ pub fn path_compare_str(path: &Path, string: &[&str]) -> bool {
             .any(|(segment, string)| segment.ident.to_string().as_str() != *string)
 }
 
+/// Returns the [syn::Path] without trailing commas in its generic arguments,
+/// so that paths written differently can be compared structurally
+pub fn path_normalize(path: &Path) -> Path {
+    let mut path = path.clone();
+    for segment in path.segments.iter_mut() {
+        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            // Remove any trailing comma, then normalize the nested paths
+            if let Some(argument) = arguments.args.pop() {
+                arguments.args.push(argument.into_value());
+            }
+            for argument in arguments.args.iter_mut() {
+                if let GenericArgument::Type(Type::Path(type_path)) = argument {
+                    type_path.path = path_normalize(&type_path.path);
+                }
+            }
+        }
+    }
+    path
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -32,4 +52,12 @@ mod tests {
         assert!(!path_compare_str(&path, &["a", "c", "b"]));
         assert!(!path_compare_str(&path, &["a", "b", "c", "d"]));
     }
+
+    #[test]
+    fn test_path_normalize() {
+        let path: Path = parse_quote! { super::A<super::B<C,>, D,> };
+        let expected: Path = parse_quote! { super::A<super::B<C>, D> };
+        assert_eq!(path_normalize(&path), expected);
+        assert_ne!(path_normalize(&path), parse_quote! { super::A<B<C>, D> });
+    }
 }
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A temporary directory for the tests of cxx-qt-gen and cxx-qt-build
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A uniquely named directory in the temporary directory of the system,
+/// which is removed with all of its contents when dropped
+///
+/// This is not part of the public API and only exists for tests which need to write files,
+/// such as the files which cxx-qt-build writes into `OUT_DIR`.
+#[doc(hidden)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Create a new empty directory, the name of which starts with the given prefix
+    pub fn new(prefix: &str) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = env::temp_dir().join(format!(
+            "{prefix}-{}-{}",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        // Remove anything left behind by a previous process with the same id
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).expect("Could not create temporary directory");
+        Self { path }
+    }
+
+    /// The path of the directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir_removed() {
+        let temp_dir = TempDir::new("cxx-qt-gen-temp-dir");
+        let other = TempDir::new("cxx-qt-gen-temp-dir");
+        assert_ne!(temp_dir.path(), other.path());
+
+        let path = temp_dir.path().to_owned();
+        fs::create_dir_all(path.join("a")).unwrap();
+        fs::write(path.join("a/b.txt"), "b").unwrap();
+        drop(temp_dir);
+        assert!(!path.exists());
+        assert!(other.path().exists());
+    }
+}
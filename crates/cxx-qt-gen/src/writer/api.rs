@@ -0,0 +1,453 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::generator::api::{
+    ApiDescription, MethodDescription, QEnumDescription, QObjectDescription,
+};
+
+/// The name of the QML element of a namespace registered with `qnamespace!`
+fn qml_namespace_name(namespace: &str) -> &str {
+    namespace.rsplit("::").next().unwrap_or(namespace)
+}
+
+/// The QEnums of the description which are in a QML namespace, grouped by their namespace
+fn qml_namespaces(api: &ApiDescription) -> BTreeMap<&str, Vec<&QEnumDescription>> {
+    let mut namespaces: BTreeMap<&str, Vec<&QEnumDescription>> = BTreeMap::new();
+    for qenum in api.qenums.iter().filter(|qenum| qenum.qml_namespace) {
+        namespaces
+            .entry(qenum.namespace.as_str())
+            .or_default()
+            .push(qenum);
+    }
+    namespaces
+}
+
+fn method_signature(method: &MethodDescription) -> String {
+    let parameters = method
+        .parameters
+        .iter()
+        .map(|parameter| format!("{} {}", parameter.ty, parameter.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} {}({parameters})", method.return_type, method.name)
+}
+
+/// Markdown for the docs of a list item, continuation lines are indented to stay in the item
+fn markdown_item_docs(docs: &[String]) -> String {
+    if docs.is_empty() {
+        return String::new();
+    }
+
+    let docs = docs
+        .iter()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("  {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(":\n{docs}")
+}
+
+fn markdown_docs(docs: &[String]) -> String {
+    if docs.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n\n", docs.join("\n"))
+    }
+}
+
+fn markdown_qenum(qenum: &QEnumDescription, heading: &str) -> String {
+    let mut markdown = format!("{heading} {}\n\n{}", qenum.name, markdown_docs(&qenum.docs));
+    for value in &qenum.values {
+        markdown.push_str(&format!(
+            "- `{}`{}\n",
+            value.name,
+            markdown_item_docs(&value.docs)
+        ));
+    }
+    markdown.push('\n');
+    markdown
+}
+
+fn markdown_qobject(qobject: &QObjectDescription, qml_name: &str) -> String {
+    let mut markdown = format!("## {qml_name}\n\n{}", markdown_docs(&qobject.docs));
+    if qobject.qml_singleton {
+        markdown.push_str("This type is a singleton.\n\n");
+    }
+    if qobject.qml_uncreatable {
+        markdown.push_str("This type can not be created from QML.\n\n");
+    }
+    markdown.push_str(&format!(
+        "C++ class `{}`, inherits `{}`.\n\n",
+        qobject.qualified_name(),
+        qobject.base_class
+    ));
+
+    if !qobject.properties.is_empty() {
+        markdown.push_str("### Properties\n\n");
+        for property in &qobject.properties {
+            markdown.push_str(&format!(
+                "- `{} {}`{}\n",
+                property.ty,
+                property.name,
+                markdown_item_docs(&property.docs)
+            ));
+        }
+        markdown.push('\n');
+    }
+
+    for (heading, methods) in [
+        ("Methods", &qobject.invokables),
+        ("Signals", &qobject.signals),
+    ] {
+        if methods.is_empty() {
+            continue;
+        }
+
+        markdown.push_str(&format!("### {heading}\n\n"));
+        for method in methods {
+            markdown.push_str(&format!(
+                "- `{}`{}\n",
+                method_signature(method),
+                markdown_item_docs(&method.docs)
+            ));
+        }
+        markdown.push('\n');
+    }
+
+    if !qobject.qenums.is_empty() {
+        markdown.push_str("### Enums\n\n");
+        for qenum in &qobject.qenums {
+            markdown.push_str(&markdown_qenum(qenum, "####"));
+        }
+    }
+
+    markdown
+}
+
+/// Write a Markdown description of the QML types in the given [ApiDescription] of a QML module
+pub fn write_api_markdown(api: &ApiDescription, uri: &str) -> String {
+    let mut markdown = format!("# {uri}\n\n");
+    for qobject in &api.qobjects {
+        if let Some(qml_name) = &qobject.qml_name {
+            markdown.push_str(&markdown_qobject(qobject, qml_name));
+        }
+    }
+
+    for (namespace, qenums) in qml_namespaces(api) {
+        markdown.push_str(&format!(
+            "## {}\n\nC++ namespace `{namespace}`.\n\n### Enums\n\n",
+            qml_namespace_name(namespace)
+        ));
+        for qenum in qenums {
+            markdown.push_str(&markdown_qenum(qenum, "####"));
+        }
+    }
+
+    // Remove the blank line after the last section
+    let len = markdown.trim_end().len();
+    markdown.truncate(len);
+    markdown.push('\n');
+    markdown
+}
+
+/// The JSON document of a QML module, containing the QML elements and the QEnums of QML namespaces
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct ApiJson<'a> {
+    module: &'a str,
+    qobjects: Vec<&'a QObjectDescription>,
+    qenums: Vec<&'a QEnumDescription>,
+}
+
+/// Write a JSON description of the QML types in the given [ApiDescription] of a QML module
+#[cfg(feature = "serde")]
+pub fn write_api_json(api: &ApiDescription, uri: &str) -> String {
+    let api_json = ApiJson {
+        module: uri,
+        qobjects: api
+            .qobjects
+            .iter()
+            .filter(|qobject| qobject.qml_name.is_some())
+            .collect(),
+        qenums: api
+            .qenums
+            .iter()
+            .filter(|qenum| qenum.qml_namespace)
+            .collect(),
+    };
+    let mut json =
+        serde_json::to_string_pretty(&api_json).expect("Could not serialize the API description");
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::api::{ParameterDescription, PropertyDescription, QEnumValueDescription};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    fn api() -> ApiDescription {
+        ApiDescription {
+            qobjects: vec![QObjectDescription {
+                name: "MyObject".to_owned(),
+                namespace: "my_namespace".to_owned(),
+                base_class: "QObject".to_owned(),
+                qml_name: Some("MyQmlObject".to_owned()),
+                qml_singleton: false,
+                qml_uncreatable: true,
+                docs: vec![
+                    "My object".to_owned(),
+                    "".to_owned(),
+                    "More docs".to_owned(),
+                ],
+                properties: vec![PropertyDescription {
+                    name: "number".to_owned(),
+                    ty: "::std::int32_t".to_owned(),
+                    docs: vec!["The number".to_owned()],
+                }],
+                invokables: vec![
+                    MethodDescription {
+                        name: "sayHi".to_owned(),
+                        return_type: "void".to_owned(),
+                        parameters: vec![ParameterDescription {
+                            name: "string".to_owned(),
+                            ty: "QString const&".to_owned(),
+                        }],
+                        docs: vec!["Say \"hi\"".to_owned()],
+                    },
+                    MethodDescription {
+                        name: "sayHi".to_owned(),
+                        return_type: "void".to_owned(),
+                        parameters: vec![
+                            ParameterDescription {
+                                name: "string".to_owned(),
+                                ty: "QString const&".to_owned(),
+                            },
+                            ParameterDescription {
+                                name: "count".to_owned(),
+                                ty: "::std::int32_t".to_owned(),
+                            },
+                        ],
+                        docs: vec!["Say \"hi\" a number of times".to_owned()],
+                    },
+                ],
+                signals: vec![MethodDescription {
+                    name: "ready".to_owned(),
+                    return_type: "void".to_owned(),
+                    parameters: vec![],
+                    docs: vec![],
+                }],
+                qenums: vec![QEnumDescription {
+                    name: "MyEnum".to_owned(),
+                    namespace: "my_namespace".to_owned(),
+                    qml_namespace: false,
+                    docs: vec!["My enum".to_owned()],
+                    values: vec![
+                        QEnumValueDescription {
+                            name: "A".to_owned(),
+                            docs: vec!["The A value".to_owned()],
+                        },
+                        QEnumValueDescription {
+                            name: "B".to_owned(),
+                            docs: vec![],
+                        },
+                    ],
+                }],
+            }],
+            qenums: vec![QEnumDescription {
+                name: "MyNamespacedEnum".to_owned(),
+                namespace: "my_namespace".to_owned(),
+                qml_namespace: true,
+                docs: vec![],
+                values: vec![QEnumValueDescription {
+                    name: "C".to_owned(),
+                    docs: vec![],
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_write_api_markdown() {
+        assert_str_eq!(
+            write_api_markdown(&api(), "com.kdab"),
+            indoc! {r#"
+            # com.kdab
+
+            ## MyQmlObject
+
+            My object
+
+            More docs
+
+            This type can not be created from QML.
+
+            C++ class `my_namespace::MyObject`, inherits `QObject`.
+
+            ### Properties
+
+            - `::std::int32_t number`:
+              The number
+
+            ### Methods
+
+            - `void sayHi(QString const& string)`:
+              Say "hi"
+            - `void sayHi(QString const& string, ::std::int32_t count)`:
+              Say "hi" a number of times
+
+            ### Signals
+
+            - `void ready()`
+
+            ### Enums
+
+            #### MyEnum
+
+            My enum
+
+            - `A`:
+              The A value
+            - `B`
+
+            ## my_namespace
+
+            C++ namespace `my_namespace`.
+
+            ### Enums
+
+            #### MyNamespacedEnum
+
+            - `C`
+            "#}
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_write_api_json() {
+        assert_str_eq!(
+            write_api_json(&api(), "com.kdab"),
+            indoc! {r#"
+            {
+              "module": "com.kdab",
+              "qobjects": [
+                {
+                  "name": "MyObject",
+                  "namespace": "my_namespace",
+                  "baseClass": "QObject",
+                  "qmlName": "MyQmlObject",
+                  "qmlSingleton": false,
+                  "qmlUncreatable": true,
+                  "docs": [
+                    "My object",
+                    "",
+                    "More docs"
+                  ],
+                  "properties": [
+                    {
+                      "name": "number",
+                      "type": "::std::int32_t",
+                      "docs": [
+                        "The number"
+                      ]
+                    }
+                  ],
+                  "invokables": [
+                    {
+                      "name": "sayHi",
+                      "returnType": "void",
+                      "parameters": [
+                        {
+                          "name": "string",
+                          "type": "QString const&"
+                        }
+                      ],
+                      "docs": [
+                        "Say \"hi\""
+                      ]
+                    },
+                    {
+                      "name": "sayHi",
+                      "returnType": "void",
+                      "parameters": [
+                        {
+                          "name": "string",
+                          "type": "QString const&"
+                        },
+                        {
+                          "name": "count",
+                          "type": "::std::int32_t"
+                        }
+                      ],
+                      "docs": [
+                        "Say \"hi\" a number of times"
+                      ]
+                    }
+                  ],
+                  "signals": [
+                    {
+                      "name": "ready",
+                      "returnType": "void",
+                      "parameters": [],
+                      "docs": []
+                    }
+                  ],
+                  "qenums": [
+                    {
+                      "name": "MyEnum",
+                      "namespace": "my_namespace",
+                      "qmlNamespace": false,
+                      "docs": [
+                        "My enum"
+                      ],
+                      "values": [
+                        {
+                          "name": "A",
+                          "docs": [
+                            "The A value"
+                          ]
+                        },
+                        {
+                          "name": "B",
+                          "docs": []
+                        }
+                      ]
+                    }
+                  ]
+                }
+              ],
+              "qenums": [
+                {
+                  "name": "MyNamespacedEnum",
+                  "namespace": "my_namespace",
+                  "qmlNamespace": true,
+                  "docs": [],
+                  "values": [
+                    {
+                      "name": "C",
+                      "docs": []
+                    }
+                  ]
+                }
+              ]
+            }
+            "#}
+        );
+    }
+}
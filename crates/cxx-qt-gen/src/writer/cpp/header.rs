@@ -90,7 +90,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
         let class_definition = namespaced(
             &qobject.namespace,
             &formatdoc! { r#"
-                class {ident} : {base_classes}
+                {doc_comment}class {ident} : {base_classes}
                 {{
                   {qobject_macro}
                 public:
@@ -98,15 +98,17 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
 
                   virtual ~{ident}() = default;
 
-                {public_methods}
+                {public_methods}{protected_methods}
                 {private_methods}
                 }};
 
                 {qobject_assert}"#,
+            doc_comment = qobject.doc_comment,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.iter().map(|base| format!("public {}", base)).collect::<Vec<String>>().join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
             public_methods = create_block("public", &qobject.blocks.methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+            protected_methods = create_block("protected", &qobject.blocks.protected_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
             private_methods = create_block("private", &qobject.blocks.private_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
         });
 
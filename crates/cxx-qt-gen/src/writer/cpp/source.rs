@@ -26,6 +26,7 @@ fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
                 .blocks
                 .methods
                 .iter()
+                .chain(qobject.blocks.protected_methods.iter())
                 .chain(qobject.blocks.private_methods.iter())
                 .filter_map(pair_as_source)
                 .collect::<Vec<String>>()
@@ -3,5 +3,6 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod api;
 pub mod cpp;
 pub mod rust;
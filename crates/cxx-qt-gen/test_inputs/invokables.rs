@@ -16,6 +16,7 @@ mod ffi {
     }
 
     unsafe extern "RustQt" {
+        /// A QObject with invokables
         #[qobject]
         type MyObject = super::MyObjectRust;
 
@@ -27,6 +28,9 @@ mod ffi {
         #[qinvokable]
         fn invokable_mutable(self: Pin<&mut MyObject>);
 
+        /// An invokable with parameters
+        ///
+        /// Which are opaque, trivial, and primitive
         #[qinvokable]
         fn invokable_parameters(self: &MyObject, opaque: &QColor, trivial: &QPoint, primitive: i32);
 
@@ -53,6 +57,14 @@ mod ffi {
 
         #[qinvokable]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
+
+        #[qinvokable]
+        #[cxx_name = "invokableOverloaded"]
+        fn invokable_overloaded_int(self: &MyObject, value: i32, #[cxx_default = "true"] notify: bool);
+
+        #[qinvokable]
+        #[cxx_name = "invokableOverloaded"]
+        fn invokable_overloaded_string(self: &MyObject, value: &QString);
     }
 
     impl cxx_qt::Threading for MyObject {}
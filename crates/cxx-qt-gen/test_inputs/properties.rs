@@ -9,6 +9,7 @@ mod ffi {
     extern "RustQt" {
         #[qobject]
         #[derive(Default)]
+        /// A primitive property
         #[qproperty(i32, primitive)]
         #[qproperty(QPoint, trivial)]
         type MyObject = super::MyObjectRust;
@@ -15,9 +15,11 @@ mod ffi {
     #[qml_element]
     qnamespace!("cxx_qt::my_object");
 
+    /// An enum in a namespace
     #[qenum]
     enum MyNamespacedEnum {
         A,
+        /// The B value
         B,
         C,
     }
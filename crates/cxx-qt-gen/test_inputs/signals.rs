@@ -23,6 +23,7 @@ mod ffi {
         #[qobject]
         type MyObject = super::MyObjectRust;
 
+        /// Emitted when the object is ready
         #[qsignal]
         fn ready(self: Pin<&mut MyObject>);
 
@@ -0,0 +1,26 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[cxx_subclassable]
+        type MyObject = super::MyObjectRust;
+    }
+
+    unsafe extern "RustQt" {
+        #[qinvokable]
+        fn invokable(self: &MyObject);
+
+        #[qinvokable]
+        #[cxx_virtual]
+        fn invokable_virtual(self: &MyObject);
+
+        #[qinvokable]
+        #[cxx_virtual]
+        fn invokable_virtual_mutable(self: Pin<&mut MyObject>, value: i32);
+
+        #[qinvokable]
+        #[cxx_virtual]
+        #[cxx_final]
+        fn invokable_final(self: &MyObject);
+    }
+}
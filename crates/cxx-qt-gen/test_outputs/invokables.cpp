@@ -80,6 +80,20 @@ MyObject::invokableResultType() const
   return invokableResultTypeWrapper();
 }
 
+void
+MyObject::invokableOverloaded(::std::int32_t value, bool notify) const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableOverloadedIntWrapper(value, notify);
+}
+
+void
+MyObject::invokableOverloaded(QString const& value) const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableOverloadedStringWrapper(value);
+}
+
 static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
               "unexpected aligment");
 static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[4]),
@@ -13,6 +13,7 @@ using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
 #include "cxx-qt-gen/ffi.cxx.h"
 
 namespace cxx_qt::my_object {
+/// A QObject with invokables
 class MyObject
   : public QObject
   , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
@@ -26,6 +27,9 @@ public:
   void cppMethod() const;
   Q_INVOKABLE void invokable() const;
   Q_INVOKABLE void invokableMutable();
+  /// An invokable with parameters
+  ///
+  /// Which are opaque, trivial, and primitive
   Q_INVOKABLE void invokableParameters(QColor const& opaque,
                                        QPoint const& trivial,
                                        ::std::int32_t primitive) const;
@@ -36,6 +40,9 @@ public:
   Q_INVOKABLE virtual void invokableVirtual() const;
   Q_INVOKABLE void invokableResultTuple() const;
   Q_INVOKABLE ::rust::String invokableResultType() const;
+  Q_INVOKABLE void invokableOverloaded(::std::int32_t value,
+                                       bool notify = true) const;
+  Q_INVOKABLE void invokableOverloaded(QString const& value) const;
   explicit MyObject(::std::int32_t arg0, QString const& arg1);
   explicit MyObject();
 
@@ -53,6 +60,9 @@ private:
   void invokableVirtualWrapper() const noexcept;
   void invokableResultTupleWrapper() const;
   ::rust::String invokableResultTypeWrapper() const;
+  void invokableOverloadedIntWrapper(::std::int32_t value,
+                                     bool notify) const noexcept;
+  void invokableOverloadedStringWrapper(QString const& value) const noexcept;
   explicit MyObject(
     ::cxx_qt::my_object::cxx_qt_my_object::CxxQtConstructorArguments0&& args);
   explicit MyObject(
@@ -95,11 +95,25 @@ mod ffi {
         #[cxx_name = "invokableResultTypeWrapper"]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
     }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableOverloadedIntWrapper"]
+        fn invokable_overloaded_int(self: &MyObject, value: i32, notify: bool);
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableOverloadedStringWrapper"]
+        fn invokable_overloaded_string(self: &MyObject, value: &QString);
+    }
     unsafe extern "C++" {
         #[doc(hidden)]
         type MyObjectCxxQtThread = cxx_qt::CxxQtThread<MyObject>;
         include!("cxx-qt/thread.h");
         #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[cxx_name = "CxxQtThreadQueueStatus"]
+        type MyObjectCxxQtThreadQueueStatus = cxx_qt::CxxQtThreadQueueStatus;
+        #[doc(hidden)]
         #[cxx_name = "qtThread"]
         fn cxx_qt_ffi_qt_thread(self: &MyObject) -> MyObjectCxxQtThread;
         #[doc(hidden)]
@@ -109,7 +123,15 @@ mod ffi {
             cxx_qt_thread: &MyObjectCxxQtThread,
             func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
             arg: Box<MyObjectCxxQtThreadQueuedFn>,
-        ) -> Result<()>;
+        ) -> MyObjectCxxQtThreadQueueStatus;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        fn cxx_qt_ffi_my_object_is_current_thread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        fn cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
         #[doc(hidden)]
         #[namespace = "rust::cxxqt1"]
         #[cxx_name = "cxxQtThreadClone"]
@@ -235,7 +257,7 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
-    ) -> std::result::Result<(), cxx::Exception>
+    ) -> std::result::Result<(), cxx_qt::CxxQtThreadError>
     where
         F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
         F: Send + 'static,
@@ -252,6 +274,15 @@ impl cxx_qt::Threading for ffi::MyObject {
             inner: std::boxed::Box::new(f),
         };
         ffi::cxx_qt_ffi_my_object_queue_boxed_fn(cxx_qt_thread, func, std::boxed::Box::new(arg))
+            .into()
+    }
+    #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_my_object_is_current_thread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread)
     }
     #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> ffi::MyObjectCxxQtThread {
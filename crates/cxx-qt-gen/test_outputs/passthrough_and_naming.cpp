@@ -290,7 +290,7 @@ namespace cxx_qt::multi_object {
 ::std::int32_t const&
 MyObject::getPropertyName() const
 {
-  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
   return getPropertyNameWrapper();
 }
 
@@ -438,7 +438,7 @@ namespace second_object {
 ::std::int32_t const&
 SecondObject::getPropertyName() const
 {
-  const ::rust::cxxqt1::MaybeLockGuard<SecondObject> guard(*this);
+  const ::rust::cxxqt1::MaybeSharedLockGuard<SecondObject> guard(*this);
   return getPropertyNameWrapper();
 }
 
@@ -120,7 +120,7 @@ namespace cxx_qt::my_object {
 ::std::int32_t const&
 MyObject::getPrimitive() const
 {
-  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
   return getPrimitiveWrapper();
 }
 
@@ -134,7 +134,7 @@ MyObject::setPrimitive(::std::int32_t const& value)
 QPoint const&
 MyObject::getTrivial() const
 {
-  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  const ::rust::cxxqt1::MaybeSharedLockGuard<MyObject> guard(*this);
   return getTrivialWrapper();
 }
 
@@ -49,6 +49,7 @@ class MyObject
 {
   Q_OBJECT
 public:
+  /// A primitive property
   Q_PROPERTY(::std::int32_t primitive READ getPrimitive WRITE setPrimitive
                NOTIFY primitiveChanged)
   Q_PROPERTY(
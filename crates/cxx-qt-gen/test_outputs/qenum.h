@@ -34,9 +34,11 @@ Q_NAMESPACE
 
 namespace cxx_qt::my_object {
 Q_NAMESPACE
+/// An enum in a namespace
 enum class MyNamespacedEnum : ::std::int32_t
 {
   A,
+  /// The B value
   B,
   C
 };
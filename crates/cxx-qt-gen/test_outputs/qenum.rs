@@ -13,8 +13,10 @@ mod ffi {
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
     }
     #[repr(i32)]
+    #[doc = " An enum in a namespace"]
     enum MyNamespacedEnum {
         A,
+        #[doc = " The B value"]
         B,
         C,
     }
@@ -78,6 +78,7 @@ public:
 
 public:
   Q_INVOKABLE void invokable();
+  /// Emitted when the object is ready
   Q_SIGNAL void ready();
   Q_SIGNAL void dataChanged(::std::int32_t first,
                             ::std::unique_ptr<Opaque> second,
@@ -40,6 +40,7 @@ mod ffi {
         fn invokable(self: Pin<&mut MyObject>);
     }
     unsafe extern "C++" {
+        #[doc = " Emitted when the object is ready"]
         #[cxx_name = "ready"]
         fn ready(self: Pin<&mut MyObject>);
     }
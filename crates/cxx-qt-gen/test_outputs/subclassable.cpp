@@ -0,0 +1,40 @@
+#include "cxx-qt-gen/ffi.cxxqt.h"
+
+namespace cxx_qt::my_object {
+void
+MyObject::invokable() const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableWrapper();
+}
+
+void
+MyObject::invokableVirtual() const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableVirtualWrapper();
+}
+
+void
+MyObject::invokableVirtualMutable(::std::int32_t value)
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableVirtualMutableWrapper(value);
+}
+
+void
+MyObject::invokableFinal() const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  invokableFinalWrapper();
+}
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , ::rust::cxxqt1::CxxQtLocking()
+{
+}
+
+} // namespace cxx_qt::my_object
@@ -0,0 +1,44 @@
+#pragma once
+
+#include <cxx-qt/locking.h>
+#include <cxx-qt/maybelockguard.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MyObject;
+
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/ffi.cxx.h"
+
+namespace cxx_qt::my_object {
+class MyObject
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+  , public ::rust::cxxqt1::CxxQtLocking
+{
+  Q_OBJECT
+public:
+  virtual ~MyObject() = default;
+
+public:
+  Q_INVOKABLE void invokable() const;
+  Q_INVOKABLE virtual void invokableVirtual() const;
+  Q_INVOKABLE virtual void invokableVirtualMutable(::std::int32_t value);
+  Q_INVOKABLE virtual void invokableFinal() const final;
+
+protected:
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  void invokableWrapper() const noexcept;
+  void invokableVirtualWrapper() const noexcept;
+  void invokableVirtualMutableWrapper(::std::int32_t value) noexcept;
+  void invokableFinalWrapper() const noexcept;
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,96 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/ffi.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MyObject;
+    }
+    extern "Rust" {
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableWrapper"]
+        fn invokable(self: &MyObject);
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableVirtualWrapper"]
+        fn invokable_virtual(self: &MyObject);
+    }
+    unsafe extern "C++" {
+        #[doc = "Call the C++ virtual method invokableVirtual, this calls any override from a C++ subclass.\n\nDo not call this from the Rust implementation of invokable_virtual, as it would then call itself forever."]
+        #[cxx_name = "invokableVirtual"]
+        fn invokable_virtual_dispatch(self: &MyObject);
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableVirtualMutableWrapper"]
+        fn invokable_virtual_mutable(self: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[doc = "Call the C++ virtual method invokableVirtualMutable, this calls any override from a C++ subclass.\n\nDo not call this from the Rust implementation of invokable_virtual_mutable, as it would then call itself forever."]
+        #[cxx_name = "invokableVirtualMutable"]
+        fn invokable_virtual_mutable_dispatch(self: Pin<&mut MyObject>, value: i32);
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableFinalWrapper"]
+        fn invokable_final(self: &MyObject);
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object_rust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRust"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust(self: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+}
+impl cxx_qt::Locking for ffi::MyObject {}
+#[doc(hidden)]
+pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        self.cxx_qt_ffi_rust()
+    }
+}
+impl cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        self.cxx_qt_ffi_rust()
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        self.cxx_qt_ffi_rust_mut()
+    }
+}
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDir>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QDir> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qdirCleanPath(const QString& path);
+QString
+qdirCurrentPath();
+QStringList
+qdirEntryList(const QDir& dir);
+QString
+qdirFromNativeSeparators(const QString& pathName);
+QString
+qdirHomePath();
+QString
+qdirRootPath();
+bool
+qdirSetCurrent(const QString& path);
+QString
+qdirTempPath();
+QString
+qdirToNativeSeparators(const QString& pathName);
+
+}
+}
@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QFile>
+#include <QtCore/QIODevice>
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QIODevice&
+qfileAsQIODevice(const QFile& file);
+QIODevice&
+qfileAsQIODeviceMut(QFile& file);
+bool
+qfileFlush(const QFile& file);
+::std::unique_ptr<QFile>
+qfileNew(const QString& name);
+
+}
+}
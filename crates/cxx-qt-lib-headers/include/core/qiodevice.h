@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QIODevice>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+
+::std::int64_t
+qiodeviceBytesAvailable(const QIODevice& device);
+bool
+qiodeviceOpen(QIODevice& device, QIODeviceOpenModeFlag mode);
+::std::int64_t
+qiodevicePos(const QIODevice& device);
+::std::int64_t
+qiodeviceRead(const QIODevice& device, ::rust::Slice<::std::uint8_t> data);
+QByteArray
+qiodeviceReadAll(QIODevice& device);
+bool
+qiodeviceSeek(const QIODevice& device, ::std::int64_t pos);
+::std::int64_t
+qiodeviceSize(const QIODevice& device);
+::std::int64_t
+qiodeviceWrite(const QIODevice& device,
+               ::rust::Slice<const ::std::uint8_t> data);
+
+}
+}
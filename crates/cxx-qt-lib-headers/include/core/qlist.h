@@ -31,6 +31,10 @@
 #include <QtGui/QColor>
 #endif
 
+#ifdef CXX_QT_QML_FEATURE
+#include <QtQml/QQmlError>
+#endif
+
 #include "rust/cxx.h"
 
 // In Qt 6 QList and QVector are the same, so we only need IsRelocatable defined
@@ -160,6 +164,9 @@ using QList_QMarginsF = QList<::QMarginsF>;
 using QList_QPersistentModelIndex = QList<::QPersistentModelIndex>;
 using QList_QPoint = QList<::QPoint>;
 using QList_QPointF = QList<::QPointF>;
+#ifdef CXX_QT_QML_FEATURE
+using QList_QQmlError = QList<::QQmlError>;
+#endif
 using QList_QRect = QList<::QRect>;
 using QList_QRectF = QList<::QRectF>;
 using QList_QSize = QList<::QSize>;
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// This is a template so that it can be bound for any QObject subclass,
+// including QObjects that are defined in a CXX-Qt bridge
+template<typename T>
+bool
+qobjectMoveToThread(T& object, const QThread& thread)
+{
+  // An object can only be pushed away from the thread that it lives in
+  // and an object with a parent must be moved together with its parent
+  if (object.thread() != QThread::currentThread() ||
+      object.parent() != nullptr) {
+    return false;
+  }
+
+  object.moveToThread(const_cast<QThread*>(&thread));
+  return object.thread() == &thread;
+}
+
+}
+}
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QResource>
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QResource>
+qresourceNew(const QString& file);
+bool
+qresourceRegisterResource(const QString& rccFileName, const QString& mapRoot);
+::std::int64_t
+qresourceSize(const QResource& resource);
+QByteArray
+qresourceUncompressedData(const QResource& resource);
+bool
+qresourceUnregisterResource(const QString& rccFileName,
+                            const QString& mapRoot);
+
+}
+}
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSettings>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSettingsFormat = QSettings::Format;
+using QSettingsScope = QSettings::Scope;
+using QSettingsStatus = QSettings::Status;
+
+// Qt 6.4 changed the key parameters to QAnyStringView so use helpers
+// rather than binding the member functions directly
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix);
+
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix);
+
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size);
+
+bool
+qsettingsContains(const QSettings& settings, const QString& key);
+
+::std::unique_ptr<QSettings>
+qsettingsNew();
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFile(const QString& fileName, QSettingsFormat format);
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application);
+
+void
+qsettingsRemove(QSettings& settings, const QString& key);
+
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value);
+
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key);
+
+}
+}
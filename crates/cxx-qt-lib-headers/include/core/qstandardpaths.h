@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStandardPaths>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QStandardPathsStandardLocation = QStandardPaths::StandardLocation;
+
+QString
+qstandardpathsDisplayName(QStandardPathsStandardLocation location);
+QString
+qstandardpathsFindExecutable(const QString& executableName);
+QString
+qstandardpathsLocate(QStandardPathsStandardLocation location,
+                     const QString& fileName);
+QStringList
+qstandardpathsLocateAll(QStandardPathsStandardLocation location,
+                        const QString& fileName);
+QStringList
+qstandardpathsStandardLocations(QStandardPathsStandardLocation location);
+QString
+qstandardpathsWritableLocation(QStandardPathsStandardLocation location);
+
+}
+}
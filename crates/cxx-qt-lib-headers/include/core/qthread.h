@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject&
+qthreadAsQObject(QThread& thread);
+
+::std::unique_ptr<QThread>
+qthreadNew();
+
+void
+qthreadStart(QThread& thread);
+
+bool
+qthreadWait(QThread& thread);
+
+bool
+qthreadWaitFor(QThread& thread, ::std::uint64_t msecs);
+
+}
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QTimer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+struct QTimerSingleShotClosure;
+
+::std::unique_ptr<QTimer>
+qtimerNew();
+
+QObject&
+qtimerAsQObject(QTimer& timer);
+
+bool
+qtimerSingleShot(::std::int32_t msec,
+                 const QObject& context,
+                 ::rust::Box<QTimerSingleShotClosure> closure);
+
+}
+}
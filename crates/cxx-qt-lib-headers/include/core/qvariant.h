@@ -31,6 +31,10 @@
 #include <QtGui/QColor>
 #endif
 
+#ifdef CXX_QT_QML_FEATURE
+#include <QtQml/QJSValue>
+#endif
+
 #include "rust/cxx.h"
 
 // Define namespace otherwise we hit a GCC bug
@@ -80,6 +84,9 @@ CXX_QT_QVARIANT_CAN_CONVERT(QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT(QDate)
 CXX_QT_QVARIANT_CAN_CONVERT(QDateTime)
+#ifdef CXX_QT_QML_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT(QJSValue)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT(QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPoint)
@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QPointF>
+#include <QtGui/QMouseEvent>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPointF
+qmouseeventPosition(const QMouseEvent& event);
+
+QPointF
+qmouseeventScenePosition(const QMouseEvent& event);
+
+}
+}
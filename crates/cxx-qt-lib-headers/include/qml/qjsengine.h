@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber);
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew();
+
+void
+qjsengineSetGlobalQObject(QJSEngine& engine,
+                          const QString& name,
+                          QObject* object);
+
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value);
+
+}
+}
+
+#endif
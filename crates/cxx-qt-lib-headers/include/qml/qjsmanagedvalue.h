@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtCore/QtGlobal>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 1, 0))
+
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSManagedValue>
+#include <QtQml/QJSValue>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QJSManagedValue only has a pointer to the value on the JavaScript heap
+// as a member, which is moved along with it.
+//
+// This has static asserts in the cpp file to ensure this is valid.
+template<>
+struct IsRelocatable<QJSManagedValue> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QJSManagedValueType = QJSManagedValue::Type;
+
+QJSValue
+qjsmanagedvalueCall(const QJSManagedValue& value,
+                    ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsmanagedvalueCallAsConstructor(const QJSManagedValue& value,
+                                 ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsmanagedvalueCallWithInstance(const QJSManagedValue& value,
+                                const QJSValue& instance,
+                                ::rust::Slice<const QJSValue> args);
+QJSManagedValue
+qjsmanagedvalueInitQJSValue(const QJSValue& value, QJSEngine& engine);
+
+}
+}
+
+#endif
+
+#endif
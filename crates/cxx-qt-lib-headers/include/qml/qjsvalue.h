@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtQml/QJSValue>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QJSValue only has a d-pointer or an encoded quint64 as a member,
+// which is moved along with it.
+//
+// This has static asserts in the cpp file to ensure this is valid.
+template<>
+struct IsRelocatable<QJSValue> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QJSValueErrorType = QJSValue::ErrorType;
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args);
+QJSValue
+qjsvalueInitNull();
+
+}
+}
+
+#endif
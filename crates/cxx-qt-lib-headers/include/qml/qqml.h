@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <cstdint>
+
+#include <QtQml/QQmlEngine>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qmlRegisterSingletonInstanceQObject(::rust::Str uri,
+                                    ::std::int32_t versionMajor,
+                                    ::std::int32_t versionMinor,
+                                    ::rust::Str typeName,
+                                    QObject* instance);
+
+}
+}
+
+#endif
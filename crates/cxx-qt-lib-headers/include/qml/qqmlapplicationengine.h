@@ -13,6 +13,8 @@
 #include <QtQml/QQmlApplicationEngine>
 #include <QtQml/QQmlEngine>
 
+#include "rust/cxx.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -22,6 +24,13 @@ qqmlapplicationengineNew();
 QQmlEngine&
 qqmlapplicationengineAsQQmlEngine(QQmlApplicationEngine&);
 
+QObject*
+qqmlapplicationengineRootObject(const QQmlApplicationEngine& engine,
+                                ::rust::isize pos);
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine);
+
 }
 }
 
@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQmlComponentStatus = QQmlComponent::Status;
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine);
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNewFromUrl(QQmlEngine& engine, const QUrl& url);
+
+}
+}
+
+#endif
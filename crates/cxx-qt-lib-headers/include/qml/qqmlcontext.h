@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlContext>
+qqmlcontextNew(QQmlEngine& engine);
+
+::std::unique_ptr<QQmlContext>
+qqmlcontextNewChild(QQmlContext& parentContext);
+
+}
+}
+
+#endif
@@ -10,11 +10,15 @@
 
 #include <memory>
 
+#include <QtQml/QJSEngine>
 #include <QtQml/QQmlEngine>
 
 namespace rust {
 namespace cxxqtlib1 {
 
+QJSEngine&
+qqmlengineAsQJSEngine(QQmlEngine&);
+
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew();
 
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <QtQml/QQmlError>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QQmlError only has a d-pointer as a member, which is moved along with it.
+//
+// This has static asserts in the cpp file to ensure this is valid.
+template<>
+struct IsRelocatable<QQmlError> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+#endif
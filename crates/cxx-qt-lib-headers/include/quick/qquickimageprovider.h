@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <atomic>
+#include <memory>
+
+#include <QtCore/QMutex>
+#include <QtCore/QString>
+#include <QtGui/QImage>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// These are defined by the CXX bridge
+struct QQuickImageProviderRust;
+struct QQuickAsyncImageProviderRust;
+
+class RustQQuickImageResponse;
+
+// The handle is shared between the QQuickImageResponse, which is owned by the
+// QML engine, and Rust, which can finish the response from any thread.
+//
+// The response detaches itself when it is destroyed, so that a late call from
+// Rust does not access a deleted response.
+class QQuickImageResponseHandle
+{
+public:
+  explicit QQuickImageResponseHandle(RustQQuickImageResponse* response);
+
+  void finish(const QImage& image) const;
+  void finishWithError(const QString& errorString) const;
+  bool isCancelled() const;
+
+  void cancel();
+  void detach();
+
+private:
+  mutable QMutex m_mutex;
+  RustQQuickImageResponse* m_response;
+  ::std::atomic<bool> m_cancelled;
+};
+
+void
+qquickimageproviderAdd(QQmlEngine& engine,
+                       const QString& id,
+                       ::rust::Box<QQuickImageProviderRust> provider,
+                       bool forceAsynchronousImageLoading);
+
+void
+qquickasyncimageproviderAdd(QQmlEngine& engine,
+                            const QString& id,
+                            ::rust::Box<QQuickAsyncImageProviderRust> provider);
+
+}
+}
+
+#endif
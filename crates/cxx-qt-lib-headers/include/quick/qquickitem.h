@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <QtQuick/QQuickItem>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QQuickItemFlag = QQuickItem::Flag;
+// This allows for updatePaintNode to be overridden from a CXX-Qt bridge
+using QQuickItemUpdatePaintNodeData = QQuickItem::UpdatePaintNodeData;
+
+void
+qquickitemSetAcceptedMouseButtons(QQuickItem& item, Qt::MouseButton buttons);
+
+}
+}
+
+#endif
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtGui/QImage>
+#include <QtQuick/QQuickWindow>
+#include <QtQuick/QSGTexture>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSGTexture>
+qquickwindowCreateTextureFromImage(const QQuickWindow& window,
+                                   const QImage& image);
+
+QString
+qquickwindowSceneGraphBackend();
+
+void
+qquickwindowSetSceneGraphBackend(const QString& backend);
+
+}
+}
+
+#endif
@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <cinttypes>
+#include <memory>
+
+#include <QtGui/QColor>
+#include <QtQuick/QSGGeometry>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSGGeometryDrawingMode = QSGGeometry::DrawingMode;
+
+QSGGeometryDrawingMode
+qsggeometryDrawingMode(const QSGGeometry& geometry);
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewColoredPoint2D(::std::int32_t vertexCount,
+                             ::std::int32_t indexCount);
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewPoint2D(::std::int32_t vertexCount, ::std::int32_t indexCount);
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewTexturedPoint2D(::std::int32_t vertexCount,
+                              ::std::int32_t indexCount);
+
+void
+qsggeometrySetDrawingMode(QSGGeometry& geometry, QSGGeometryDrawingMode mode);
+
+void
+qsggeometrySetIndex(QSGGeometry& geometry,
+                    ::std::int32_t index,
+                    ::std::uint16_t value);
+
+void
+qsggeometrySetVertexColoredPoint2D(QSGGeometry& geometry,
+                                   ::std::int32_t index,
+                                   float x,
+                                   float y,
+                                   const QColor& color);
+
+void
+qsggeometrySetVertexPoint2D(QSGGeometry& geometry,
+                            ::std::int32_t index,
+                            float x,
+                            float y);
+
+void
+qsggeometrySetVertexTexturedPoint2D(QSGGeometry& geometry,
+                                    ::std::int32_t index,
+                                    float x,
+                                    float y,
+                                    float tx,
+                                    float ty);
+
+::std::int32_t
+qsggeometrySizeOfColoredPoint2D();
+
+::std::int32_t
+qsggeometrySizeOfPoint2D();
+
+::std::int32_t
+qsggeometrySizeOfTexturedPoint2D();
+
+}
+}
+
+#endif
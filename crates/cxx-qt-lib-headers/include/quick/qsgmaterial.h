@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtQuick/QSGFlatColorMaterial>
+#include <QtQuick/QSGMaterial>
+#include <QtQuick/QSGTextureMaterial>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+QSGMaterial*
+qsgmaterialUpcast(T* material)
+{
+  return static_cast<QSGMaterial*>(material);
+}
+
+template<typename T>
+T*
+qsgmaterialDowncast(QSGMaterial* material)
+{
+  return static_cast<T*>(material);
+}
+
+::std::unique_ptr<QSGFlatColorMaterial>
+qsgflatcolormaterialNew();
+
+::std::unique_ptr<QSGTextureMaterial>
+qsgtexturematerialNew();
+
+}
+}
+
+#endif
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtCore/QRectF>
+#include <QtGui/QColor>
+#include <QtQuick/QSGGeometryNode>
+#include <QtQuick/QSGNode>
+#include <QtQuick/QSGSimpleRectNode>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSGNodeFlag = QSGNode::Flag;
+using QSGNodeDirtyStateBit = QSGNode::DirtyStateBit;
+
+template<typename T>
+QSGNode*
+qsgnodeUpcast(T* node)
+{
+  return static_cast<QSGNode*>(node);
+}
+
+template<typename T>
+T*
+qsgnodeDowncast(QSGNode* node)
+{
+  return static_cast<T*>(node);
+}
+
+void
+qsgnodeMarkDirty(QSGNode& node, QSGNodeDirtyStateBit bits);
+
+::std::unique_ptr<QSGNode>
+qsgnodeNew();
+
+void
+qsgnodeRemoveAllChildNodesAndDelete(QSGNode& node);
+
+::std::unique_ptr<QSGGeometryNode>
+qsggeometrynodeNew();
+
+::std::unique_ptr<QSGSimpleRectNode>
+qsgsimplerectnodeNew(const QRectF& rect, const QColor& color);
+
+}
+}
+
+#endif
@@ -0,0 +1,18 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <QtQuick/QSGTexture>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSGTextureFiltering = QSGTexture::Filtering;
+}
+}
+
+#endif
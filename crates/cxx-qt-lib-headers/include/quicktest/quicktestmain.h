@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK_TEST_FEATURE
+
+#include <cinttypes>
+
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+quicktestRun(const QString& name,
+             const QString& source,
+             const QString& sceneGraphBackend,
+             const QStringList& arguments);
+
+}
+}
+
+#endif
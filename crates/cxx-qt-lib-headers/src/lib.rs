@@ -15,6 +15,7 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
     let mut opts = cxx_qt_build::CxxQtBuildersOpts::default();
 
     for (file_contents, file_name) in [
+        (include_str!("../include/core/executor.h"), "executor.h"),
         (include_str!("../include/core/qbytearray.h"), "qbytearray.h"),
         (
             include_str!("../include/core/qcoreapplication.h"),
@@ -22,7 +23,11 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
         ),
         (include_str!("../include/core/qdate.h"), "qdate.h"),
         (include_str!("../include/core/qdatetime.h"), "qdatetime.h"),
+        (include_str!("../include/core/qdir.h"), "qdir.h"),
+        (include_str!("../include/core/qfile.h"), "qfile.h"),
+        (include_str!("../include/core/qfileinfo.h"), "qfileinfo.h"),
         (include_str!("../include/core/qhash.h"), "qhash.h"),
+        (include_str!("../include/core/qiodevice.h"), "qiodevice.h"),
         (include_str!("../include/core/qline.h"), "qline.h"),
         (include_str!("../include/core/qlinef.h"), "qlinef.h"),
         (include_str!("../include/core/qlist.h"), "qlist.h"),
@@ -41,6 +46,7 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
             include_str!("../include/core/qmodelindex.h"),
             "qmodelindex.h",
         ),
+        (include_str!("../include/core/qobject.h"), "qobject.h"),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qpen.h"), "qpen.h"),
         (
@@ -51,16 +57,24 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
         (include_str!("../include/core/qpointf.h"), "qpointf.h"),
         (include_str!("../include/core/qrect.h"), "qrect.h"),
         (include_str!("../include/core/qrectf.h"), "qrectf.h"),
+        (include_str!("../include/core/qresource.h"), "qresource.h"),
         (include_str!("../include/core/qset.h"), "qset.h"),
+        (include_str!("../include/core/qsettings.h"), "qsettings.h"),
         (include_str!("../include/core/qsize.h"), "qsize.h"),
         (include_str!("../include/core/qsizef.h"), "qsizef.h"),
+        (
+            include_str!("../include/core/qstandardpaths.h"),
+            "qstandardpaths.h",
+        ),
         (include_str!("../include/core/qstring.h"), "qstring.h"),
         (
             include_str!("../include/core/qstringlist.h"),
             "qstringlist.h",
         ),
         (include_str!("../include/core/qt.h"), "qt.h"),
+        (include_str!("../include/core/qthread.h"), "qthread.h"),
         (include_str!("../include/core/qtime.h"), "qtime.h"),
+        (include_str!("../include/core/qtimer.h"), "qtimer.h"),
         (include_str!("../include/core/qtimezone.h"), "qtimezone.h"),
         (include_str!("../include/core/qurl.h"), "qurl.h"),
         (include_str!("../include/core/qvariant.h"), "qvariant.h"),
@@ -77,6 +91,13 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qimage.h"), "qimage.h"),
         #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qkeyevent.h"), "qkeyevent.h"),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qmouseevent.h"),
+            "qmouseevent.h",
+        ),
+        #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qpolygon.h"), "qpolygon.h"),
         (include_str!("../include/gui/qpolygonf.h"), "qpolygonf.h"),
         (
@@ -94,12 +115,72 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector4d.h"), "qvector4d.h"),
         #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qjsengine.h"), "qjsengine.h"),
+        #[cfg(feature = "qt_qml")]
+        (
+            include_str!("../include/qml/qjsmanagedvalue.h"),
+            "qjsmanagedvalue.h",
+        ),
+        #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qjsvalue.h"), "qjsvalue.h"),
+        #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qqml.h"), "qqml.h"),
+        #[cfg(feature = "qt_qml")]
         (
             include_str!("../include/qml/qqmlapplicationengine.h"),
             "qqmlapplicationengine.h",
         ),
         #[cfg(feature = "qt_qml")]
+        (
+            include_str!("../include/qml/qqmlcomponent.h"),
+            "qqmlcomponent.h",
+        ),
+        #[cfg(feature = "qt_qml")]
+        (
+            include_str!("../include/qml/qqmlcontext.h"),
+            "qqmlcontext.h",
+        ),
+        #[cfg(feature = "qt_qml")]
         (include_str!("../include/qml/qqmlengine.h"), "qqmlengine.h"),
+        #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qqmlerror.h"), "qqmlerror.h"),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickimageprovider.h"),
+            "qquickimageprovider.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickitem.h"),
+            "qquickitem.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qquickwindow.h"),
+            "qquickwindow.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qsggeometry.h"),
+            "qsggeometry.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qsgmaterial.h"),
+            "qsgmaterial.h",
+        ),
+        #[cfg(feature = "qt_quick")]
+        (include_str!("../include/quick/qsgnode.h"), "qsgnode.h"),
+        #[cfg(feature = "qt_quick")]
+        (
+            include_str!("../include/quick/qsgtexture.h"),
+            "qsgtexture.h",
+        ),
+        #[cfg(feature = "qt_quick_test")]
+        (
+            include_str!("../include/quicktest/quicktestmain.h"),
+            "quicktestmain.h",
+        ),
         (include_str!("../include/common.h"), "common.h"),
     ] {
         opts = opts.header(file_contents, "cxx-qt-lib", file_name);
@@ -115,5 +196,17 @@ pub fn build_opts() -> cxx_qt_build::CxxQtBuildersOpts {
         opts = opts.define("CXX_QT_QML_FEATURE").qt_module("Qml");
     }
 
+    #[cfg(feature = "qt_quick")]
+    {
+        opts = opts.define("CXX_QT_QUICK_FEATURE").qt_module("Quick");
+    }
+
+    #[cfg(feature = "qt_quick_test")]
+    {
+        opts = opts
+            .define("CXX_QT_QUICK_TEST_FEATURE")
+            .qt_module("QuickTest");
+    }
+
     opts
 }
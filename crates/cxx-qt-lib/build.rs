@@ -8,17 +8,28 @@ use cxx_qt_build::CxxQtBuilder;
 fn main() {
     let feature_qt_gui_enabled = std::env::var("CARGO_FEATURE_QT_GUI").is_ok();
     let feature_qt_qml_enabled = std::env::var("CARGO_FEATURE_QT_QML").is_ok();
+    let feature_qt_quick_enabled = std::env::var("CARGO_FEATURE_QT_QUICK").is_ok();
+    let feature_qt_quick_test_enabled = std::env::var("CARGO_FEATURE_QT_QUICK_TEST").is_ok();
+    let qt_version_major = qt_build_utils::QtBuild::new(vec![])
+        .expect("Could not find Qt installation")
+        .version()
+        .major;
     let emscripten_targeted = match std::env::var("CARGO_CFG_TARGET_OS") {
         Ok(val) => val == "emscripten",
         Err(_) => false,
     };
 
     let mut rust_bridges = vec![
+        "core/executor",
         "core/qbytearray",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qfile",
+        "core/qfileinfo",
         "core/qhash/qhash_i32_qbytearray",
         "core/qhash/qhash_qstring_qvariant",
+        "core/qiodevice",
         "core/qline",
         "core/qlinef",
         "core/qlist/qlist_bool",
@@ -51,11 +62,13 @@ fn main() {
         "core/qmargins",
         "core/qmarginsf",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qresource",
         "core/qset/qset_bool",
         "core/qset/qset_f32",
         "core/qset/qset_f64",
@@ -73,12 +86,16 @@ fn main() {
         "core/qset/qset_u16",
         "core/qset/qset_u32",
         "core/qset/qset_u64",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
+        "core/qstandardpaths",
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
         "core/qurl",
         "core/qvariant/mod",
         "core/qvariant/qvariant_bool",
@@ -143,6 +160,8 @@ fn main() {
             "gui/qfont",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qkeyevent",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -156,7 +175,39 @@ fn main() {
     }
 
     if feature_qt_qml_enabled {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "core/qlist/qlist_qqmlerror",
+            "core/qvariant/qvariant_qjsvalue",
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqml",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+        ]);
+    }
+
+    if feature_qt_quick_enabled {
+        rust_bridges.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickwindow",
+            "quick/qsggeometry",
+            "quick/qsgmaterial",
+            "quick/qsgnode",
+            "quick/qsgtexture",
+        ]);
+    }
+
+    if feature_qt_quick_test_enabled {
+        rust_bridges.extend(["quicktest/quicktestmain"]);
+    }
+
+    // QJSManagedValue was introduced in Qt 6.1
+    if feature_qt_qml_enabled && qt_version_major >= 6 {
+        rust_bridges.push("qml/qjsmanagedvalue");
     }
 
     if !emscripten_targeted {
@@ -171,10 +222,15 @@ fn main() {
     }
 
     let mut cpp_files = vec![
+        "core/executor",
         "core/qbytearray",
         "core/qcoreapplication",
         "core/qdate",
+        "core/qdir",
+        "core/qfile",
+        "core/qfileinfo",
         "core/qhash/qhash",
+        "core/qiodevice",
         "core/qline",
         "core/qlinef",
         "core/qlist/qlist",
@@ -187,12 +243,17 @@ fn main() {
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qresource",
         "core/qset/qset",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
+        "core/qstandardpaths",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
         "core/qurl",
         "core/qvariant/qvariant",
         "core/qvector/qvector",
@@ -204,6 +265,7 @@ fn main() {
             "gui/qfont",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -217,7 +279,35 @@ fn main() {
     }
 
     if feature_qt_qml_enabled {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqml",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+        ]);
+    }
+
+    if feature_qt_quick_enabled {
+        cpp_files.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickwindow",
+            "quick/qsggeometry",
+            "quick/qsgmaterial",
+            "quick/qsgnode",
+        ]);
+    }
+
+    if feature_qt_quick_test_enabled {
+        cpp_files.extend(["quicktest/quicktestmain"]);
+    }
+
+    if feature_qt_qml_enabled && qt_version_major >= 6 {
+        cpp_files.push("qml/qjsmanagedvalue");
     }
 
     if !emscripten_targeted {
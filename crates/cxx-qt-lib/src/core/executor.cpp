@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/executor.h"
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QMetaObject>
+#include <QtCore/QThread>
+
+#include "cxx-qt-gen/executor.cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+executorIsApplicationThread()
+{
+  const auto* app = QCoreApplication::instance();
+  return app != nullptr && app->thread() == QThread::currentThread();
+}
+
+void
+executorSchedule(::rust::usize id)
+{
+  // This can be called from any thread, the poll is then queued onto the
+  // event loop of the application thread
+  auto* app = QCoreApplication::instance();
+  if (app == nullptr) {
+    // Without an application the task can never be polled again
+    return;
+  }
+
+  QMetaObject::invokeMethod(
+    app, [id]() { executorPoll(id); }, Qt::QueuedConnection);
+}
+
+}
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/executor.h");
+
+        #[doc(hidden)]
+        #[rust_name = "executor_is_application_thread"]
+        fn executorIsApplicationThread() -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "executor_schedule"]
+        fn executorSchedule(id: usize);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "executorPoll"]
+        fn executor_poll(id: usize);
+    }
+}
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Wake, Waker},
+};
+
+/// The part of a task which is shared with its [Waker]
+///
+/// This is Send and Sync so that wakers can be used from any thread,
+/// the future itself never leaves the application thread.
+struct TaskWaker {
+    id: usize,
+    scheduled: AtomicBool,
+}
+
+impl TaskWaker {
+    fn schedule(&self) {
+        // Only queue one poll per task at a time
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            ffi::executor_schedule(self.id);
+        }
+    }
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.schedule();
+    }
+}
+
+struct Task {
+    waker: Arc<TaskWaker>,
+    future: Pin<Box<dyn Future<Output = ()>>>,
+}
+
+thread_local! {
+    static NEXT_TASK_ID: Cell<usize> = const { Cell::new(0) };
+    static TASKS: RefCell<HashMap<usize, Task>> = RefCell::new(HashMap::new());
+}
+
+fn executor_poll(id: usize) {
+    // Take the task out of the map while polling so that the future can spawn further tasks
+    let mut task = if let Some(task) = TASKS.with(|tasks| tasks.borrow_mut().remove(&id)) {
+        task
+    } else {
+        // The task has already completed
+        return;
+    };
+
+    // Clear the flag before polling so that a wake during the poll queues another poll
+    task.waker.scheduled.store(false, Ordering::Release);
+
+    let waker = Waker::from(task.waker.clone());
+    let mut context = Context::from_waker(&waker);
+    if task.future.as_mut().poll(&mut context).is_pending() {
+        TASKS.with(|tasks| tasks.borrow_mut().insert(id, task));
+    }
+}
+
+/// Spawn a future onto the Qt event loop of the application thread
+///
+/// The future is polled from the event loop, and is woken by queueing an event to the application,
+/// so it can hold non-Send state and use QObjects from the application thread.
+pub(crate) fn spawn_local<F>(future: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    if !ffi::executor_is_application_thread() {
+        panic!("spawn_local must be called from the thread of the application object");
+    }
+
+    let id = NEXT_TASK_ID.with(|next| {
+        let id = next.get();
+        next.set(id.wrapping_add(1));
+        id
+    });
+    let waker = Arc::new(TaskWaker {
+        id,
+        scheduled: AtomicBool::new(false),
+    });
+    TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(
+            id,
+            Task {
+                waker: waker.clone(),
+                future: Box::pin(future),
+            },
+        )
+    });
+
+    // Queue the first poll rather than polling immediately,
+    // so that spawning behaves the same before and during exec()
+    waker.schedule();
+}
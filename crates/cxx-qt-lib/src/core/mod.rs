@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod executor;
+pub(crate) use executor::spawn_local;
+
 mod qbytearray;
 pub use qbytearray::QByteArray;
 
@@ -17,9 +20,21 @@ mod qdatetime;
 #[cfg(not(target_os = "emscripten"))]
 pub use qdatetime::QDateTime;
 
+mod qdir;
+pub use qdir::QDir;
+
+mod qfile;
+pub use qfile::QFile;
+
+mod qfileinfo;
+pub use qfileinfo::QFileInfo;
+
 mod qhash;
 pub use qhash::{QHash, QHashPair, QHashPair_QString_QVariant, QHashPair_i32_QByteArray};
 
+mod qiodevice;
+pub use qiodevice::{QIODevice, QIODeviceOpenModeFlag};
+
 mod qline;
 pub use qline::QLine;
 
@@ -44,6 +59,9 @@ pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard};
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobject;
+pub use qobject::QObject;
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
@@ -53,15 +71,24 @@ pub use qrect::QRect;
 mod qrectf;
 pub use qrectf::QRectF;
 
+mod qresource;
+pub use qresource::QResource;
+
 mod qset;
 pub use qset::{QSet, QSetElement};
 
+mod qsettings;
+pub use qsettings::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+
 mod qsize;
 pub use qsize::QSize;
 
 mod qsizef;
 pub use qsizef::QSizeF;
 
+mod qstandardpaths;
+pub use qstandardpaths::{QStandardPaths, QStandardPathsStandardLocation};
+
 mod qstring;
 pub use qstring::QString;
 
@@ -71,13 +98,19 @@ pub use qstringlist::QStringList;
 mod qt;
 pub use qt::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    LayoutDirection, MouseButton, PenCapStyle, PenJoinStyle, PenStyle, SizeMode,
+    SplitBehaviorFlags, TimeSpec, TimerType, TransformationMode,
 };
 
+mod qthread;
+pub use qthread::QThread;
+
 mod qtime;
 pub use qtime::QTime;
 
+mod qtimer;
+pub use qtimer::QTimer;
+
 #[cfg(not(target_os = "emscripten"))]
 mod qtimezone;
 #[cfg(not(target_os = "emscripten"))]
@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qdir.h"
+
+#include "../assertion_utils.h"
+
+// QDir has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v5.15.6-lts-lgpl#n230
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qdir.h?h=v6.2.4#n250
+assert_alignment_and_size(QDir, alignof(::std::size_t), sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QDir>::value);
+static_assert(!::std::is_trivially_copy_constructible<QDir>::value);
+
+static_assert(!::std::is_trivially_destructible<QDir>::value);
+
+static_assert(QTypeInfo<QDir>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qdirCleanPath(const QString& path)
+{
+  return QDir::cleanPath(path);
+}
+
+QString
+qdirCurrentPath()
+{
+  return QDir::currentPath();
+}
+
+QStringList
+qdirEntryList(const QDir& dir)
+{
+  return dir.entryList();
+}
+
+QString
+qdirFromNativeSeparators(const QString& pathName)
+{
+  return QDir::fromNativeSeparators(pathName);
+}
+
+QString
+qdirHomePath()
+{
+  return QDir::homePath();
+}
+
+QString
+qdirRootPath()
+{
+  return QDir::rootPath();
+}
+
+bool
+qdirSetCurrent(const QString& path)
+{
+  return QDir::setCurrent(path);
+}
+
+QString
+qdirTempPath()
+{
+  return QDir::tempPath();
+}
+
+QString
+qdirToNativeSeparators(const QString& pathName)
+{
+  return QDir::toNativeSeparators(pathName);
+}
+
+}
+}
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = super::QDir;
+
+        /// Returns the absolute path name of a file in the directory.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns the absolute path (a path that starts with "/" or with a drive specification), which may contain symbolic links, but never contains redundant ".", ".." or multiple separators.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QDir) -> QString;
+
+        /// Returns the canonical path, i.e. a path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the directory does not exist, returns an empty string.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QDir) -> QString;
+
+        /// Changes the QDir's directory to dir_name.
+        ///
+        /// Returns true if the new directory exists; otherwise returns false.
+        fn cd(self: &mut QDir, dir_name: &QString) -> bool;
+
+        /// Changes directory by moving one directory up from the QDir's current directory.
+        ///
+        /// Returns true if the new directory exists; otherwise returns false.
+        #[rust_name = "cd_up"]
+        fn cdUp(self: &mut QDir) -> bool;
+
+        /// Returns the name of the directory; this is not the same as the path.
+        #[rust_name = "dir_name"]
+        fn dirName(self: &QDir) -> QString;
+
+        /// Returns true if the directory exists; otherwise returns false.
+        fn exists(self: &QDir) -> bool;
+
+        /// Returns true if the file called name exists; otherwise returns false.
+        #[cxx_name = "exists"]
+        fn exists_entry(self: &QDir, name: &QString) -> bool;
+
+        /// Returns the path name of a file in the directory.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Returns true if the directory's path is absolute; otherwise returns false.
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QDir) -> bool;
+
+        /// Returns true if the directory is readable and we can open files by name; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QDir) -> bool;
+
+        /// Returns true if the directory path is relative; otherwise returns false.
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QDir) -> bool;
+
+        /// Returns true if the directory is the root directory; otherwise returns false.
+        #[rust_name = "is_root"]
+        fn isRoot(self: &QDir) -> bool;
+
+        /// Creates a sub-directory called dir_name.
+        ///
+        /// Returns true on success; otherwise returns false.
+        fn mkdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Creates the directory path dir_path, including all necessary parent directories.
+        ///
+        /// Returns true if successful; otherwise returns false.
+        fn mkpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Returns the path. This may contain symbolic links, but never contains redundant ".", ".." or multiple separators.
+        fn path(self: &QDir) -> QString;
+
+        /// Refreshes the directory information.
+        fn refresh(self: &QDir);
+
+        /// Returns the path to file_name relative to the directory.
+        #[rust_name = "relative_file_path"]
+        fn relativeFilePath(self: &QDir, file_name: &QString) -> QString;
+
+        /// Removes the file, file_name.
+        ///
+        /// Returns true if the file is removed successfully; otherwise returns false.
+        fn remove(self: &mut QDir, file_name: &QString) -> bool;
+
+        /// Removes the directory, including all its contents.
+        ///
+        /// Returns true if successful, otherwise false.
+        #[rust_name = "remove_recursively"]
+        fn removeRecursively(self: &mut QDir) -> bool;
+
+        /// Renames a file or directory from old_name to new_name, and returns true if successful; otherwise returns false.
+        fn rename(self: &mut QDir, old_name: &QString, new_name: &QString) -> bool;
+
+        /// Removes the directory specified by dir_name.
+        ///
+        /// The directory must be empty for rmdir() to succeed.
+        fn rmdir(self: &QDir, dir_name: &QString) -> bool;
+
+        /// Removes the directory path dir_path.
+        ///
+        /// The function will remove all parent directories in dir_path, provided that they are empty.
+        fn rmpath(self: &QDir, dir_path: &QString) -> bool;
+
+        /// Sets the path of the directory to path.
+        #[rust_name = "set_path"]
+        fn setPath(self: &mut QDir, path: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdir_clean_path"]
+        fn qdirCleanPath(path: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_current_path"]
+        fn qdirCurrentPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_entry_list"]
+        fn qdirEntryList(dir: &QDir) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qdir_from_native_separators"]
+        fn qdirFromNativeSeparators(path_name: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_home_path"]
+        fn qdirHomePath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_root_path"]
+        fn qdirRootPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_set_current"]
+        fn qdirSetCurrent(path: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdir_temp_path"]
+        fn qdirTempPath() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_native_separators"]
+        fn qdirToNativeSeparators(path_name: &QString) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_drop"]
+        fn drop(dir: &mut QDir);
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_default"]
+        fn construct() -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_init_from_qstring"]
+        fn construct(path: &QString) -> QDir;
+        #[doc(hidden)]
+        #[rust_name = "qdir_clone"]
+        fn construct(dir: &QDir) -> QDir;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_eq"]
+        fn operatorEq(a: &QDir, b: &QDir) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qdir_to_qstring"]
+        fn toQString(dir: &QDir) -> QString;
+    }
+}
+
+use crate::{QString, QStringList};
+
+/// The QDir class provides access to directory structures and their contents.
+///
+/// Paths starting with `:/` refer to the Qt resource system.
+#[repr(C)]
+pub struct QDir {
+    _space: MaybeUninit<usize>,
+}
+
+impl QDir {
+    /// Returns path with directory separators normalized (that is, platform-native separators converted to "/")
+    /// and redundant ones removed, and "."s and ".."s resolved (as far as possible).
+    pub fn clean_path(path: &QString) -> QString {
+        ffi::qdir_clean_path(path)
+    }
+
+    /// Returns the absolute path of the application's current directory.
+    pub fn current_path() -> QString {
+        ffi::qdir_current_path()
+    }
+
+    /// Returns a list of the names of all the files and directories in the directory,
+    /// ordered by name and including "." and "..".
+    pub fn entry_list(&self) -> QStringList {
+        ffi::qdir_entry_list(self)
+    }
+
+    /// Returns path_name using '/' as file separator.
+    pub fn from_native_separators(path_name: &QString) -> QString {
+        ffi::qdir_from_native_separators(path_name)
+    }
+
+    /// Returns the absolute path of the user's home directory.
+    pub fn home_path() -> QString {
+        ffi::qdir_home_path()
+    }
+
+    /// Returns the absolute path of the root directory.
+    pub fn root_path() -> QString {
+        ffi::qdir_root_path()
+    }
+
+    /// Sets the application's current working directory to path.
+    /// Returns true if the directory was successfully changed; otherwise returns false.
+    pub fn set_current(path: &QString) -> bool {
+        ffi::qdir_set_current(path)
+    }
+
+    /// Returns the absolute canonical path of the system's temporary directory.
+    pub fn temp_path() -> QString {
+        ffi::qdir_temp_path()
+    }
+
+    /// Returns path_name with the '/' separators converted to separators that are appropriate for the underlying operating system.
+    pub fn to_native_separators(path_name: &QString) -> QString {
+        ffi::qdir_to_native_separators(path_name)
+    }
+}
+
+impl Clone for QDir {
+    /// Constructs a copy of the given QDir.
+    fn clone(&self) -> Self {
+        ffi::qdir_clone(self)
+    }
+}
+
+impl Default for QDir {
+    /// Constructs a QDir pointing to the application's working directory.
+    fn default() -> Self {
+        ffi::qdir_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QDir {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qdir_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qdir_to_qstring(self))
+    }
+}
+
+impl Drop for QDir {
+    fn drop(&mut self) {
+        ffi::qdir_drop(self)
+    }
+}
+
+impl From<&QString> for QDir {
+    /// Constructs a QDir pointing to the given directory path.
+    fn from(path: &QString) -> Self {
+        ffi::qdir_init_from_qstring(path)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDir {
+    type Id = type_id!("QDir");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QIODevice&
+qfileAsQIODevice(const QFile& file)
+{
+  return static_cast<const QIODevice&>(file);
+}
+
+QIODevice&
+qfileAsQIODeviceMut(QFile& file)
+{
+  return static_cast<QIODevice&>(file);
+}
+
+bool
+qfileFlush(const QFile& file)
+{
+  // Write for &QFile needs to flush, see the note in qiodevice.cpp
+  return const_cast<QFile&>(file).flush();
+}
+
+::std::unique_ptr<QFile>
+qfileNew(const QString& name)
+{
+  return ::std::make_unique<QFile>(name);
+}
+
+}
+}
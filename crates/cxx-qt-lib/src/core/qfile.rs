@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qfile.h");
+        /// The QFile class provides an interface for reading from and writing to files.
+        ///
+        /// This can read files from the Qt resource system, such as `:/qml/main.qml`,
+        /// which are not available to [std::fs].
+        ///
+        /// `&QFile` implements [std::io::Read], [std::io::Write] and [std::io::Seek],
+        /// in the same way as `&std::fs::File`.
+        type QFile;
+
+        /// Copies the file named file_name() to new_name.
+        ///
+        /// Returns true if successful; otherwise returns false.
+        fn copy(self: Pin<&mut QFile>, new_name: &QString) -> bool;
+
+        /// Returns true if the file specified by file_name() exists; otherwise returns false.
+        fn exists(self: &QFile) -> bool;
+
+        /// Returns the name set by set_file_name() or to the QFile constructors.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFile) -> QString;
+
+        /// Removes the file specified by file_name(). Returns true if successful; otherwise returns false.
+        ///
+        /// The file is closed before it is removed.
+        fn remove(self: Pin<&mut QFile>) -> bool;
+
+        /// Renames the file currently specified by file_name() to new_name.
+        ///
+        /// Returns true if successful; otherwise returns false.
+        fn rename(self: Pin<&mut QFile>, new_name: &QString) -> bool;
+
+        /// Sets the name of the file.
+        ///
+        /// Do not call this function if the file has already been opened.
+        #[rust_name = "set_file_name"]
+        fn setFileName(self: Pin<&mut QFile>, name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfile_as_qiodevice"]
+        fn qfileAsQIODevice(file: &QFile) -> &QIODevice;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_as_qiodevice_mut"]
+        fn qfileAsQIODeviceMut(file: Pin<&mut QFile>) -> Pin<&mut QIODevice>;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_flush"]
+        fn qfileFlush(file: &QFile) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_new"]
+        fn qfileNew(name: &QString) -> UniquePtr<QFile>;
+    }
+
+    // QFile is a QObject so is not relocatable and needs to be used via references or pointers
+    impl UniquePtr<QFile> {}
+}
+
+use crate::{QIODevice, QIODeviceOpenModeFlag, QString};
+use core::pin::Pin;
+use std::io;
+
+pub use ffi::QFile;
+
+impl QFile {
+    /// Convert the existing [QFile] to a [QIODevice]
+    pub fn as_qiodevice(&self) -> &QIODevice {
+        ffi::qfile_as_qiodevice(self)
+    }
+
+    /// Convert the existing [QFile] to a mutable [QIODevice]
+    pub fn as_qiodevice_mut(self: Pin<&mut Self>) -> Pin<&mut QIODevice> {
+        ffi::qfile_as_qiodevice_mut(self)
+    }
+
+    /// Flushes any buffered data to the file. Returns true if successful; otherwise returns false.
+    pub fn flush(&self) -> bool {
+        ffi::qfile_flush(self)
+    }
+
+    /// Constructs a new file object to represent the file with the given name.
+    pub fn new(name: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qfile_new(name)
+    }
+
+    /// Opens the file using the given mode, returning true if successful; otherwise false.
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        self.as_qiodevice_mut().open(mode)
+    }
+}
+
+impl io::Read for &QFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_qiodevice().read(buf)
+    }
+}
+
+impl io::Write for &QFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_qiodevice().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if QFile::flush(self) {
+            Ok(())
+        } else {
+            Err(io::Error::other(
+                self.as_qiodevice().error_string().to_string(),
+            ))
+        }
+    }
+}
+
+impl io::Seek for &QFile {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        io::Seek::seek(&mut self.as_qiodevice(), pos)
+    }
+}
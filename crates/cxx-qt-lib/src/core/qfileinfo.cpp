@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qfileinfo.h"
+
+#include "../assertion_utils.h"
+
+// QFileInfo has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v5.15.6-lts-lgpl#n167
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qfileinfo.h?h=v6.2.4#n183
+assert_alignment_and_size(QFileInfo,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QFileInfo>::value);
+static_assert(!::std::is_trivially_copy_constructible<QFileInfo>::value);
+
+static_assert(!::std::is_trivially_destructible<QFileInfo>::value);
+
+static_assert(QTypeInfo<QFileInfo>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int64_t
+qfileinfoSize(const QFileInfo& info)
+{
+  return static_cast<::std::int64_t>(info.size());
+}
+
+}
+}
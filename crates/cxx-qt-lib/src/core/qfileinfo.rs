@@ -0,0 +1,216 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdir.h");
+        type QDir = crate::QDir;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qfileinfo.h");
+        type QFileInfo = super::QFileInfo;
+
+        /// Returns an absolute path including the file name.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns the file's path absolute path. This doesn't include the file name.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(self: &QFileInfo) -> QString;
+
+        /// Returns the base name of the file without the path.
+        ///
+        /// The base name consists of all characters in the file up to (but not including) the first '.' character.
+        #[rust_name = "base_name"]
+        fn baseName(self: &QFileInfo) -> QString;
+
+        /// Returns the canonical path including the file name, i.e. an absolute path without symbolic links or redundant "." or ".." elements.
+        ///
+        /// If the file does not exist, returns an empty string.
+        #[rust_name = "canonical_file_path"]
+        fn canonicalFilePath(self: &QFileInfo) -> QString;
+
+        /// Returns the file's path canonical path (excluding the file name), i.e. an absolute path without symbolic links or redundant "." or ".." elements.
+        #[rust_name = "canonical_path"]
+        fn canonicalPath(self: &QFileInfo) -> QString;
+
+        /// Returns the complete base name of the file without the path.
+        ///
+        /// The complete base name consists of all characters in the file up to (but not including) the last '.' character.
+        #[rust_name = "complete_base_name"]
+        fn completeBaseName(self: &QFileInfo) -> QString;
+
+        /// Returns the complete suffix (extension) of the file.
+        ///
+        /// The complete suffix consists of all characters in the file after (but not including) the first '.'.
+        #[rust_name = "complete_suffix"]
+        fn completeSuffix(self: &QFileInfo) -> QString;
+
+        /// Returns the path of the object's parent directory as a QDir object.
+        fn dir(self: &QFileInfo) -> QDir;
+
+        /// Returns true if the file exists; otherwise returns false.
+        fn exists(self: &QFileInfo) -> bool;
+
+        /// Returns the name of the file, excluding the path.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFileInfo) -> QString;
+
+        /// Returns the file name, including the path (which may be absolute or relative).
+        #[rust_name = "file_path"]
+        fn filePath(self: &QFileInfo) -> QString;
+
+        /// Returns true if the file path is absolute, otherwise returns false (i.e. the path is relative).
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a directory or to a symbolic link to a directory.
+        #[rust_name = "is_dir"]
+        fn isDir(self: &QFileInfo) -> bool;
+
+        /// Returns true if the file is executable; otherwise returns false.
+        #[rust_name = "is_executable"]
+        fn isExecutable(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a file or to a symbolic link to a file.
+        #[rust_name = "is_file"]
+        fn isFile(self: &QFileInfo) -> bool;
+
+        /// Returns true if this is a "hidden" file; otherwise returns false.
+        #[rust_name = "is_hidden"]
+        fn isHidden(self: &QFileInfo) -> bool;
+
+        /// Returns true if the user can read the file; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QFileInfo) -> bool;
+
+        /// Returns true if the file path is relative, otherwise returns false (i.e. the path is absolute).
+        #[rust_name = "is_relative"]
+        fn isRelative(self: &QFileInfo) -> bool;
+
+        /// Returns true if this object points to a symbolic link, shortcut, or alias; otherwise returns false.
+        #[rust_name = "is_sym_link"]
+        fn isSymLink(self: &QFileInfo) -> bool;
+
+        /// Returns true if the user can write to the file; otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QFileInfo) -> bool;
+
+        /// Returns the file's path. This doesn't include the file name.
+        fn path(self: &QFileInfo) -> QString;
+
+        /// Refreshes the information about the file, i.e. reads in information from the file system the next time a cached property is fetched.
+        fn refresh(self: &mut QFileInfo);
+
+        /// Sets the file that the QFileInfo provides information about to file.
+        #[rust_name = "set_file"]
+        fn setFile(self: &mut QFileInfo, file: &QString);
+
+        /// Returns the suffix (extension) of the file.
+        ///
+        /// The suffix consists of all characters in the file after (but not including) the last '.'.
+        fn suffix(self: &QFileInfo) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_size"]
+        fn qfileinfoSize(info: &QFileInfo) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_drop"]
+        fn drop(info: &mut QFileInfo);
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_default"]
+        fn construct() -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_init_from_qstring"]
+        fn construct(file: &QString) -> QFileInfo;
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_clone"]
+        fn construct(info: &QFileInfo) -> QFileInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_eq"]
+        fn operatorEq(a: &QFileInfo, b: &QFileInfo) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qfileinfo_to_qstring"]
+        fn toQString(info: &QFileInfo) -> QString;
+    }
+}
+
+/// The QFileInfo class provides an OS-independent API to retrieve information about file system entries.
+#[repr(C)]
+pub struct QFileInfo {
+    _space: MaybeUninit<usize>,
+}
+
+impl QFileInfo {
+    /// Returns the file size in bytes.
+    ///
+    /// If the file does not exist or cannot be fetched, 0 is returned.
+    pub fn size(&self) -> i64 {
+        ffi::qfileinfo_size(self)
+    }
+}
+
+impl Clone for QFileInfo {
+    /// Constructs a copy of the given QFileInfo.
+    fn clone(&self) -> Self {
+        ffi::qfileinfo_clone(self)
+    }
+}
+
+impl Default for QFileInfo {
+    /// Constructs an empty QFileInfo object.
+    fn default() -> Self {
+        ffi::qfileinfo_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QFileInfo {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfileinfo_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QFileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qfileinfo_to_qstring(self))
+    }
+}
+
+impl Drop for QFileInfo {
+    fn drop(&mut self) {
+        ffi::qfileinfo_drop(self)
+    }
+}
+
+impl From<&ffi::QString> for QFileInfo {
+    /// Constructs a new QFileInfo that gives information about the given file.
+    fn from(file: &ffi::QString) -> Self {
+        ffi::qfileinfo_init_from_qstring(file)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFileInfo {
+    type Id = type_id!("QFileInfo");
+    type Kind = cxx::kind::Trivial;
+}
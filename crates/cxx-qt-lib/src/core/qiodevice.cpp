@@ -0,0 +1,70 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qiodevice.h"
+
+// The Rust std::io traits are implemented for &QIODevice, in the same way as
+// for &std::fs::File, so the read, write and seek helpers take a const
+// reference. QIODevice is not Sync in Rust so this cannot race.
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int64_t
+qiodeviceBytesAvailable(const QIODevice& device)
+{
+  return static_cast<::std::int64_t>(device.bytesAvailable());
+}
+
+bool
+qiodeviceOpen(QIODevice& device, QIODeviceOpenModeFlag mode)
+{
+  return device.open(QIODevice::OpenMode(mode));
+}
+
+::std::int64_t
+qiodevicePos(const QIODevice& device)
+{
+  return static_cast<::std::int64_t>(device.pos());
+}
+
+::std::int64_t
+qiodeviceRead(const QIODevice& device, ::rust::Slice<::std::uint8_t> data)
+{
+  return static_cast<::std::int64_t>(
+    const_cast<QIODevice&>(device).read(reinterpret_cast<char*>(data.data()),
+                                        static_cast<qint64>(data.size())));
+}
+
+QByteArray
+qiodeviceReadAll(QIODevice& device)
+{
+  return device.readAll();
+}
+
+bool
+qiodeviceSeek(const QIODevice& device, ::std::int64_t pos)
+{
+  return const_cast<QIODevice&>(device).seek(static_cast<qint64>(pos));
+}
+
+::std::int64_t
+qiodeviceSize(const QIODevice& device)
+{
+  return static_cast<::std::int64_t>(device.size());
+}
+
+::std::int64_t
+qiodeviceWrite(const QIODevice& device,
+               ::rust::Slice<const ::std::uint8_t> data)
+{
+  return static_cast<::std::int64_t>(const_cast<QIODevice&>(device).write(
+    reinterpret_cast<const char*>(data.data()),
+    static_cast<qint64>(data.size())));
+}
+
+}
+}
@@ -0,0 +1,223 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum is used with open() to describe the mode in which a device is opened.
+    ///
+    /// Flags can be combined with `|`, for example `QIODeviceOpenModeFlag::ReadOnly | QIODeviceOpenModeFlag::Text`.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIODeviceOpenModeFlag {
+        /// The device is not open.
+        NotOpen = 0x0000,
+        /// The device is open for reading.
+        ReadOnly = 0x0001,
+        /// The device is open for writing.
+        WriteOnly = 0x0002,
+        /// The device is open for reading and writing.
+        ReadWrite = 0x0003,
+        /// The device is opened in append mode so that all data is written to the end of the file.
+        Append = 0x0004,
+        /// If possible, the device is truncated before it is opened.
+        Truncate = 0x0008,
+        /// When reading, the end-of-line terminators are translated to '\n'.
+        Text = 0x0010,
+        /// Any buffer in the device is bypassed.
+        Unbuffered = 0x0020,
+        /// Fail if the file to be opened already exists.
+        NewOnly = 0x0040,
+        /// Fail if the file to be opened does not exist.
+        ExistingOnly = 0x0080,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qiodevice.h");
+        type QIODeviceOpenModeFlag;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QIODevice class is the base interface class of all I/O devices in Qt.
+        ///
+        /// `&QIODevice` implements [std::io::Read], [std::io::Write] and [std::io::Seek],
+        /// in the same way as `&std::fs::File`.
+        type QIODevice;
+
+        /// Returns true if the current read and write position is at the end of the device.
+        #[rust_name = "at_end"]
+        fn atEnd(self: &QIODevice) -> bool;
+
+        /// First emits aboutToClose(), then closes the device and sets its OpenMode to NotOpen.
+        fn close(self: Pin<&mut QIODevice>);
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QIODevice) -> QString;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QIODevice) -> bool;
+
+        /// Returns true if data can be read from the device; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QIODevice) -> bool;
+
+        /// Returns true if this device is sequential; otherwise returns false.
+        #[rust_name = "is_sequential"]
+        fn isSequential(self: &QIODevice) -> bool;
+
+        /// Returns true if the Text flag is enabled; otherwise returns false.
+        #[rust_name = "is_text_mode_enabled"]
+        fn isTextModeEnabled(self: &QIODevice) -> bool;
+
+        /// Returns true if data can be written to the device; otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QIODevice) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_bytes_available"]
+        fn qiodeviceBytesAvailable(device: &QIODevice) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_open"]
+        fn qiodeviceOpen(device: Pin<&mut QIODevice>, mode: QIODeviceOpenModeFlag) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_pos"]
+        fn qiodevicePos(device: &QIODevice) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_read"]
+        fn qiodeviceRead(device: &QIODevice, data: &mut [u8]) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_read_all"]
+        fn qiodeviceReadAll(device: Pin<&mut QIODevice>) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_seek"]
+        fn qiodeviceSeek(device: &QIODevice, pos: i64) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_size"]
+        fn qiodeviceSize(device: &QIODevice) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_write"]
+        fn qiodeviceWrite(device: &QIODevice, data: &[u8]) -> i64;
+    }
+}
+
+use crate::QByteArray;
+use core::pin::Pin;
+use std::io;
+
+pub use ffi::{QIODevice, QIODeviceOpenModeFlag};
+
+impl core::ops::BitOr for QIODeviceOpenModeFlag {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            repr: self.repr | rhs.repr,
+        }
+    }
+}
+
+impl QIODevice {
+    /// For buffered devices, returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        ffi::qiodevice_bytes_available(self)
+    }
+
+    /// Opens the device and sets its OpenMode to mode. Returns true if successful; otherwise returns false.
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        ffi::qiodevice_open(self, mode)
+    }
+
+    /// For random-access devices, this function returns the position that data is written to or read from.
+    pub fn pos(&self) -> i64 {
+        ffi::qiodevice_pos(self)
+    }
+
+    /// Reads all remaining data from the device, and returns it as a byte array.
+    pub fn read_all(self: Pin<&mut Self>) -> QByteArray {
+        ffi::qiodevice_read_all(self)
+    }
+
+    /// For random-access devices, this function sets the current position to pos, returning true on success.
+    pub fn seek(&self, pos: i64) -> bool {
+        ffi::qiodevice_seek(self, pos)
+    }
+
+    /// For open random-access devices, this function returns the size of the device.
+    /// For open sequential devices, bytes_available() is returned.
+    pub fn size(&self) -> i64 {
+        ffi::qiodevice_size(self)
+    }
+
+    fn io_error(&self) -> io::Error {
+        io::Error::other(self.error_string().to_string())
+    }
+}
+
+impl io::Read for &QIODevice {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = ffi::qiodevice_read(self, buf);
+        if read < 0 {
+            Err(self.io_error())
+        } else {
+            Ok(read as usize)
+        }
+    }
+}
+
+impl io::Write for &QIODevice {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = ffi::qiodevice_write(self, buf);
+        if written < 0 {
+            Err(self.io_error())
+        } else {
+            Ok(written as usize)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // QIODevice has no generic flush, subclasses such as QFile provide their own
+        Ok(())
+    }
+}
+
+impl io::Seek for &QIODevice {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            io::SeekFrom::Start(offset) => i64::try_from(offset).ok(),
+            io::SeekFrom::End(offset) => self.size().checked_add(offset),
+            io::SeekFrom::Current(offset) => self.pos().checked_add(offset),
+        };
+        match target {
+            Some(target) if target >= 0 => {
+                if ffi::qiodevice_seek(self, target) {
+                    Ok(target as u64)
+                } else {
+                    Err(self.io_error())
+                }
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
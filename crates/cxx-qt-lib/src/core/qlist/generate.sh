@@ -220,6 +220,7 @@ generate_bridge_qt "QMarginsF" "qmarginsf"
 generate_bridge_qt "QPersistentModelIndex" "qpersistentmodelindex"
 generate_bridge_qt "QPoint" "qpoint"
 generate_bridge_qt "QPointF" "qpointf"
+generate_bridge_qt "QQmlError" "qqmlerror"
 generate_bridge_qt "QRect" "qrect"
 generate_bridge_qt "QRectF" "qrectf"
 generate_bridge_qt "QSize" "qsize"
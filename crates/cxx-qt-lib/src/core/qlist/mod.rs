@@ -7,6 +7,8 @@
 use crate::QColor;
 #[cfg(not(target_os = "emscripten"))]
 use crate::QDateTime;
+#[cfg(feature = "qt_qml")]
+use crate::QQmlError;
 use crate::{
     QByteArray, QDate, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF, QRect, QRectF,
     QSize, QSizeF, QString, QTime, QUrl, QVariant,
@@ -364,6 +366,8 @@ impl_qlist_element!(
 );
 impl_qlist_element!(QPoint, qlist_qpoint, "QList_QPoint");
 impl_qlist_element!(QPointF, qlist_qpointf, "QList_QPointF");
+#[cfg(feature = "qt_qml")]
+impl_qlist_element!(QQmlError, qlist_qqmlerror, "QList_QQmlError");
 impl_qlist_element!(QRect, qlist_qrect, "QList_QRect");
 impl_qlist_element!(QRectF, qlist_qrectf, "QList_QRectF");
 impl_qlist_element!(QSize, qlist_qsize, "QList_QSize");
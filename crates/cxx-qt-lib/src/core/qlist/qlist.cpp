@@ -48,6 +48,9 @@ CXX_QT_QLIST_ASSERTS(::QMarginsF, QMarginsF);
 CXX_QT_QLIST_ASSERTS(::QPersistentModelIndex, QPersistentModelIndex);
 CXX_QT_QLIST_ASSERTS(::QPoint, QPoint);
 CXX_QT_QLIST_ASSERTS(::QPointF, QPointF);
+#ifdef CXX_QT_QML_FEATURE
+CXX_QT_QLIST_ASSERTS(::QQmlError, QQmlError);
+#endif
 CXX_QT_QLIST_ASSERTS(::QRect, QRect);
 CXX_QT_QLIST_ASSERTS(::QRectF, QRectF);
 CXX_QT_QLIST_ASSERTS(::QSize, QSize);
@@ -95,6 +98,10 @@ static const int register_QList_QPoint =
   qRegisterMetaType<::QList_QPoint>("QList_QPoint");
 static const int register_QList_QPointF =
   qRegisterMetaType<::QList_QPointF>("QList_QPointF");
+#ifdef CXX_QT_QML_FEATURE
+static const int register_QList_QQmlError =
+  qRegisterMetaType<::QList_QQmlError>("QList_QQmlError");
+#endif
 static const int register_QList_QRect =
   qRegisterMetaType<::QList_QRect>("QList_QRect");
 static const int register_QList_QRectF =
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = crate::QQmlError;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QQmlError = crate::QList<QQmlError>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QQmlError);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QQmlError, _: &QQmlError) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QQmlError"]
+        fn construct(_: &QList_QQmlError) -> QList_QQmlError;
+        #[rust_name = "qlist_default_QQmlError"]
+        fn construct() -> QList_QQmlError;
+        #[rust_name = "qlist_drop_QQmlError"]
+        fn drop(_: &mut QList_QQmlError);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QQmlError"]
+        fn qlistReserve(_: &mut QList_QQmlError, size: isize);
+        #[rust_name = "append_QQmlError"]
+        fn qlistAppend(_: &mut QList_QQmlError, _: &QQmlError);
+        #[rust_name = "get_unchecked_QQmlError"]
+        unsafe fn qlistGetUnchecked(set: &QList_QQmlError, pos: isize) -> &QQmlError;
+        #[rust_name = "index_of_QQmlError"]
+        fn qlistIndexOf(_: &QList_QQmlError, _: &QQmlError) -> isize;
+        #[rust_name = "insert_QQmlError"]
+        fn qlistInsert(_: &mut QList_QQmlError, _: isize, _: &QQmlError);
+        #[rust_name = "remove_QQmlError"]
+        fn qlistRemove(_: &mut QList_QQmlError, _: isize);
+        #[rust_name = "len_QQmlError"]
+        fn qlistLen(_: &QList_QQmlError) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QQmlError, size: isize) {
+    ffi::reserve_QQmlError(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QQmlError, value: &ffi::QQmlError) {
+    ffi::append_QQmlError(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QQmlError) -> ffi::QList_QQmlError {
+    ffi::qlist_clone_QQmlError(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QQmlError {
+    ffi::qlist_default_QQmlError()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QQmlError) {
+    ffi::qlist_drop_QQmlError(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QQmlError, pos: isize) -> &ffi::QQmlError {
+    ffi::get_unchecked_QQmlError(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QQmlError, value: &ffi::QQmlError) -> isize {
+    ffi::index_of_QQmlError(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QQmlError, pos: isize, value: &ffi::QQmlError) {
+    ffi::insert_QQmlError(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QQmlError) -> isize {
+    ffi::len_QQmlError(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QQmlError, pos: isize) {
+    ffi::remove_QQmlError(s, pos);
+}
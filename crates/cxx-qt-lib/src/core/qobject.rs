@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
+
+        include!("cxx-qt-lib/qobject.h");
+        /// The QObject class is the base class of all Qt objects.
+        ///
+        /// This is an opaque type which is used to refer to any QObject, such as the
+        /// root objects of a QML engine or the object created by a QML component.
+        type QObject;
+
+        /// Returns the name of this object.
+        #[rust_name = "object_name"]
+        fn objectName(self: &QObject) -> QString;
+
+        /// Returns a pointer to the parent object.
+        fn parent(self: &QObject) -> *mut QObject;
+
+        /// Sets the object's name to name.
+        #[rust_name = "set_object_name"]
+        fn setObjectName(self: Pin<&mut QObject>, name: &QString);
+
+        /// Returns the thread in which the object lives.
+        fn thread(self: &QObject) -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "qobjectMoveToThread"]
+        fn qobject_move_to_thread(object: Pin<&mut QObject>, thread: &QThread) -> bool;
+    }
+}
+
+use crate::QThread;
+use core::pin::Pin;
+
+pub use ffi::QObject;
+
+impl QObject {
+    /// Changes the thread affinity for this object and its children,
+    /// so that its events and queued closures are processed by the event loop of the given thread.
+    ///
+    /// Returns false without moving the object if it does not live in the current thread or if it has a parent.
+    ///
+    /// After moving, the object should only be used from the new thread,
+    /// for example with a closure queued by [CxxQtThread](https://docs.rs/cxx-qt/latest/cxx_qt/struct.CxxQtThread.html).
+    pub fn move_to_thread(self: Pin<&mut Self>, thread: &QThread) -> bool {
+        ffi::qobject_move_to_thread(self, thread)
+    }
+}
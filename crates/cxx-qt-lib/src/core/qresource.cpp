@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qresource.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QResource>
+qresourceNew(const QString& file)
+{
+  return ::std::make_unique<QResource>(file);
+}
+
+bool
+qresourceRegisterResource(const QString& rccFileName, const QString& mapRoot)
+{
+  return QResource::registerResource(rccFileName, mapRoot);
+}
+
+::std::int64_t
+qresourceSize(const QResource& resource)
+{
+  return static_cast<::std::int64_t>(resource.size());
+}
+
+QByteArray
+qresourceUncompressedData(const QResource& resource)
+{
+  return resource.uncompressedData();
+}
+
+bool
+qresourceUnregisterResource(const QString& rccFileName,
+                            const QString& mapRoot)
+{
+  return QResource::unregisterResource(rccFileName, mapRoot);
+}
+
+}
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qresource.h");
+        /// The QResource class provides an interface for reading directly from resources.
+        type QResource;
+
+        /// Returns the real path that this QResource represents, if the resource was found via the QDir::searchPaths() it will be indicated in the path.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(self: &QResource) -> QString;
+
+        /// Returns the full path to the file that this QResource represents as passed to set_file_name().
+        #[rust_name = "file_name"]
+        fn fileName(self: &QResource) -> QString;
+
+        /// Returns true if the resource really exists in the resource hierarchy, false otherwise.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QResource) -> bool;
+
+        /// Sets a QResource to point to file.
+        #[rust_name = "set_file_name"]
+        fn setFileName(self: Pin<&mut QResource>, file: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qresource_new"]
+        fn qresourceNew(file: &QString) -> UniquePtr<QResource>;
+        #[doc(hidden)]
+        #[rust_name = "qresource_register_resource"]
+        fn qresourceRegisterResource(rcc_file_name: &QString, map_root: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qresource_size"]
+        fn qresourceSize(resource: &QResource) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qresource_uncompressed_data"]
+        fn qresourceUncompressedData(resource: &QResource) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qresource_unregister_resource"]
+        fn qresourceUnregisterResource(rcc_file_name: &QString, map_root: &QString) -> bool;
+    }
+
+    // QResource is not copyable so it needs to be used via references or pointers
+    impl UniquePtr<QResource> {}
+}
+
+use crate::{QByteArray, QString};
+
+pub use ffi::QResource;
+
+impl QResource {
+    /// Constructs a QResource pointing to file, such as `:/qml/main.qml`.
+    pub fn new(file: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qresource_new(file)
+    }
+
+    /// Registers the resource with the given rcc_file_name at the location in the resource tree specified by map_root,
+    /// and returns true if the file is successfully opened; otherwise returns false.
+    pub fn register_resource(rcc_file_name: &QString, map_root: &QString) -> bool {
+        ffi::qresource_register_resource(rcc_file_name, map_root)
+    }
+
+    /// Returns the size of the stored data backing the resource.
+    ///
+    /// If the resource is compressed, this is the compressed size.
+    pub fn size(&self) -> i64 {
+        ffi::qresource_size(self)
+    }
+
+    /// Returns the resource data, decompressing it first, if the data was stored compressed.
+    ///
+    /// If the resource is a directory or an error occurs while decompressing, an empty QByteArray is returned.
+    pub fn uncompressed_data(&self) -> QByteArray {
+        ffi::qresource_uncompressed_data(self)
+    }
+
+    /// Unregisters the resource with the given rcc_file_name at the location in the resource tree specified by map_root,
+    /// and returns true if the resource is successfully unloaded and no references exist for the resource; otherwise returns false.
+    pub fn unregister_resource(rcc_file_name: &QString, map_root: &QString) -> bool {
+        ffi::qresource_unregister_resource(rcc_file_name, map_root)
+    }
+}
@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsettings.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix)
+{
+  settings.beginGroup(prefix);
+}
+
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix)
+{
+  return static_cast<::std::int32_t>(settings.beginReadArray(prefix));
+}
+
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size)
+{
+  settings.beginWriteArray(prefix, static_cast<int>(size));
+}
+
+bool
+qsettingsContains(const QSettings& settings, const QString& key)
+{
+  return settings.contains(key);
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNew()
+{
+  return ::std::make_unique<QSettings>();
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFile(const QString& fileName, QSettingsFormat format)
+{
+  return ::std::make_unique<QSettings>(fileName, format);
+}
+
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application)
+{
+  return ::std::make_unique<QSettings>(format, scope, organization, application);
+}
+
+void
+qsettingsRemove(QSettings& settings, const QString& key)
+{
+  settings.remove(key);
+}
+
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value)
+{
+  settings.setValue(key, value);
+}
+
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key)
+{
+  // Qt 5 only has the overload with a default value
+  return settings.value(key);
+}
+
+}
+}
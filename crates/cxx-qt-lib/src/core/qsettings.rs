@@ -0,0 +1,267 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type specifies the storage format used by QSettings.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsFormat {
+        /// Store the settings using the most appropriate storage format for the platform.
+        NativeFormat = 0,
+        /// Store the settings in INI files.
+        IniFormat = 1,
+        /// Special value returned by registerFormat().
+        InvalidFormat = 16,
+    }
+
+    /// This enum specifies whether settings are user-specific or shared by all users of the same system.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsScope {
+        /// Store settings in a location specific to the current user.
+        UserScope,
+        /// Store settings in a global location, so that all users on the same machine access the same set of settings.
+        SystemScope,
+    }
+
+    /// The following status values are possible.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSettingsStatus {
+        /// No error occurred.
+        NoError,
+        /// An access error occurred (e.g. trying to write to a read-only file).
+        AccessError,
+        /// A format error occurred (e.g. loading a malformed INI file).
+        FormatError,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsettings.h");
+        type QSettingsFormat;
+        type QSettingsScope;
+        type QSettingsStatus;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QSettings class provides persistent platform-independent application settings.
+        type QSettings;
+
+        /// Returns a list of all keys, including subkeys, that can be read using the QSettings object.
+        #[rust_name = "all_keys"]
+        fn allKeys(self: &QSettings) -> QStringList;
+
+        /// Returns the application name used for storing the settings.
+        #[rust_name = "application_name"]
+        fn applicationName(self: &QSettings) -> QString;
+
+        /// Returns a list of all key top-level groups that contain keys that can be read using the QSettings object.
+        #[rust_name = "child_groups"]
+        fn childGroups(self: &QSettings) -> QStringList;
+
+        /// Returns a list of all top-level keys that can be read using the QSettings object.
+        #[rust_name = "child_keys"]
+        fn childKeys(self: &QSettings) -> QStringList;
+
+        /// Removes all entries in the primary location associated to this QSettings object.
+        fn clear(self: Pin<&mut QSettings>);
+
+        /// Closes the array that was started using begin_read_array() or begin_write_array().
+        #[rust_name = "end_array"]
+        fn endArray(self: Pin<&mut QSettings>);
+
+        /// Resets the group to what it was before the corresponding begin_group() call.
+        #[rust_name = "end_group"]
+        fn endGroup(self: Pin<&mut QSettings>);
+
+        /// Returns true if fallbacks are enabled; returns false otherwise.
+        #[rust_name = "fallbacks_enabled"]
+        fn fallbacksEnabled(self: &QSettings) -> bool;
+
+        /// Returns the path where settings written using this QSettings object are stored.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QSettings) -> QString;
+
+        /// Returns the format used for storing the settings.
+        fn format(self: &QSettings) -> QSettingsFormat;
+
+        /// Returns the current group.
+        fn group(self: &QSettings) -> QString;
+
+        /// Returns true if settings can be written using this QSettings object; returns false otherwise.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QSettings) -> bool;
+
+        /// Returns the organization name used for storing the settings.
+        #[rust_name = "organization_name"]
+        fn organizationName(self: &QSettings) -> QString;
+
+        /// Returns the scope used for storing the settings.
+        fn scope(self: &QSettings) -> QSettingsScope;
+
+        /// Sets the current array index to i.
+        #[rust_name = "set_array_index"]
+        fn setArrayIndex(self: Pin<&mut QSettings>, i: i32);
+
+        /// Sets whether fallbacks are enabled to b.
+        #[rust_name = "set_fallbacks_enabled"]
+        fn setFallbacksEnabled(self: Pin<&mut QSettings>, b: bool);
+
+        /// Returns a status code indicating the first error that was met by QSettings, or NoError if no error occurred.
+        fn status(self: &QSettings) -> QSettingsStatus;
+
+        /// Writes any unsaved changes to permanent storage, and reloads any settings that have been changed in the meantime by another application.
+        fn sync(self: Pin<&mut QSettings>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_group"]
+        fn qsettingsBeginGroup(settings: Pin<&mut QSettings>, prefix: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_read_array"]
+        fn qsettingsBeginReadArray(settings: Pin<&mut QSettings>, prefix: &QString) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_write_array"]
+        fn qsettingsBeginWriteArray(settings: Pin<&mut QSettings>, prefix: &QString, size: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_contains"]
+        fn qsettingsContains(settings: &QSettings, key: &QString) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new"]
+        fn qsettingsNew() -> UniquePtr<QSettings>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_file"]
+        fn qsettingsNewWithFile(
+            file_name: &QString,
+            format: QSettingsFormat,
+        ) -> UniquePtr<QSettings>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_format"]
+        fn qsettingsNewWithFormat(
+            format: QSettingsFormat,
+            scope: QSettingsScope,
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_remove"]
+        fn qsettingsRemove(settings: Pin<&mut QSettings>, key: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_value"]
+        fn qsettingsSetValue(settings: Pin<&mut QSettings>, key: &QString, value: &QVariant);
+
+        #[doc(hidden)]
+        #[rust_name = "qsettings_value"]
+        fn qsettingsValue(settings: &QSettings, key: &QString) -> QVariant;
+    }
+
+    // QSettings is a QObject so is not relocatable and needs to be used via references or pointers
+    impl UniquePtr<QSettings> {}
+}
+
+use crate::{QString, QVariant, QVariantValue};
+use core::pin::Pin;
+
+pub use ffi::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+
+impl QSettings {
+    /// Appends prefix to the current group.
+    ///
+    /// The current group is automatically prepended to all keys specified to QSettings.
+    pub fn begin_group(self: Pin<&mut Self>, prefix: &QString) {
+        ffi::qsettings_begin_group(self, prefix);
+    }
+
+    /// Adds prefix to the current group and starts reading from an array. Returns the size of the array.
+    pub fn begin_read_array(self: Pin<&mut Self>, prefix: &QString) -> i32 {
+        ffi::qsettings_begin_read_array(self, prefix)
+    }
+
+    /// Adds prefix to the current group and starts writing an array of size size.
+    ///
+    /// If size is -1, it is automatically determined based on the indexes of the entries written.
+    pub fn begin_write_array(self: Pin<&mut Self>, prefix: &QString, size: i32) {
+        ffi::qsettings_begin_write_array(self, prefix, size);
+    }
+
+    /// Returns true if there exists a setting called key; returns false otherwise.
+    pub fn contains(&self, key: &QString) -> bool {
+        ffi::qsettings_contains(self, key)
+    }
+
+    /// Constructs a QSettings object for accessing the settings of the application and organization
+    /// set previously with [QCoreApplication](crate::QCoreApplication) or [QGuiApplication](crate::QGuiApplication).
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new()
+    }
+
+    /// Constructs a QSettings object for accessing the settings stored in the file called file_name.
+    ///
+    /// If format is [QSettingsFormat::NativeFormat] on Windows, file_name is a path in the system registry.
+    pub fn new_with_file(file_name: &QString, format: QSettingsFormat) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_file(file_name, format)
+    }
+
+    /// Constructs a QSettings object for accessing settings of the application called application
+    /// from the organization called organization, with the given format and scope.
+    pub fn new_with_format(
+        format: QSettingsFormat,
+        scope: QSettingsScope,
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_format(format, scope, organization, application)
+    }
+
+    /// Removes the setting key and any sub-settings of key.
+    pub fn remove(self: Pin<&mut Self>, key: &QString) {
+        ffi::qsettings_remove(self, key);
+    }
+
+    /// Sets the value of setting key to value. If the key already exists, the previous value is overwritten.
+    pub fn set_value<T: QVariantValue>(self: Pin<&mut Self>, key: &QString, value: &T) {
+        self.set_value_variant(key, &QVariant::from(value));
+    }
+
+    /// Sets the value of setting key to the given [QVariant].
+    pub fn set_value_variant(self: Pin<&mut Self>, key: &QString, value: &QVariant) {
+        ffi::qsettings_set_value(self, key, value);
+    }
+
+    /// Returns the value for setting key converted to the type T.
+    ///
+    /// If the setting doesn't exist or cannot be converted to T, returns None.
+    pub fn value<T: QVariantValue>(&self, key: &QString) -> Option<T> {
+        self.value_variant(key).value()
+    }
+
+    /// Returns the value for setting key.
+    ///
+    /// If the setting doesn't exist, returns an invalid [QVariant].
+    pub fn value_variant(&self, key: &QString) -> QVariant {
+        ffi::qsettings_value(self, key)
+    }
+}
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qstandardpaths.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qstandardpathsDisplayName(QStandardPathsStandardLocation location)
+{
+  return QStandardPaths::displayName(location);
+}
+
+QString
+qstandardpathsFindExecutable(const QString& executableName)
+{
+  return QStandardPaths::findExecutable(executableName);
+}
+
+QString
+qstandardpathsLocate(QStandardPathsStandardLocation location,
+                     const QString& fileName)
+{
+  return QStandardPaths::locate(location, fileName);
+}
+
+QStringList
+qstandardpathsLocateAll(QStandardPathsStandardLocation location,
+                        const QString& fileName)
+{
+  return QStandardPaths::locateAll(location, fileName);
+}
+
+QStringList
+qstandardpathsStandardLocations(QStandardPathsStandardLocation location)
+{
+  return QStandardPaths::standardLocations(location);
+}
+
+QString
+qstandardpathsWritableLocation(QStandardPathsStandardLocation location)
+{
+  return QStandardPaths::writableLocation(location);
+}
+
+}
+}
@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the different locations that can be queried using methods such as [QStandardPaths::writable_location].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QStandardPathsStandardLocation {
+        /// Returns the user's desktop directory.
+        DesktopLocation,
+        /// Returns the directory containing user document files.
+        DocumentsLocation,
+        /// Returns the directory containing user's fonts.
+        FontsLocation,
+        /// Returns the directory containing the user applications.
+        ApplicationsLocation,
+        /// Returns the directory containing the user's music or other audio files.
+        MusicLocation,
+        /// Returns the directory containing the user's movies and videos.
+        MoviesLocation,
+        /// Returns the directory containing the user's pictures or photos.
+        PicturesLocation,
+        /// Returns a directory where temporary files can be stored.
+        TempLocation,
+        /// Returns the user's home directory.
+        HomeLocation,
+        /// Returns the local settings path on the Windows operating system, on all other platforms it returns the same value as AppDataLocation.
+        AppLocalDataLocation,
+        /// Returns a directory location where user-specific non-essential (cached) data should be written.
+        CacheLocation,
+        /// Returns a directory location where persistent data shared across applications can be stored.
+        GenericDataLocation,
+        /// Returns a directory location where runtime communication files should be written, like Unix local sockets.
+        RuntimeLocation,
+        /// Returns a directory location where user-specific configuration files should be written.
+        ConfigLocation,
+        /// Returns a directory for user's downloaded files.
+        DownloadLocation,
+        /// Returns a directory location where user-specific non-essential (cached) data, shared across applications, should be written.
+        GenericCacheLocation,
+        /// Returns a directory location where user-specific configuration files shared between multiple applications should be written.
+        GenericConfigLocation,
+        /// Returns a directory location where persistent application data can be stored.
+        AppDataLocation,
+        /// Returns a directory location where user-specific configuration files should be written.
+        AppConfigLocation,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstandardpaths.h");
+        type QStandardPathsStandardLocation;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_display_name"]
+        fn qstandardpathsDisplayName(location: QStandardPathsStandardLocation) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_find_executable"]
+        fn qstandardpathsFindExecutable(executable_name: &QString) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate"]
+        fn qstandardpathsLocate(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_locate_all"]
+        fn qstandardpathsLocateAll(
+            location: QStandardPathsStandardLocation,
+            file_name: &QString,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_standard_locations"]
+        fn qstandardpathsStandardLocations(location: QStandardPathsStandardLocation)
+            -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstandardpaths_writable_location"]
+        fn qstandardpathsWritableLocation(location: QStandardPathsStandardLocation) -> QString;
+    }
+}
+
+use crate::{QString, QStringList};
+
+pub use ffi::QStandardPathsStandardLocation;
+
+/// The QStandardPaths class provides methods for accessing standard paths.
+///
+/// This is a namespace of associated functions and cannot be constructed.
+pub struct QStandardPaths {
+    _private: (),
+}
+
+impl QStandardPaths {
+    /// Returns a localized display name for the given location type
+    /// or an empty QString if no relevant location can be found.
+    pub fn display_name(location: QStandardPathsStandardLocation) -> QString {
+        ffi::qstandardpaths_display_name(location)
+    }
+
+    /// Finds the executable named executable_name in the system paths.
+    ///
+    /// Returns the absolute file path to the executable, or an empty string if not found.
+    pub fn find_executable(executable_name: &QString) -> QString {
+        ffi::qstandardpaths_find_executable(executable_name)
+    }
+
+    /// Tries to find a file or directory called file_name in the standard locations for location.
+    ///
+    /// Returns the full path to the first file or directory found, otherwise returns None.
+    pub fn locate(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+    ) -> Option<QString> {
+        let path = ffi::qstandardpaths_locate(location, file_name);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Tries to find all files or directories called file_name in the standard locations for location.
+    pub fn locate_all(
+        location: QStandardPathsStandardLocation,
+        file_name: &QString,
+    ) -> QStringList {
+        ffi::qstandardpaths_locate_all(location, file_name)
+    }
+
+    /// Returns all the directories where files of type belong.
+    ///
+    /// The first element is the path where files should be written to, if it can be written to.
+    pub fn standard_locations(location: QStandardPathsStandardLocation) -> QStringList {
+        ffi::qstandardpaths_standard_locations(location)
+    }
+
+    /// Returns the directory where files of type should be written to, or None if the location cannot be determined.
+    ///
+    /// Note that the directory may not exist yet.
+    pub fn writable_location(location: QStandardPathsStandardLocation) -> Option<QString> {
+        let path = ffi::qstandardpaths_writable_location(location);
+        if path.is_empty() {
+            None
+        } else {
+            Some(path)
+        }
+    }
+}
@@ -145,6 +145,36 @@ mod ffi {
         RelativeSize,
     }
 
+    /// This enum type describes the different mouse buttons.
+    #[repr(i32)]
+    enum MouseButton {
+        /// The button state does not refer to any button.
+        NoButton = 0x00000000,
+        /// The left button is pressed, or an event refers to the left button.
+        LeftButton = 0x00000001,
+        /// The right button.
+        RightButton = 0x00000002,
+        /// The middle button.
+        MiddleButton = 0x00000004,
+        /// The 'Back' button.
+        BackButton = 0x00000008,
+        /// The 'Forward' button.
+        ForwardButton = 0x00000010,
+        /// This value corresponds to a mask of all possible mouse buttons.
+        AllButtons = 0x07ffffff,
+    }
+
+    /// The timer type indicates how accurate a timer can be.
+    #[repr(i32)]
+    enum TimerType {
+        /// Precise timers try to keep millisecond accuracy.
+        PreciseTimer,
+        /// Coarse timers try to keep accuracy within 5% of the desired interval.
+        CoarseTimer,
+        /// Very coarse timers only keep full second accuracy.
+        VeryCoarseTimer,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
@@ -161,13 +191,15 @@ mod ffi {
         type BGMode;
         type ClipOperation;
         type SizeMode;
+        type MouseButton;
+        type TimerType;
     }
 }
 
 pub use ffi::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, LayoutDirection,
-    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    MouseButton, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
+    TimerType, TransformationMode,
 };
 
 // Reexport ConnectionType from cxx-qt
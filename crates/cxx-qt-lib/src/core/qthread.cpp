@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qthread.h"
+
+#include <algorithm>
+#include <limits>
+
+#include <QtCore/QDeadlineTimer>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject&
+qthreadAsQObject(QThread& thread)
+{
+  return static_cast<QObject&>(thread);
+}
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+void
+qthreadStart(QThread& thread)
+{
+  thread.start();
+}
+
+bool
+qthreadWait(QThread& thread)
+{
+  return thread.wait();
+}
+
+bool
+qthreadWaitFor(QThread& thread, ::std::uint64_t msecs)
+{
+  // Clamp to the largest deadline, as a negative value would wait forever
+  const auto clamped = ::std::min<::std::uint64_t>(
+    msecs, ::std::numeric_limits<qint64>::max());
+  return thread.wait(QDeadlineTimer(static_cast<qint64>(clamped)));
+}
+
+}
+}
@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(cxx_file_stem = "qthread")]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qthread.h");
+        /// The QThread class provides a platform-independent way to manage threads.
+        ///
+        /// When started the thread runs its own event loop, so QObjects that are moved to the thread
+        /// with [QObject::move_to_thread](crate::QObject::move_to_thread) have their slots and queued closures
+        /// called on that thread.
+        ///
+        /// The thread must not be running when the QThread is dropped, call [QThread::quit] and then
+        /// [QThread::wait] before dropping it.
+        ///
+        /// A QObject defined in a CXX-Qt bridge can be moved to a thread by declaring the
+        /// `qobjectMoveToThread` helper from `cxx-qt-lib/qobject.h` for it in the bridge.
+        ///
+        /// ```ignore
+        /// unsafe extern "C++" {
+        ///     include!("cxx-qt-lib/qthread.h");
+        ///     type QThread = cxx_qt_lib::QThread;
+        /// }
+        ///
+        /// #[namespace = "rust::cxxqtlib1"]
+        /// unsafe extern "C++" {
+        ///     include!("cxx-qt-lib/qobject.h");
+        ///     #[cxx_name = "qobjectMoveToThread"]
+        ///     fn move_to_thread(object: Pin<&mut MyObject>, thread: &QThread) -> bool;
+        /// }
+        /// ```
+        ///
+        /// A [CxxQtThread](https://docs.rs/cxx-qt/latest/cxx_qt/struct.CxxQtThread.html) of the object
+        /// then queues closures onto the event loop of the new thread.
+        #[qobject]
+        type QThread;
+
+        /// This signal is emitted from the associated thread when it finishes executing.
+        ///
+        /// This signal can only be emitted by C++, connect to it using `on_finished`.
+        #[doc(hidden)]
+        #[qsignal]
+        fn finished(self: Pin<&mut QThread>);
+
+        /// This signal is emitted from the associated thread when it starts executing, before the event loop is entered.
+        ///
+        /// This signal can only be emitted by C++, connect to it using `on_started`.
+        #[doc(hidden)]
+        #[qsignal]
+        fn started(self: Pin<&mut QThread>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+
+        /// Tells the thread's event loop to exit with a return code.
+        ///
+        /// By convention, a return code of 0 means success, any non-zero value indicates an error.
+        fn exit(self: Pin<&mut QThread>, return_code: i32);
+
+        /// Returns true if the thread is finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Returns true if the task running on this thread should be stopped.
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Returns the current event loop level for the thread.
+        #[rust_name = "loop_level"]
+        fn loopLevel(self: &QThread) -> i32;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread.
+        ///
+        /// This request is advisory and it is up to code running on the thread to decide if and how it should act upon such request.
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_as_qobject"]
+        fn qthreadAsQObject(thread: Pin<&mut QThread>) -> Pin<&mut QObject>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_start"]
+        fn qthreadStart(thread: Pin<&mut QThread>);
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait_for"]
+        fn qthreadWaitFor(thread: Pin<&mut QThread>, msecs: u64) -> bool;
+    }
+
+    // QThread is a QObject so is not relocatable and needs to be used via references or pointers
+    impl UniquePtr<QThread> {}
+}
+
+use crate::QObject;
+use core::pin::Pin;
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Convert the existing [QThread] to a [QObject]
+    pub fn as_qobject(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qthread_as_qobject(self)
+    }
+
+    /// Create a new QThread without a parent
+    ///
+    /// The QThread object itself lives in the thread that created it, not in the thread that it manages.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Begins execution of the thread, which enters its own event loop.
+    ///
+    /// If the thread is already running, this function does nothing.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qthread_start(self);
+    }
+
+    /// Blocks until the thread has finished execution.
+    ///
+    /// Returns true if the thread has finished or has not been started yet.
+    pub fn wait(self: Pin<&mut Self>) -> bool {
+        ffi::qthread_wait(self)
+    }
+
+    /// Blocks until the thread has finished execution or msecs milliseconds have passed.
+    ///
+    /// Returns false if the wait timed out.
+    pub fn wait_for(self: Pin<&mut Self>, msecs: u64) -> bool {
+        ffi::qthread_wait_for(self, msecs)
+    }
+}
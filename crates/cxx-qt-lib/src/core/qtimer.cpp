@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtimer.h"
+
+#include <QtCore/QThread>
+
+#include "cxx-qt-gen/qtimer.cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTimer>
+qtimerNew()
+{
+  return ::std::make_unique<QTimer>();
+}
+
+QObject&
+qtimerAsQObject(QTimer& timer)
+{
+  return static_cast<QObject&>(timer);
+}
+
+bool
+qtimerSingleShot(::std::int32_t msec,
+                 const QObject& context,
+                 ::rust::Box<QTimerSingleShotClosure> closure)
+{
+  // The closure is not Send so it must be called from the current thread
+  if (context.thread() != QThread::currentThread()) {
+    return false;
+  }
+
+  // The closure is owned by the slot object, which Qt destroys when the
+  // context object is destroyed before the timeout occurs
+  auto shared =
+    ::std::make_shared<::rust::Box<QTimerSingleShotClosure>>(::std::move(closure));
+  QTimer::singleShot(static_cast<int>(msec), &context, [shared]() {
+    qtimerSingleShotClosureCall(**shared);
+  });
+  return true;
+}
+
+}
+}
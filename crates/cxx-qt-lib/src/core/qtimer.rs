@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(cxx_file_stem = "qtimer")]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtimer.h");
+        /// The QTimer class provides repetitive and single-shot timers.
+        #[qobject]
+        type QTimer;
+
+        /// This signal is emitted when the timer times out.
+        ///
+        /// This signal can only be emitted by C++, connect to it using `on_timeout`.
+        #[doc(hidden)]
+        #[qsignal]
+        fn timeout(self: Pin<&mut QTimer>);
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = crate::TimerType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+
+        /// Returns the timeout interval in milliseconds.
+        fn interval(self: &QTimer) -> i32;
+
+        /// Returns true if the timer is running (pending); otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QTimer) -> bool;
+
+        /// Returns whether the timer is a single-shot timer.
+        #[rust_name = "is_single_shot"]
+        fn isSingleShot(self: &QTimer) -> bool;
+
+        /// Returns the timer's remaining value in milliseconds left until the timeout.
+        ///
+        /// If the timer is inactive, the returned value will be -1.
+        /// If the timer is overdue, the returned value will be 0.
+        #[rust_name = "remaining_time"]
+        fn remainingTime(self: &QTimer) -> i32;
+
+        /// Sets the timeout interval in milliseconds.
+        ///
+        /// The default value for this property is 0. A timer with a timeout interval of 0
+        /// will time out as soon as all the events in the window system's event queue have been processed.
+        #[rust_name = "set_interval"]
+        fn setInterval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Sets whether the timer is a single-shot timer.
+        ///
+        /// A single-shot timer fires only once, non-single-shot timers fire every interval milliseconds.
+        #[rust_name = "set_single_shot"]
+        fn setSingleShot(self: Pin<&mut QTimer>, single_shot: bool);
+
+        /// Sets the accuracy of the timer.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: Pin<&mut QTimer>, timer_type: TimerType);
+
+        /// Starts or restarts the timer with the timeout specified in the interval.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        fn start(self: Pin<&mut QTimer>);
+
+        /// Starts or restarts the timer with a timeout interval of msec milliseconds.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        #[cxx_name = "start"]
+        fn start_with_interval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Stops the timer.
+        fn stop(self: Pin<&mut QTimer>);
+
+        /// Returns the accuracy of the timer.
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QTimer) -> TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_new"]
+        fn qtimerNew() -> UniquePtr<QTimer>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtimer_as_qobject"]
+        fn qtimerAsQObject(timer: Pin<&mut QTimer>) -> Pin<&mut QObject>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtimer_single_shot"]
+        fn qtimerSingleShot(
+            msec: i32,
+            context: &QObject,
+            closure: Box<QTimerSingleShotClosure>,
+        ) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QTimerSingleShotClosure;
+
+        #[doc(hidden)]
+        #[cxx_name = "qtimerSingleShotClosureCall"]
+        fn qtimer_single_shot_closure_call(closure: &mut QTimerSingleShotClosure);
+    }
+
+    // QTimer is a QObject so is not relocatable and needs to be used via references or pointers
+    impl UniquePtr<QTimer> {}
+}
+
+use crate::QObject;
+use core::pin::Pin;
+
+pub use ffi::QTimer;
+
+#[doc(hidden)]
+pub struct QTimerSingleShotClosure {
+    closure: Option<Box<dyn FnOnce()>>,
+}
+
+fn qtimer_single_shot_closure_call(closure: &mut QTimerSingleShotClosure) {
+    if let Some(closure) = closure.closure.take() {
+        closure();
+    }
+}
+
+impl QTimer {
+    /// Convert the existing [QTimer] to a [QObject]
+    pub fn as_qobject(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qtimer_as_qobject(self)
+    }
+
+    /// Create a new QTimer without a parent
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtimer_new()
+    }
+
+    /// Call the closure after a given time interval of msec milliseconds.
+    ///
+    /// The closure is executed in the event loop of the thread of the context object,
+    /// if the context object is destroyed before the interval occurs the closure
+    /// is dropped without being called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context object does not live in the current thread,
+    /// as the closure is not required to be [Send].
+    pub fn single_shot<F>(msec: i32, context: &QObject, closure: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let closure = Box::new(QTimerSingleShotClosure {
+            closure: Some(Box::new(closure)),
+        });
+        if !ffi::qtimer_single_shot(msec, context, closure) {
+            panic!("QTimer::single_shot context object must live in the current thread");
+        }
+    }
+}
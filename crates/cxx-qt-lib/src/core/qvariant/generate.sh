@@ -104,6 +104,7 @@ generate_bridge_qt "QByteArray" "qbytearray"
 generate_bridge_qt "QColor" "qcolor"
 generate_bridge_qt "QDate" "qdate"
 generate_bridge_qt "QDateTime" "qdatetime"
+generate_bridge_qt "QJSValue" "qjsvalue"
 generate_bridge_qt "QModelIndex" "qmodelindex"
 generate_bridge_qt "QPersistentModelIndex" "qpersistentmodelindex"
 generate_bridge_qt "QPoint" "qpoint"
@@ -88,6 +88,9 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QColor, QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDate, QDate)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDateTime, QDateTime)
+#ifdef CXX_QT_QML_FEATURE
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QJSValue, QJSValue)
+#endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QModelIndex, QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPersistentModelIndex, QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPoint, QPoint)
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QJSValue"]
+        fn qvariantCanConvertQJSValue(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QJSValue"]
+        fn qvariantConstruct(value: &QJSValue) -> QVariant;
+        #[rust_name = "value_or_default_QJSValue"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QJSValue;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QJSValue(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QJSValue) -> ffi::QVariant {
+    ffi::construct_QJSValue(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QJSValue {
+    ffi::value_or_default_QJSValue(variant)
+}
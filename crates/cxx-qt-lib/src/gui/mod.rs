@@ -18,6 +18,12 @@ pub use qvector3d::QVector3D;
 mod qvector4d;
 pub use qvector4d::QVector4D;
 
+mod qkeyevent;
+pub use qkeyevent::QKeyEvent;
+
+mod qmouseevent;
+pub use qmouseevent::QMouseEvent;
+
 mod qimage;
 pub use qimage::{QImage, QImageFormat, QImageInvertMode};
 
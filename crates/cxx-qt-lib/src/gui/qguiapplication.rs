@@ -5,7 +5,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{QByteArray, QFont, QString, QStringList, QVector};
-use core::pin::Pin;
+use core::{future::Future, pin::Pin};
 
 #[cxx::bridge]
 mod ffi {
@@ -198,4 +198,23 @@ impl QGuiApplication {
     pub fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qguiapplication_set_organization_name(self, name);
     }
+
+    /// Spawn a future onto the event loop of this application.
+    ///
+    /// The future is polled from the Qt event loop on the application thread, so it can hold
+    /// non-[Send] state and await I/O directly without queueing results back to the GUI thread.
+    /// It is woken from any thread by queueing an event to the application, so futures from
+    /// other runtimes work as long as their reactor is driven elsewhere (eg a background tokio runtime).
+    ///
+    /// The future is first polled once the event loop is running, eg from [Self::exec].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a thread other than the one the application lives in.
+    pub fn spawn_local<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        crate::spawn_local(future);
+    }
 }
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qkeyevent.h");
+        type QKeyEvent;
+
+        /// Sets the accept flag of the event object, indicating that the receiver wants the event.
+        fn accept(self: Pin<&mut QKeyEvent>);
+
+        /// Returns the number of keys involved in this event.
+        ///
+        /// If text() is not empty, this is simply the length of the string.
+        fn count(self: &QKeyEvent) -> i32;
+
+        /// Clears the accept flag of the event object, indicating that the receiver does not want the event.
+        ///
+        /// Unwanted events might be propagated to the parent widget or item.
+        fn ignore(self: Pin<&mut QKeyEvent>);
+
+        /// Returns whether the accept flag of the event object is set.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QKeyEvent) -> bool;
+
+        /// Returns true if this event comes from an auto-repeating key; returns false if it starts a new key press.
+        #[rust_name = "is_auto_repeat"]
+        fn isAutoRepeat(self: &QKeyEvent) -> bool;
+
+        /// Returns the code of the key that was pressed or released, as a value of the Qt::Key enum.
+        ///
+        /// If the key could not be identified then 0 is returned.
+        fn key(self: &QKeyEvent) -> i32;
+
+        /// Returns the Unicode text that this key generated.
+        ///
+        /// The text may be empty when modifier keys such as Shift or Control are pressed.
+        fn text(self: &QKeyEvent) -> QString;
+    }
+}
+
+pub use ffi::QKeyEvent;
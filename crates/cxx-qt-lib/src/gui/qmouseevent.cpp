@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmouseevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPointF
+qmouseeventPosition(const QMouseEvent& event)
+{
+  // Qt 6 renamed localPos to position
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.position();
+#else
+  return event.localPos();
+#endif
+}
+
+QPointF
+qmouseeventScenePosition(const QMouseEvent& event)
+{
+  // Qt 6 renamed windowPos to scenePosition
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.scenePosition();
+#else
+  return event.windowPos();
+#endif
+}
+
+}
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButton = crate::MouseButton;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qmouseevent.h");
+        type QMouseEvent;
+
+        /// Sets the accept flag of the event object, indicating that the receiver wants the event.
+        fn accept(self: Pin<&mut QMouseEvent>);
+
+        /// Returns the button that caused the event.
+        ///
+        /// Note that the returned value is always [MouseButton::NoButton] for mouse move events.
+        fn button(self: &QMouseEvent) -> MouseButton;
+
+        /// Clears the accept flag of the event object, indicating that the receiver does not want the event.
+        ///
+        /// Unwanted events might be propagated to the parent widget or item.
+        fn ignore(self: Pin<&mut QMouseEvent>);
+
+        /// Returns whether the accept flag of the event object is set.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QMouseEvent) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_position"]
+        fn qmouseeventPosition(event: &QMouseEvent) -> QPointF;
+
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_scene_position"]
+        fn qmouseeventScenePosition(event: &QMouseEvent) -> QPointF;
+    }
+}
+
+use crate::QPointF;
+
+pub use ffi::QMouseEvent;
+
+impl QMouseEvent {
+    /// Returns the position of the mouse cursor, relative to the widget or item that received the event.
+    pub fn position(&self) -> QPointF {
+        ffi::qmouseevent_position(self)
+    }
+
+    /// Returns the position of the mouse cursor, relative to the window or scene that received the event.
+    pub fn scene_position(&self) -> QPointF {
+        ffi::qmouseevent_scene_position(self)
+    }
+}
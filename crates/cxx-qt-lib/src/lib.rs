@@ -20,3 +20,13 @@ pub use crate::gui::*;
 mod qml;
 #[cfg(feature = "qt_qml")]
 pub use crate::qml::*;
+
+#[cfg(feature = "qt_quick")]
+mod quick;
+#[cfg(feature = "qt_quick")]
+pub use crate::quick::*;
+
+#[cfg(feature = "qt_quick_test")]
+mod quicktest;
+#[cfg(feature = "qt_quick_test")]
+pub use crate::quicktest::*;
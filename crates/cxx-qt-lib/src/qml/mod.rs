@@ -3,8 +3,31 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qjsengine;
+pub use qjsengine::QJSEngine;
+
+#[cfg(cxxqt_qt_version_major = "6")]
+mod qjsmanagedvalue;
+#[cfg(cxxqt_qt_version_major = "6")]
+pub use qjsmanagedvalue::{QJSManagedValue, QJSManagedValueType};
+
+mod qjsvalue;
+pub use qjsvalue::{QJSValue, QJSValueErrorType};
+
+mod qqml;
+pub use qqml::qml_register_singleton_instance;
+
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
+mod qqmlcomponent;
+pub use qqmlcomponent::{QQmlComponent, QQmlComponentStatus};
+
+mod qqmlcontext;
+pub use qqmlcontext::QQmlContext;
+
 mod qqmlengine;
 pub use qqmlengine::QQmlEngine;
+
+mod qqmlerror;
+pub use qqmlerror::QQmlError;
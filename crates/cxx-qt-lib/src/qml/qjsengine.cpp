@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsengine.h"
+
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber)
+{
+  // Qt 6 has an extra optional parameter for the exception stack trace
+  // so we can't bind to the method directly
+  return engine.evaluate(program, fileName, static_cast<int>(lineNumber));
+}
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew()
+{
+  return ::std::make_unique<QJSEngine>();
+}
+
+void
+qjsengineSetGlobalQObject(QJSEngine& engine,
+                          const QString& name,
+                          QObject* object)
+{
+  // Ensure that the engine does not take ownership of the object
+  // as it is owned by Rust or C++
+  //
+  // In Qt 5 setObjectOwnership is only available on QQmlEngine
+  QQmlEngine::setObjectOwnership(object, QQmlEngine::CppOwnership);
+  engine.globalObject().setProperty(name, engine.newQObject(object));
+}
+
+QJSValue
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value)
+{
+  return engine.toScriptValue(value);
+}
+
+}
+}
@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine;
+
+        /// Runs the garbage collector.
+        ///
+        /// The garbage collector will attempt to reclaim memory by locating and disposing of objects
+        /// that are no longer reachable in the script environment.
+        #[rust_name = "collect_garbage"]
+        fn collectGarbage(self: Pin<&mut QJSEngine>);
+
+        /// Returns this engine's Global Object.
+        ///
+        /// By default, the Global Object contains the built-in objects that are part of ECMA-262,
+        /// such as Math, Date and String. Additionally, you can set properties of the Global Object
+        /// to make your own extensions available to all script code.
+        #[rust_name = "global_object"]
+        fn globalObject(self: &QJSEngine) -> QJSValue;
+
+        /// Imports the module located at file_name and returns a module namespace object
+        /// that contains all exported variables, constants and functions as properties.
+        ///
+        /// If this is the first time the module is imported in the engine,
+        /// the file is loaded from the specified location in either the local file system
+        /// or the Qt resource system and evaluated as an ECMAScript module.
+        #[rust_name = "import_module"]
+        fn importModule(self: Pin<&mut QJSEngine>, file_name: &QString) -> QJSValue;
+
+        /// Creates a JavaScript object of class Array with the given length.
+        #[rust_name = "new_array"]
+        fn newArray(self: Pin<&mut QJSEngine>, length: u32) -> QJSValue;
+
+        /// Creates a JavaScript object of class Object.
+        ///
+        /// The prototype of the created object will be the Object prototype object.
+        #[rust_name = "new_object"]
+        fn newObject(self: Pin<&mut QJSEngine>) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "new_qobject_raw"]
+        unsafe fn newQObject(self: Pin<&mut QJSEngine>, object: *mut QObject) -> QJSValue;
+
+        /// Throws a run-time error (exception) with the given message.
+        ///
+        /// This method is the C++ counterpart of a throw() expression in JavaScript.
+        /// It enables C++ code to report run-time errors to QJSEngine.
+        /// Therefore it should only be called from C++ code that was invoked by a JavaScript function.
+        #[rust_name = "throw_error"]
+        fn throwError(self: Pin<&mut QJSEngine>, message: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_evaluate"]
+        fn qjsengineEvaluate(
+            engine: Pin<&mut QJSEngine>,
+            program: &QString,
+            file_name: &QString,
+            line_number: i32,
+        ) -> QJSValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new"]
+        fn qjsengineNew() -> UniquePtr<QJSEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_set_global_qobject"]
+        unsafe fn qjsengineSetGlobalQObject(
+            engine: Pin<&mut QJSEngine>,
+            name: &QString,
+            object: *mut QObject,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_to_script_value"]
+        fn qjsengineToScriptValue(engine: Pin<&mut QJSEngine>, value: &QVariant) -> QJSValue;
+    }
+
+    // QJSEngine is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QJSEngine>::isRelocatable);
+    impl UniquePtr<QJSEngine> {}
+}
+
+use crate::{QJSValue, QObject, QString, QVariant};
+use core::pin::Pin;
+
+pub use ffi::QJSEngine;
+
+impl QJSEngine {
+    /// Evaluates program, using line_number as the base line number,
+    /// and returns the result of the evaluation.
+    ///
+    /// The script code will be evaluated in the context of the global object.
+    ///
+    /// The evaluation of program can cause an exception in the engine;
+    /// in this case the return value will be the exception that was thrown (typically an Error object).
+    /// You can call [QJSValue::is_error] on the return value to determine whether an exception occurred.
+    pub fn evaluate(
+        self: Pin<&mut Self>,
+        program: &QString,
+        file_name: &QString,
+        line_number: i32,
+    ) -> QJSValue {
+        ffi::qjsengine_evaluate(self, program, file_name, line_number)
+    }
+
+    /// Create a new QJSEngine
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qjsengine_new()
+    }
+
+    /// Creates a JavaScript object that wraps the given QObject object.
+    ///
+    /// Signals and slots, properties and children of object are available as properties
+    /// of the created QJSValue.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer, or a null pointer in which case a null value is returned.
+    ///
+    /// If the object does not have a parent and its ownership has not been set explicitly,
+    /// then it is owned by the JavaScript engine and will be deleted when it is garbage collected.
+    pub unsafe fn new_qobject(self: Pin<&mut Self>, object: *mut QObject) -> QJSValue {
+        self.new_qobject_raw(object)
+    }
+
+    /// Makes the given QObject available to all script code as a property of the global object
+    /// with the given name.
+    ///
+    /// The ownership of the object is set to C++ ownership,
+    /// so that the JavaScript engine never deletes the object.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer which outlives the engine,
+    /// and the object must only be accessed from the thread of the engine.
+    pub unsafe fn set_global_qobject(self: Pin<&mut Self>, name: &QString, object: *mut QObject) {
+        ffi::qjsengine_set_global_qobject(self, name, object);
+    }
+
+    /// Creates a QJSValue with the given value.
+    ///
+    /// The contents of the QVariant are converted into the JavaScript type,
+    /// for example a QVariantMap becomes a JavaScript object and a QVariantList becomes an array.
+    pub fn to_script_value(self: Pin<&mut Self>, value: &QVariant) -> QJSValue {
+        ffi::qjsengine_to_script_value(self, value)
+    }
+}
@@ -0,0 +1,72 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qjsmanagedvalue.h"
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 1, 0))
+
+#include "../assertion_utils.h"
+
+// QJSManagedValue has one pointer as a member
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsmanagedvalue.h?h=v6.2.4#n143
+assert_alignment_and_size(QJSManagedValue,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_copy_constructible<QJSManagedValue>::value);
+
+static_assert(!::std::is_trivially_destructible<QJSManagedValue>::value);
+
+namespace {
+
+QJSValueList
+qjsvalueListFromSlice(::rust::Slice<const QJSValue> args)
+{
+  QJSValueList list;
+  list.reserve(static_cast<qsizetype>(args.size()));
+  for (const auto& arg : args) {
+    list.append(arg);
+  }
+  return list;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QJSValue
+qjsmanagedvalueCall(const QJSManagedValue& value,
+                    ::rust::Slice<const QJSValue> args)
+{
+  return value.call(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsmanagedvalueCallAsConstructor(const QJSManagedValue& value,
+                                 ::rust::Slice<const QJSValue> args)
+{
+  return value.callAsConstructor(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsmanagedvalueCallWithInstance(const QJSManagedValue& value,
+                                const QJSValue& instance,
+                                ::rust::Slice<const QJSValue> args)
+{
+  return value.callWithInstance(instance, qjsvalueListFromSlice(args));
+}
+
+QJSManagedValue
+qjsmanagedvalueInitQJSValue(const QJSValue& value, QJSEngine& engine)
+{
+  return QJSManagedValue(value, &engine);
+}
+
+}
+}
+
+#endif
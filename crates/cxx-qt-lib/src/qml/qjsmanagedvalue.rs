@@ -0,0 +1,324 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum represents the JavaScript native types, as specified by ECMA-262.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QJSManagedValueType {
+        /// The undefined type
+        Undefined,
+        /// The boolean type
+        Boolean,
+        /// The number type
+        Number,
+        /// The string type
+        String,
+        /// The symbol type
+        Symbol,
+        /// The object type
+        Object,
+        /// The function type
+        Function,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qjsmanagedvalue.h");
+        type QJSManagedValue = super::QJSManagedValue;
+        #[namespace = "rust::cxxqtlib1"]
+        type QJSManagedValueType;
+
+        /// Deletes the property name from this QJSManagedValue.
+        /// Returns true if the deletion succeeded, or false otherwise.
+        #[rust_name = "delete_property"]
+        fn deleteProperty(self: &mut QJSManagedValue, name: &QString) -> bool;
+
+        /// Returns the QJSEngine this QJSManagedValue belongs to.
+        /// Mind that the engine is always valid, unless the QJSManagedValue is default-constructed or moved from.
+        /// In the latter case a null pointer is returned.
+        fn engine(self: &QJSManagedValue) -> *mut QJSEngine;
+
+        /// Invokes, in order, the JavaScript 'Equality Comparison' algorithm,
+        /// and returns true if this QJSManagedValue is equal to other.
+        fn equals(self: &QJSManagedValue, other: &QJSManagedValue) -> bool;
+
+        /// Returns true if this QJSManagedValue has an own property name, otherwise false.
+        ///
+        /// The prototype chain is not searched.
+        #[rust_name = "has_own_property"]
+        fn hasOwnProperty(self: &QJSManagedValue, name: &QString) -> bool;
+
+        /// Returns true if this QJSManagedValue has a property name, otherwise false.
+        ///
+        /// The prototype chain is searched.
+        #[rust_name = "has_property"]
+        fn hasProperty(self: &QJSManagedValue, name: &QString) -> bool;
+
+        /// Returns true if this value represents a JavaScript Array object, or false otherwise.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Boolean, or false otherwise.
+        #[rust_name = "is_boolean"]
+        fn isBoolean(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a JavaScript Date object, or false otherwise.
+        #[rust_name = "is_date"]
+        fn isDate(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a JavaScript Error object, or false otherwise.
+        #[rust_name = "is_error"]
+        fn isError(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Function, or false otherwise.
+        #[rust_name = "is_function"]
+        fn isFunction(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this QJSManagedValue holds an integer value, or false otherwise.
+        #[rust_name = "is_integer"]
+        fn isInteger(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this QJSManagedValue holds the JavaScript null value, or false otherwise.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Number, or false otherwise.
+        #[rust_name = "is_number"]
+        fn isNumber(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Object, or false otherwise.
+        #[rust_name = "is_object"]
+        fn isObject(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a QObject pointer managed on the JavaScript heap, or false otherwise.
+        #[rust_name = "is_qobject"]
+        fn isQObject(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a JavaScript regular expression object, or false otherwise.
+        #[rust_name = "is_regular_expression"]
+        fn isRegularExpression(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is String, or false otherwise.
+        #[rust_name = "is_string"]
+        fn isString(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Symbol, or false otherwise.
+        #[rust_name = "is_symbol"]
+        fn isSymbol(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if the type of this QJSManagedValue is Undefined, or false otherwise.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a JavaScript Url object, or false otherwise.
+        #[rust_name = "is_url"]
+        fn isUrl(self: &QJSManagedValue) -> bool;
+
+        /// Returns true if this value represents a QVariant managed on the JavaScript heap, or false otherwise.
+        #[rust_name = "is_variant"]
+        fn isVariant(self: &QJSManagedValue) -> bool;
+
+        /// Returns the property name of this QJSManagedValue.
+        ///
+        /// The prototype chain is searched if the property is not found on the actual object.
+        fn property(self: &QJSManagedValue, name: &QString) -> QJSValue;
+
+        /// Returns the property at the given index of this QJSManagedValue.
+        ///
+        /// This is used to access the elements of an array.
+        #[rust_name = "property_at"]
+        fn property(self: &QJSManagedValue, array_index: u32) -> QJSValue;
+
+        /// Sets the property name to value on this QJSManagedValue.
+        ///
+        /// This can only be done on JavaScript values of type object.
+        #[rust_name = "set_property"]
+        fn setProperty(self: &mut QJSManagedValue, name: &QString, value: &QJSValue);
+
+        /// Sets the property at the given index to value on this QJSManagedValue.
+        ///
+        /// This is used to set the elements of an array.
+        #[rust_name = "set_property_at"]
+        fn setProperty(self: &mut QJSManagedValue, array_index: u32, value: &QJSValue);
+
+        /// Invokes the JavaScript 'IsStrictlyEqual' algorithm on this QJSManagedValue and other.
+        /// Returns true if both values are strictly equal, otherwise false.
+        #[rust_name = "strictly_equals"]
+        fn strictlyEquals(self: &QJSManagedValue, other: &QJSManagedValue) -> bool;
+
+        /// Converts the managed value to a boolean.
+        #[rust_name = "to_boolean"]
+        fn toBoolean(self: &QJSManagedValue) -> bool;
+
+        /// Converts the managed value to an integer.
+        #[rust_name = "to_integer"]
+        fn toInteger(self: &QJSManagedValue) -> i32;
+
+        /// Copies this QJSManagedValue into a new QJSValue.
+        #[rust_name = "to_jsvalue"]
+        fn toJSValue(self: &QJSManagedValue) -> QJSValue;
+
+        /// Converts the managed value to a number.
+        #[rust_name = "to_number"]
+        fn toNumber(self: &QJSManagedValue) -> f64;
+
+        /// If this QJSManagedValue holds a QObject pointer, returns it. Otherwise, returns a null pointer.
+        #[rust_name = "to_qobject"]
+        fn toQObject(self: &QJSManagedValue) -> *mut QObject;
+
+        /// Converts the managed value to a string.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSManagedValue) -> QString;
+
+        /// If this QJSManagedValue holds a JavaScript Url object, returns an equivalent QUrl.
+        /// Otherwise, returns an invalid QUrl.
+        #[rust_name = "to_qurl"]
+        fn toUrl(self: &QJSManagedValue) -> QUrl;
+
+        /// Copies this QJSManagedValue into a new QVariant.
+        #[rust_name = "to_variant"]
+        fn toVariant(self: &QJSManagedValue) -> QVariant;
+
+        /// Returns the JavaScript type of this QJSManagedValue.
+        #[cxx_name = "type"]
+        fn value_type(self: &QJSManagedValue) -> QJSManagedValueType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_drop"]
+        fn drop(value: &mut QJSManagedValue);
+
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_init_default"]
+        fn construct() -> QJSManagedValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_call"]
+        fn qjsmanagedvalueCall(value: &QJSManagedValue, args: &[QJSValue]) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_call_as_constructor"]
+        fn qjsmanagedvalueCallAsConstructor(value: &QJSManagedValue, args: &[QJSValue])
+            -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_call_with_instance"]
+        fn qjsmanagedvalueCallWithInstance(
+            value: &QJSManagedValue,
+            instance: &QJSValue,
+            args: &[QJSValue],
+        ) -> QJSValue;
+        #[doc(hidden)]
+        #[rust_name = "qjsmanagedvalue_init_qjsvalue"]
+        fn qjsmanagedvalueInitQJSValue(
+            value: &QJSValue,
+            engine: Pin<&mut QJSEngine>,
+        ) -> QJSManagedValue;
+    }
+}
+
+pub use ffi::QJSManagedValueType;
+
+use crate::{QJSEngine, QJSValue};
+use core::pin::Pin;
+
+/// The QJSManagedValue class represents a value on the JavaScript heap belonging to a QJSEngine.
+///
+/// Unlike [QJSValue], the value is always bound to an engine and the JavaScript semantics
+/// are used for conversions and property access.
+///
+/// Note that this type is only available with Qt 6.1 or later.
+#[repr(C)]
+pub struct QJSManagedValue {
+    _space: MaybeUninit<usize>,
+}
+
+impl QJSManagedValue {
+    /// If this QJSManagedValue represents a JavaScript FunctionObject,
+    /// calls it with the given arguments, and with the globalObject() as "this".
+    /// Returns the return value of the JavaScript function, wrapped in a QJSValue.
+    ///
+    /// If this QJSManagedValue is not a function, returns an undefined QJSValue.
+    pub fn call(&self, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsmanagedvalue_call(self, args)
+    }
+
+    /// If this QJSManagedValue represents a JavaScript FunctionObject,
+    /// calls it as constructor with the given arguments, and returns the object created.
+    ///
+    /// If this QJSManagedValue is not a function, returns an undefined QJSValue.
+    pub fn call_as_constructor(&self, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsmanagedvalue_call_as_constructor(self, args)
+    }
+
+    /// If this QJSManagedValue represents a JavaScript FunctionObject,
+    /// calls it on instance with the given arguments.
+    /// Returns the return value of the JavaScript function, wrapped in a QJSValue.
+    pub fn call_with_instance(&self, instance: &QJSValue, args: &[QJSValue]) -> QJSValue {
+        ffi::qjsmanagedvalue_call_with_instance(self, instance, args)
+    }
+
+    /// Creates a QJSManagedValue from value, using the heap of engine.
+    ///
+    /// If value is itself managed by an engine other than engine, the managed value
+    /// will be undefined.
+    pub fn new(value: &QJSValue, engine: Pin<&mut QJSEngine>) -> Self {
+        ffi::qjsmanagedvalue_init_qjsvalue(value, engine)
+    }
+}
+
+impl Default for QJSManagedValue {
+    /// Creates a QJSManagedValue that represents the JavaScript undefined value.
+    /// This is the only value not stored on the JavaScript heap.
+    fn default() -> Self {
+        ffi::qjsmanagedvalue_init_default()
+    }
+}
+
+impl fmt::Display for QJSManagedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QJSManagedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Drop for QJSManagedValue {
+    fn drop(&mut self) {
+        ffi::qjsmanagedvalue_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QJSManagedValue {
+    type Id = type_id!("QJSManagedValue");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,82 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qjsvalue.h"
+
+#include "../assertion_utils.h"
+
+// The layout has changed between Qt 5 and Qt 6
+//
+// Qt5 QJSValue has one pointer as a member
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v5.15.2#n147
+//
+// Qt6 QJSValue has one quint64 as a member
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/jsapi/qjsvalue.h?h=v6.2.4#n156
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QJSValue,
+                          alignof(::std::uint64_t),
+                          sizeof(::std::uint64_t));
+#else
+assert_alignment_and_size(QJSValue,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QJSValue>::value);
+static_assert(!::std::is_trivially_copy_constructible<QJSValue>::value);
+
+static_assert(!::std::is_trivially_destructible<QJSValue>::value);
+
+namespace {
+
+QJSValueList
+qjsvalueListFromSlice(::rust::Slice<const QJSValue> args)
+{
+  QJSValueList list;
+  list.reserve(static_cast<int>(args.size()));
+  for (const auto& arg : args) {
+    list.append(arg);
+  }
+  return list;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Note that in Qt 5 the call methods are not const, but as QJSValue is
+// a reference to the JavaScript value we can call them on a copy.
+
+QJSValue
+qjsvalueCall(const QJSValue& value, ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).call(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueCallAsConstructor(const QJSValue& value,
+                          ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).callAsConstructor(qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         ::rust::Slice<const QJSValue> args)
+{
+  return QJSValue(value).callWithInstance(instance,
+                                          qjsvalueListFromSlice(args));
+}
+
+QJSValue
+qjsvalueInitNull()
+{
+  return QJSValue(QJSValue::NullValue);
+}
+
+}
+}
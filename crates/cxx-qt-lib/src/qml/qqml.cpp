@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqml.h"
+
+#include <QtCore/QByteArray>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qmlRegisterSingletonInstanceQObject(::rust::Str uri,
+                                    ::std::int32_t versionMajor,
+                                    ::std::int32_t versionMinor,
+                                    ::rust::Str typeName,
+                                    QObject* instance)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray.
+  // The QML type registration copies the strings, so they only need to live
+  // for the duration of the call.
+  const auto uriBytes = QByteArray(uri.data(), uri.size());
+  const auto typeNameBytes = QByteArray(typeName.data(), typeName.size());
+  return ::qmlRegisterSingletonInstance(uriBytes.constData(),
+                                        versionMajor,
+                                        versionMinor,
+                                        typeNameBytes.constData(),
+                                        instance);
+}
+
+}
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqml.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qml_register_singleton_instance_qobject"]
+        unsafe fn qmlRegisterSingletonInstanceQObject(
+            uri: &str,
+            version_major: i32,
+            version_minor: i32,
+            type_name: &str,
+            instance: *mut QObject,
+        ) -> i32;
+    }
+}
+
+use crate::QObject;
+use core::pin::Pin;
+
+/// Register a singleton instance with the name type_name, in the library imported from uri having
+/// the version number composed from version_major and version_minor.
+///
+/// This allows for an object which has been created and configured in Rust to be used from QML,
+/// the QML engine does not take ownership of the instance.
+///
+/// Returns the QML type id.
+///
+/// # Safety
+///
+/// The instance must outlive every QML engine which uses the singleton,
+/// and the instance must only be accessed from the thread of the QML engine.
+pub unsafe fn qml_register_singleton_instance<T: cxx_qt::CxxQtType>(
+    uri: &str,
+    version_major: i32,
+    version_minor: i32,
+    type_name: &str,
+    instance: Pin<&mut T>,
+) -> i32 {
+    // A CXX-Qt QObject always has a QObject as its first base class
+    let instance = instance.get_unchecked_mut() as *mut T as *mut QObject;
+    ffi::qml_register_singleton_instance_qobject(
+        uri,
+        version_major,
+        version_minor,
+        type_name,
+        instance,
+    )
+}
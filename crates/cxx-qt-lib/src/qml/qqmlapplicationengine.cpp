@@ -22,5 +22,28 @@ qqmlapplicationengineAsQQmlEngine(QQmlApplicationEngine& engine)
   return static_cast<QQmlEngine&>(engine);
 }
 
+QObject*
+qqmlapplicationengineRootObject(const QQmlApplicationEngine& engine,
+                                ::rust::isize pos)
+{
+  const auto rootObjects = engine.rootObjects();
+  Q_ASSERT(pos >= 0);
+  Q_ASSERT(pos < rootObjects.size());
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return rootObjects.at(static_cast<qsizetype>(pos));
+#else
+  return rootObjects.at(static_cast<int>(pos));
+#endif
+}
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine)
+{
+  // In Qt 5 the type was int now it is qsizetype, so we need to ensure the type
+  // is the same for CXX
+  return static_cast<::rust::isize>(engine.rootObjects().size());
+}
+
 }
 }
@@ -3,9 +3,38 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cxx::bridge]
+#[cxx_qt::bridge(cxx_file_stem = "qqmlapplicationengine")]
 mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlapplicationengine.h");
+        #[qobject]
+        type QQmlApplicationEngine;
+
+        /// This signal is emitted when an object finishes loading.
+        /// If loading was successful, object contains a pointer to the loaded object, otherwise the pointer is null.
+        ///
+        /// The url to the component the object came from is also provided.
+        ///
+        /// This signal can only be emitted by C++, connect to it using `on_object_created`.
+        #[doc(hidden)]
+        #[qsignal]
+        #[cxx_name = "objectCreated"]
+        unsafe fn object_created(
+            self: Pin<&mut QQmlApplicationEngine>,
+            object: *mut QObject,
+            url: &QUrl,
+        );
+    }
+
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -13,9 +42,6 @@ mod ffi {
         include!("cxx-qt-lib/qurl.h");
         type QUrl = crate::QUrl;
 
-        include!("cxx-qt-lib/qqmlapplicationengine.h");
-        type QQmlApplicationEngine;
-
         /// Adds path as a directory where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "add_import_path"]
         fn addImportPath(self: Pin<&mut QQmlApplicationEngine>, path: &QString);
@@ -36,6 +62,13 @@ mod ffi {
         /// Loads the root QML file located at url.
         fn load(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
 
+        /// Loads the QML given in data. The object tree defined by data is instantiated immediately.
+        ///
+        /// If a url is specified it is used as the base url of the component.
+        /// This affects relative paths within the data and error messages.
+        #[rust_name = "load_data"]
+        fn loadData(self: Pin<&mut QQmlApplicationEngine>, data: &QByteArray, url: &QUrl);
+
         /// This property holds the directory for storing offline user data
         #[rust_name = "offline_storage_path"]
         fn offlineStoragePath(self: &QQmlApplicationEngine) -> QString;
@@ -44,6 +77,13 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlApplicationEngine) -> QStringList;
 
+        /// Returns the engine's root context.
+        ///
+        /// The root context is automatically created by the QQmlEngine. Data that should be available
+        /// to all QML component instances instantiated by the engine should be put in the root context.
+        #[rust_name = "root_context"]
+        fn rootContext(self: &QQmlApplicationEngine) -> *mut QQmlContext;
+
         /// Set the base URL for this engine to url.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
@@ -52,6 +92,15 @@ mod ffi {
         #[rust_name = "set_import_path_list"]
         fn setImportPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
 
+        /// Sets the initial properties with which the QML component gets initialized after loading.
+        ///
+        /// This allows for objects created in Rust to be given to the root object before it is completed.
+        #[rust_name = "set_initial_properties"]
+        fn setInitialProperties(
+            self: Pin<&mut QQmlApplicationEngine>,
+            initial_properties: &QMap_QString_QVariant,
+        );
+
         /// Sets the list of directories where the engine searches for native plugins for imported modules (referenced in the qmldir file) to paths.
         #[rust_name = "set_plugin_path_list"]
         fn setPluginPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
@@ -77,6 +126,17 @@ mod ffi {
         fn qqmlapplicationengineAsQQmlEngine(
             ptr: Pin<&mut QQmlApplicationEngine>,
         ) -> Pin<&mut QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_object"]
+        fn qqmlapplicationengineRootObject(
+            engine: &QQmlApplicationEngine,
+            pos: isize,
+        ) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_objects_len"]
+        fn qqmlapplicationengineRootObjectsLen(engine: &QQmlApplicationEngine) -> isize;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -86,7 +146,7 @@ mod ffi {
     impl UniquePtr<QQmlApplicationEngine> {}
 }
 
-use crate::QQmlEngine;
+use crate::{QObject, QQmlEngine};
 use core::pin::Pin;
 
 pub use ffi::QQmlApplicationEngine;
@@ -101,4 +161,14 @@ impl QQmlApplicationEngine {
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Returns a list of all the root objects instantiated by the QQmlApplicationEngine.
+    /// This will only contain objects loaded via load() or a convenience constructor.
+    ///
+    /// An empty list can be used to detect that the QML failed to load.
+    pub fn root_objects(&self) -> Vec<*mut QObject> {
+        (0..ffi::qqmlapplicationengine_root_objects_len(self))
+            .map(|pos| ffi::qqmlapplicationengine_root_object(self, pos))
+            .collect()
+    }
 }
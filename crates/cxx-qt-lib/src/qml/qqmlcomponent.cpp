@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlcomponent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlComponent>(&engine);
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNewFromUrl(QQmlEngine& engine, const QUrl& url)
+{
+  return ::std::make_unique<QQmlComponent>(&engine, url);
+}
+
+}
+}
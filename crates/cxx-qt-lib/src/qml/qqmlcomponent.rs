@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(cxx_file_stem = "qqmlcomponent")]
+mod ffi {
+    /// Specifies the loading status of the QQmlComponent.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlComponentStatus {
+        /// This QQmlComponent has no data. Call loadUrl() or setData() to add QML content.
+        Null,
+        /// This QQmlComponent is ready and create() may be called.
+        Ready,
+        /// This QQmlComponent is loading network data.
+        Loading,
+        /// An error has occurred. Call errors() to retrieve a list of errors.
+        Error,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        type QQmlComponentStatus;
+    }
+
+    unsafe extern "C++Qt" {
+        #[qobject]
+        type QQmlComponent;
+
+        /// Emitted whenever the component's loading progress changes.
+        /// progress will be the current progress between 0.0 (nothing loaded) and 1.0 (finished).
+        #[qsignal]
+        #[cxx_name = "progressChanged"]
+        fn progress_changed(self: Pin<&mut QQmlComponent>, progress: f64);
+
+        /// Emitted whenever the component's status changes. status will be the new status.
+        #[qsignal]
+        #[cxx_name = "statusChanged"]
+        fn status_changed(self: Pin<&mut QQmlComponent>, status: QQmlComponentStatus);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QQmlError = crate::QList<crate::QQmlError>;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        #[doc(hidden)]
+        #[rust_name = "create_raw"]
+        unsafe fn create(self: Pin<&mut QQmlComponent>, context: *mut QQmlContext) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "create_with_initial_properties_raw"]
+        unsafe fn createWithInitialProperties(
+            self: Pin<&mut QQmlComponent>,
+            initial_properties: &QMap_QString_QVariant,
+            context: *mut QQmlContext,
+        ) -> *mut QObject;
+
+        /// Returns the QQmlEngine of this component.
+        fn engine(self: &QQmlComponent) -> *mut QQmlEngine;
+
+        /// Return the list of errors that occurred during the last compile or create operation.
+        /// An empty list is returned if isError() is not set.
+        fn errors(self: &QQmlComponent) -> QList_QQmlError;
+
+        /// Returns a human-readable description of any error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QQmlComponent) -> QString;
+
+        /// Returns true if status() == QQmlComponentStatus::Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponentStatus::Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlComponent) -> bool;
+
+        /// Load the QQmlComponent from the provided url.
+        #[rust_name = "load_url"]
+        fn loadUrl(self: Pin<&mut QQmlComponent>, url: &QUrl);
+
+        /// The progress of loading the component, from 0.0 (nothing loaded) to 1.0 (finished).
+        fn progress(self: &QQmlComponent) -> f64;
+
+        /// Sets the QQmlComponent to use the given QML data.
+        /// If url is provided, it is used to set the component name and to provide a base path
+        /// for items resolved by this component.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QQmlComponent>, data: &QByteArray, url: &QUrl);
+
+        /// The component's current status.
+        fn status(self: &QQmlComponent) -> QQmlComponentStatus;
+
+        /// The component URL. This is the URL passed to either the constructor, or the
+        /// loadUrl() or setData() methods.
+        fn url(self: &QQmlComponent) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new"]
+        fn qqmlcomponentNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new_from_url"]
+        fn qqmlcomponentNewFromUrl(
+            engine: Pin<&mut QQmlEngine>,
+            url: &QUrl,
+        ) -> UniquePtr<QQmlComponent>;
+    }
+
+    // QQmlComponent is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQmlComponent>::isRelocatable);
+    impl UniquePtr<QQmlComponent> {}
+}
+
+use crate::{QByteArray, QMap, QMapPair_QString_QVariant, QObject, QQmlContext, QQmlEngine, QUrl};
+use core::pin::Pin;
+
+pub use ffi::{QQmlComponent, QQmlComponentStatus};
+
+impl QQmlComponent {
+    /// Create an object instance from this component, within the root context of the engine.
+    /// Returns a null pointer if creation failed.
+    ///
+    /// The ownership of the returned object instance is transferred to the caller.
+    pub fn create(self: Pin<&mut Self>) -> *mut QObject {
+        // SAFETY: a null context means that the root context of the engine is used
+        unsafe { self.create_raw(std::ptr::null_mut()) }
+    }
+
+    /// Create an object instance from this component, within the specified context.
+    /// Returns a null pointer if creation failed.
+    ///
+    /// If context is a null pointer, the root context of the engine is used.
+    ///
+    /// The ownership of the returned object instance is transferred to the caller.
+    ///
+    /// # Safety
+    ///
+    /// The context must be a valid pointer or a null pointer.
+    pub unsafe fn create_with_context(
+        self: Pin<&mut Self>,
+        context: *mut QQmlContext,
+    ) -> *mut QObject {
+        self.create_raw(context)
+    }
+
+    /// Create an object instance of this component, within the root context of the engine,
+    /// and initialize its top-level properties with initial_properties.
+    /// Returns a null pointer if creation failed.
+    ///
+    /// The ownership of the returned object instance is transferred to the caller.
+    pub fn create_with_initial_properties(
+        self: Pin<&mut Self>,
+        initial_properties: &QMap<QMapPair_QString_QVariant>,
+    ) -> *mut QObject {
+        // SAFETY: a null context means that the root context of the engine is used
+        unsafe { self.create_with_initial_properties_raw(initial_properties, std::ptr::null_mut()) }
+    }
+
+    /// Create a new QQmlComponent with no data, use set_data() or load_url() to add QML content.
+    pub fn new(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_new(engine)
+    }
+
+    /// Create a QQmlComponent from the given url.
+    ///
+    /// If the url is remote the component is loaded asynchronously,
+    /// otherwise it is loaded synchronously.
+    pub fn from_url(engine: Pin<&mut QQmlEngine>, url: &QUrl) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_new_from_url(engine, url)
+    }
+
+    /// Create a QQmlComponent from the given QML data, the url is used for error messages
+    /// and to resolve relative imports.
+    pub fn from_data(
+        engine: Pin<&mut QQmlEngine>,
+        data: &QByteArray,
+        url: &QUrl,
+    ) -> cxx::UniquePtr<Self> {
+        let mut component = Self::new(engine);
+        if let Some(component) = component.as_mut() {
+            component.set_data(data, url);
+        }
+        component
+    }
+}
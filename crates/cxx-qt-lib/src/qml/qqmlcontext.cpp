@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlcontext.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QQmlContext>
+qqmlcontextNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlContext>(&engine);
+}
+
+::std::unique_ptr<QQmlContext>
+qqmlcontextNewChild(QQmlContext& parentContext)
+{
+  return ::std::make_unique<QQmlContext>(&parentContext);
+}
+
+}
+}
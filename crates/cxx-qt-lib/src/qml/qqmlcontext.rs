@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext;
+
+        /// Return the base url of the component, or the containing component if none is set.
+        #[rust_name = "base_url"]
+        fn baseUrl(self: &QQmlContext) -> QUrl;
+
+        /// Return the context object, or a null pointer if there is no context object.
+        #[rust_name = "context_object"]
+        fn contextObject(self: &QQmlContext) -> *mut QObject;
+
+        /// Returns the value of the name property for this context as a QVariant.
+        #[rust_name = "context_property"]
+        fn contextProperty(self: &QQmlContext, name: &QString) -> QVariant;
+
+        /// Return the context's QQmlEngine, or a null pointer if the context has no QQmlEngine
+        /// or the QQmlEngine was destroyed.
+        fn engine(self: &QQmlContext) -> *mut QQmlEngine;
+
+        /// Returns whether the context is valid.
+        ///
+        /// To be valid, a context must have a engine, and it's contextObject(), if any,
+        /// must not have been deleted.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlContext) -> bool;
+
+        /// Return the context's parent QQmlContext, or a null pointer if this context has no parent
+        /// or if the parent has been destroyed.
+        #[rust_name = "parent_context"]
+        fn parentContext(self: &QQmlContext) -> *mut QQmlContext;
+
+        /// Resolves the URL src relative to the URL of the containing component.
+        #[rust_name = "resolved_url"]
+        fn resolvedUrl(self: &QQmlContext, src: &QUrl) -> QUrl;
+
+        /// Explicitly sets the url resolvedUrl() will use for relative references to base_url.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(self: Pin<&mut QQmlContext>, base_url: &QUrl);
+
+        #[doc(hidden)]
+        #[rust_name = "set_context_object_raw"]
+        unsafe fn setContextObject(self: Pin<&mut QQmlContext>, object: *mut QObject);
+
+        /// Set the value of the name property on this context.
+        ///
+        /// QQmlContext does not take ownership of value.
+        #[rust_name = "set_context_property"]
+        fn setContextProperty(self: Pin<&mut QQmlContext>, name: &QString, value: &QVariant);
+
+        #[doc(hidden)]
+        #[rust_name = "set_context_property_qobject_raw"]
+        unsafe fn setContextProperty(
+            self: Pin<&mut QQmlContext>,
+            name: &QString,
+            value: *mut QObject,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcontext_new"]
+        fn qqmlcontextNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlContext>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcontext_new_child"]
+        fn qqmlcontextNewChild(parent_context: Pin<&mut QQmlContext>) -> UniquePtr<QQmlContext>;
+    }
+
+    // QQmlContext is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQmlContext>::isRelocatable);
+    impl UniquePtr<QQmlContext> {}
+}
+
+use crate::{QObject, QQmlEngine, QString};
+use core::pin::Pin;
+
+pub use ffi::QQmlContext;
+
+impl QQmlContext {
+    /// Create a new QQmlContext as a child of the engine's root context.
+    pub fn new(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcontext_new(engine)
+    }
+
+    /// Create a new QQmlContext with the given parent context.
+    pub fn new_child(parent_context: Pin<&mut QQmlContext>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcontext_new_child(parent_context)
+    }
+
+    /// Set the context object.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer which outlives the context, or a null pointer.
+    pub unsafe fn set_context_object(self: Pin<&mut Self>, object: *mut QObject) {
+        self.set_context_object_raw(object);
+    }
+
+    /// Set the value of the name property on this context to the given object.
+    ///
+    /// QQmlContext does not take ownership of value.
+    ///
+    /// # Safety
+    ///
+    /// The value must be a valid pointer which outlives the context, or a null pointer.
+    pub unsafe fn set_context_property_qobject(
+        self: Pin<&mut Self>,
+        name: &QString,
+        value: *mut QObject,
+    ) {
+        self.set_context_property_qobject_raw(name, value);
+    }
+}
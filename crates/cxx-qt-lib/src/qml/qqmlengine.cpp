@@ -10,6 +10,12 @@
 namespace rust {
 namespace cxxqtlib1 {
 
+QJSEngine&
+qqmlengineAsQJSEngine(QQmlEngine& engine)
+{
+  return static_cast<QJSEngine&>(engine);
+}
+
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew()
 {
@@ -17,9 +17,19 @@ mod ffi {
         /// This signal is emitted when the QML loaded by the engine would like to quit.
         #[qsignal]
         fn quit(self: Pin<&mut QQmlEngine>);
+
+        /// This signal is emitted when warnings messages are generated by QML.
+        #[qsignal]
+        fn warnings(self: Pin<&mut QQmlEngine>, warnings: &QList_QQmlError);
     }
 
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QQmlError = crate::QList<crate::QQmlError>;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -56,6 +66,17 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlEngine) -> QStringList;
 
+        /// Removes the image provider for provider_id.
+        #[rust_name = "remove_image_provider"]
+        fn removeImageProvider(self: Pin<&mut QQmlEngine>, provider_id: &QString);
+
+        /// Returns the engine's root context.
+        ///
+        /// The root context is automatically created by the QQmlEngine. Data that should be available
+        /// to all QML component instances instantiated by the engine should be put in the root context.
+        #[rust_name = "root_context"]
+        fn rootContext(self: &QQmlEngine) -> *mut QQmlContext;
+
         /// Set the base URL for this engine to url.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlEngine>, url: &QUrl);
@@ -79,6 +100,10 @@ mod ffi {
 
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_as_qjsengine"]
+        fn qqmlengineAsQJSEngine(ptr: Pin<&mut QQmlEngine>) -> Pin<&mut QJSEngine>;
+
         #[doc(hidden)]
         #[rust_name = "qqmlengine_new"]
         fn qqmlengineNew() -> UniquePtr<QQmlEngine>;
@@ -91,9 +116,17 @@ mod ffi {
     impl UniquePtr<QQmlEngine> {}
 }
 
+use crate::QJSEngine;
+use core::pin::Pin;
+
 pub use ffi::QQmlEngine;
 
 impl QQmlEngine {
+    /// Convert the existing [QQmlEngine] to a [QJSEngine]
+    pub fn as_qjsengine(self: Pin<&mut Self>) -> Pin<&mut QJSEngine> {
+        ffi::qqmlengine_as_qjsengine(self)
+    }
+
     /// Create a new QQmlEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlengine_new()
@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qqmlerror.h"
+
+#include "../assertion_utils.h"
+
+// QQmlError has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v5.15.2#n98
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v6.2.4#n90
+assert_alignment_and_size(QQmlError,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QQmlError>::value);
+static_assert(!::std::is_trivially_copy_constructible<QQmlError>::value);
+
+static_assert(!::std::is_trivially_destructible<QQmlError>::value);
@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = super::QQmlError;
+
+        /// Returns the error column number.
+        fn column(self: &QQmlError) -> i32;
+
+        /// Returns the error description.
+        fn description(self: &QQmlError) -> QString;
+
+        /// Returns true if this error is valid, otherwise false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlError) -> bool;
+
+        /// Returns the error line number.
+        fn line(self: &QQmlError) -> i32;
+
+        /// Returns the nearest object where this error occurred.
+        /// Exceptions in bound property expressions set this to the object
+        /// to which the property belongs. It will be null for all other exceptions.
+        fn object(self: &QQmlError) -> *mut QObject;
+
+        /// Sets the error column number.
+        #[rust_name = "set_column"]
+        fn setColumn(self: &mut QQmlError, column: i32);
+
+        /// Sets the error description.
+        #[rust_name = "set_description"]
+        fn setDescription(self: &mut QQmlError, description: &QString);
+
+        /// Sets the error line number.
+        #[rust_name = "set_line"]
+        fn setLine(self: &mut QQmlError, line: i32);
+
+        /// Sets the url for the file that caused this error.
+        #[rust_name = "set_url"]
+        fn setUrl(self: &mut QQmlError, url: &QUrl);
+
+        /// Returns the error as a human readable string.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QQmlError) -> QString;
+
+        /// Returns the url for the file that caused this error.
+        fn url(self: &QQmlError) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_drop"]
+        fn drop(error: &mut QQmlError);
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_init_default"]
+        fn construct() -> QQmlError;
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_clone"]
+        fn construct(error: &QQmlError) -> QQmlError;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_eq"]
+        fn operatorEq(a: &QQmlError, b: &QQmlError) -> bool;
+    }
+}
+
+/// The QQmlError class encapsulates a QML error.
+///
+/// Errors are returned from a [QQmlComponent](crate::QQmlComponent) which failed to load
+/// and are emitted by the `warnings` signal of a [QQmlEngine](crate::QQmlEngine).
+#[repr(C)]
+pub struct QQmlError {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QQmlError {
+    /// Creates a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qqmlerror_clone(self)
+    }
+}
+
+impl Default for QQmlError {
+    /// Creates an empty error object.
+    fn default() -> Self {
+        ffi::qqmlerror_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QQmlError {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qqmlerror_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QQmlError {}
+
+impl fmt::Display for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::error::Error for QQmlError {}
+
+impl Drop for QQmlError {
+    fn drop(&mut self) {
+        ffi::qqmlerror_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QQmlError {
+    type Id = type_id!("QQmlError");
+    type Kind = cxx::kind::Trivial;
+}
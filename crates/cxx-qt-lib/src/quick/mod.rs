@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquickimageprovider;
+pub use qquickimageprovider::{QQuickAsyncImageProvider, QQuickImageProvider, QQuickImageResponse};
+
+mod qquickitem;
+pub use qquickitem::{QQuickItem, QQuickItemFlag, QQuickItemUpdatePaintNodeData};
+
+mod qquickwindow;
+pub use qquickwindow::QQuickWindow;
+
+mod qsggeometry;
+pub use qsggeometry::{QSGGeometry, QSGGeometryDrawingMode};
+
+mod qsgmaterial;
+pub use qsgmaterial::{QSGFlatColorMaterial, QSGMaterial, QSGTextureMaterial};
+
+mod qsgnode;
+pub use qsgnode::{QSGGeometryNode, QSGNode, QSGNodeDirtyStateBit, QSGNodeFlag, QSGSimpleRectNode};
+
+mod qsgtexture;
+pub use qsgtexture::{QSGTexture, QSGTextureFiltering};
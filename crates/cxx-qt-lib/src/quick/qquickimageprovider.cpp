@@ -0,0 +1,178 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickimageprovider.h"
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QMutexLocker>
+
+#include "cxx-qt-gen/qquickimageprovider.cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+class RustQQuickImageProvider : public QQuickImageProvider
+{
+public:
+  RustQQuickImageProvider(::rust::Box<QQuickImageProviderRust>&& provider,
+                          bool forceAsynchronousImageLoading)
+    : QQuickImageProvider(
+        QQmlImageProviderBase::Image,
+        forceAsynchronousImageLoading
+          ? QQmlImageProviderBase::ForceAsynchronousImageLoading
+          : QQmlImageProviderBase::Flags())
+    , m_provider(::std::move(provider))
+  {
+  }
+
+  QImage requestImage(const QString& id,
+                      QSize* size,
+                      const QSize& requestedSize) override
+  {
+    QImage image = m_provider->requestImage(id, requestedSize);
+    if (size != nullptr) {
+      *size = image.size();
+    }
+    return image;
+  }
+
+private:
+  ::rust::Box<QQuickImageProviderRust> m_provider;
+};
+
+class RustQQuickImageResponse : public QQuickImageResponse
+{
+public:
+  RustQQuickImageResponse()
+    : m_handle(::std::make_shared<QQuickImageResponseHandle>(this))
+  {
+  }
+
+  ~RustQQuickImageResponse() override { m_handle->detach(); }
+
+  void cancel() override { m_handle->cancel(); }
+
+  QString errorString() const override { return m_errorString; }
+
+  QQuickTextureFactory* textureFactory() const override
+  {
+    return QQuickTextureFactory::textureFactoryForImage(m_image);
+  }
+
+  ::std::shared_ptr<QQuickImageResponseHandle> handle() const
+  {
+    return m_handle;
+  }
+
+private:
+  friend class QQuickImageResponseHandle;
+
+  ::std::shared_ptr<QQuickImageResponseHandle> m_handle;
+  QImage m_image;
+  QString m_errorString;
+};
+
+class RustQQuickAsyncImageProvider : public QQuickAsyncImageProvider
+{
+public:
+  explicit RustQQuickAsyncImageProvider(
+    ::rust::Box<QQuickAsyncImageProviderRust>&& provider)
+    : m_provider(::std::move(provider))
+  {
+  }
+
+  QQuickImageResponse* requestImageResponse(
+    const QString& id,
+    const QSize& requestedSize) override
+  {
+    auto response = new RustQQuickImageResponse;
+    m_provider->requestImageResponse(id, requestedSize, response->handle());
+    return response;
+  }
+
+private:
+  ::rust::Box<QQuickAsyncImageProviderRust> m_provider;
+};
+
+QQuickImageResponseHandle::QQuickImageResponseHandle(
+  RustQQuickImageResponse* response)
+  : m_response(response)
+  , m_cancelled(false)
+{
+}
+
+void
+QQuickImageResponseHandle::finish(const QImage& image) const
+{
+  const QMutexLocker locker(&m_mutex);
+  if (m_response != nullptr) {
+    m_response->m_image = image;
+
+    // The finished signal is queued so that the QML engine has connected to it,
+    // even if the response is finished inside requestImageResponse
+    auto response = m_response;
+    QMetaObject::invokeMethod(
+      response, [response]() { Q_EMIT response->finished(); }, Qt::QueuedConnection);
+  }
+}
+
+void
+QQuickImageResponseHandle::finishWithError(const QString& errorString) const
+{
+  const QMutexLocker locker(&m_mutex);
+  if (m_response != nullptr) {
+    m_response->m_errorString = errorString;
+
+    auto response = m_response;
+    QMetaObject::invokeMethod(
+      response, [response]() { Q_EMIT response->finished(); }, Qt::QueuedConnection);
+  }
+}
+
+bool
+QQuickImageResponseHandle::isCancelled() const
+{
+  return m_cancelled.load();
+}
+
+void
+QQuickImageResponseHandle::cancel()
+{
+  m_cancelled.store(true);
+}
+
+void
+QQuickImageResponseHandle::detach()
+{
+  const QMutexLocker locker(&m_mutex);
+  m_response = nullptr;
+}
+
+void
+qquickimageproviderAdd(QQmlEngine& engine,
+                       const QString& id,
+                       ::rust::Box<QQuickImageProviderRust> provider,
+                       bool forceAsynchronousImageLoading)
+{
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    id,
+    new RustQQuickImageProvider(::std::move(provider),
+                                forceAsynchronousImageLoading));
+}
+
+void
+qquickasyncimageproviderAdd(QQmlEngine& engine,
+                            const QString& id,
+                            ::rust::Box<QQuickAsyncImageProviderRust> provider)
+{
+  // The engine takes ownership of the provider
+  engine.addImageProvider(id,
+                          new RustQQuickAsyncImageProvider(::std::move(provider)));
+}
+
+}
+}
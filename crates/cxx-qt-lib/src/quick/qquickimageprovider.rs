@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qquickimageprovider.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QQuickImageResponseHandle;
+
+        /// Store the image and emit the finished signal of the response.
+        fn finish(self: &QQuickImageResponseHandle, image: &QImage);
+
+        /// Store the error string and emit the finished signal of the response.
+        #[rust_name = "finish_with_error"]
+        fn finishWithError(self: &QQuickImageResponseHandle, error_string: &QString);
+
+        /// Returns whether the QML engine has cancelled the response.
+        #[rust_name = "is_cancelled"]
+        fn isCancelled(self: &QQuickImageResponseHandle) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickimageprovider_add"]
+        fn qquickimageproviderAdd(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            provider: Box<QQuickImageProviderRust>,
+            force_asynchronous_image_loading: bool,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qquickasyncimageprovider_add"]
+        fn qquickasyncimageproviderAdd(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            provider: Box<QQuickAsyncImageProviderRust>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQuickImageProviderRust;
+
+        #[cxx_name = "requestImage"]
+        fn request_image(
+            self: &QQuickImageProviderRust,
+            id: &QString,
+            requested_size: &QSize,
+        ) -> QImage;
+
+        #[doc(hidden)]
+        type QQuickAsyncImageProviderRust;
+
+        #[cxx_name = "requestImageResponse"]
+        fn request_image_response(
+            self: &QQuickAsyncImageProviderRust,
+            id: &QString,
+            requested_size: &QSize,
+            handle: SharedPtr<QQuickImageResponseHandle>,
+        );
+    }
+}
+
+use crate::{QImage, QQmlEngine, QSize, QString};
+use core::pin::Pin;
+use cxx::SharedPtr;
+
+// Safety:
+//
+// The handle only contains a pointer to the response which is guarded by a mutex,
+// and an atomic for the cancelled state. So it can be used from any thread.
+unsafe impl Send for ffi::QQuickImageResponseHandle {}
+unsafe impl Sync for ffi::QQuickImageResponseHandle {}
+
+/// A trait which allows for a Rust struct to provide images to QML,
+/// in the same way as a C++ subclass of [QQuickImageProvider](https://doc.qt.io/qt-6/qquickimageprovider.html).
+///
+/// The provider is registered on an engine with [QQmlEngine::add_image_provider],
+/// then images are requested by QML with an URL such as `image://<id>/<image id>`.
+///
+/// ```rust,ignore
+/// struct ColorImageProvider;
+///
+/// impl QQuickImageProvider for ColorImageProvider {
+///     fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+///         // Create the image for the given id
+///     }
+/// }
+///
+/// engine.as_mut().add_image_provider(&QString::from("colors"), ColorImageProvider);
+/// ```
+pub trait QQuickImageProvider: Send + Sync + 'static {
+    /// Implement this method to return the image with the given id.
+    ///
+    /// The requested_size corresponds to the `sourceSize` requested by an Image item.
+    /// If the requested_size is a valid size, the image returned should be of that size.
+    ///
+    /// Note that this may be called from multiple threads at the same time,
+    /// for example when an Image item has asynchronous set to true.
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage;
+
+    /// Whether to load images asynchronously, even if the Image item has asynchronous set to false.
+    ///
+    /// This is only read once, when the provider is added to the engine.
+    fn force_asynchronous_image_loading(&self) -> bool {
+        false
+    }
+}
+
+/// A trait which allows for a Rust struct to provide images to QML asynchronously,
+/// in the same way as a C++ subclass of [QQuickAsyncImageProvider](https://doc.qt.io/qt-6/qquickasyncimageprovider.html).
+///
+/// The provider is registered on an engine with [QQmlEngine::add_async_image_provider].
+pub trait QQuickAsyncImageProvider: Send + Sync + 'static {
+    /// Implement this method to start loading the image with the given id.
+    ///
+    /// The image is then given to QML by calling [QQuickImageResponse::finish] from any thread,
+    /// for example from a thread which was spawned to load the image.
+    fn request_image_response(
+        &self,
+        id: &QString,
+        requested_size: &QSize,
+        response: QQuickImageResponse,
+    );
+}
+
+/// The response for an image which has been requested from a [QQuickAsyncImageProvider].
+///
+/// The response can be sent to, and finished from, any thread.
+/// If the response is dropped without being finished, it is finished with an error.
+pub struct QQuickImageResponse {
+    handle: Option<SharedPtr<ffi::QQuickImageResponseHandle>>,
+}
+
+impl QQuickImageResponse {
+    /// Finish the response with the given image.
+    pub fn finish(mut self, image: QImage) {
+        if let Some(handle) = self.handle.take() {
+            handle.finish(&image);
+        }
+    }
+
+    /// Finish the response with the given error, the image of the response will be empty.
+    pub fn finish_with_error(mut self, error_string: &QString) {
+        if let Some(handle) = self.handle.take() {
+            handle.finish_with_error(error_string);
+        }
+    }
+
+    /// Returns whether the QML engine has cancelled the request,
+    /// for example because the Image item has been destroyed.
+    ///
+    /// A cancelled response still needs to be finished, but any further work can be skipped.
+    pub fn is_cancelled(&self) -> bool {
+        self.handle
+            .as_ref()
+            .map(|handle| handle.is_cancelled())
+            .unwrap_or(true)
+    }
+}
+
+impl Drop for QQuickImageResponse {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.finish_with_error(&QString::from(
+                "The image response was dropped without being finished",
+            ));
+        }
+    }
+}
+
+impl QQmlEngine {
+    /// Sets the provider to use for images requested via the image: url scheme, with host id.
+    ///
+    /// The QQmlEngine takes ownership of the provider.
+    pub fn add_image_provider(
+        self: Pin<&mut Self>,
+        id: &QString,
+        provider: impl QQuickImageProvider,
+    ) {
+        let force_asynchronous_image_loading = provider.force_asynchronous_image_loading();
+        ffi::qquickimageprovider_add(
+            self,
+            id,
+            Box::new(QQuickImageProviderRust(Box::new(provider))),
+            force_asynchronous_image_loading,
+        );
+    }
+
+    /// Sets the asynchronous provider to use for images requested via the image: url scheme, with host id.
+    ///
+    /// The QQmlEngine takes ownership of the provider.
+    pub fn add_async_image_provider(
+        self: Pin<&mut Self>,
+        id: &QString,
+        provider: impl QQuickAsyncImageProvider,
+    ) {
+        ffi::qquickasyncimageprovider_add(
+            self,
+            id,
+            Box::new(QQuickAsyncImageProviderRust(Box::new(provider))),
+        );
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickImageProviderRust(Box<dyn QQuickImageProvider>);
+
+impl QQuickImageProviderRust {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        self.0.request_image(id, requested_size)
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickAsyncImageProviderRust(Box<dyn QQuickAsyncImageProvider>);
+
+impl QQuickAsyncImageProviderRust {
+    fn request_image_response(
+        &self,
+        id: &QString,
+        requested_size: &QSize,
+        handle: SharedPtr<ffi::QQuickImageResponseHandle>,
+    ) {
+        self.0.request_image_response(
+            id,
+            requested_size,
+            QQuickImageResponse {
+                handle: Some(handle),
+            },
+        );
+    }
+}
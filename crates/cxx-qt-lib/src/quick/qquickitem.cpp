@@ -0,0 +1,19 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickitem.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qquickitemSetAcceptedMouseButtons(QQuickItem& item, Qt::MouseButton buttons)
+{
+  item.setAcceptedMouseButtons(Qt::MouseButtons(buttons));
+}
+
+}
+}
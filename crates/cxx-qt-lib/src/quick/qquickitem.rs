@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Flags which control the behaviour of a QQuickItem.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQuickItemFlag {
+        /// Indicates this item should visually clip its children
+        /// so that they are rendered only within the boundaries of this item.
+        ItemClipsChildrenToShape = 0x01,
+        /// Indicates the item supports text input methods.
+        ItemAcceptsInputMethod = 0x02,
+        /// Indicates the item is a focus scope.
+        ItemIsFocusScope = 0x04,
+        /// Indicates the item has visual content and should be rendered by the scene graph.
+        ///
+        /// This flag needs to be set for update_paint_node to be called.
+        ItemHasContents = 0x08,
+        /// Indicates the item accepts drag and drop events.
+        ItemAcceptsDrops = 0x10,
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButton = crate::MouseButton;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItemFlag;
+
+        /// Contains data which is passed to update_paint_node,
+        /// the type is only used as a pointer in the signature of the override.
+        type QQuickItemUpdatePaintNodeData;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+
+        include!("cxx-qt-lib/qquickwindow.h");
+        type QQuickWindow = crate::QQuickWindow;
+
+        /// The QQuickItem class provides the most basic of all visual items in Qt Quick.
+        ///
+        /// A QQuickItem based class implemented in Rust, by using `#[base = "QQuickItem"]`,
+        /// can override the virtual methods of QQuickItem with `#[cxx_override]`.
+        /// For example `updatePaintNode` to provide scene graph nodes, or `mousePressEvent` to handle input.
+        type QQuickItem;
+
+        /// Returns the bounding rectangle of the item in its own coordinate system.
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QQuickItem) -> QRectF;
+
+        /// Forces active focus on the item.
+        #[rust_name = "force_active_focus"]
+        fn forceActiveFocus(self: Pin<&mut QQuickItem>);
+
+        /// Returns whether hover events are accepted by this item.
+        #[rust_name = "accept_hover_events"]
+        fn acceptHoverEvents(self: &QQuickItem) -> bool;
+
+        /// Returns whether the item has active focus.
+        #[rust_name = "has_active_focus"]
+        fn hasActiveFocus(self: &QQuickItem) -> bool;
+
+        /// Returns the height of the item.
+        fn height(self: &QQuickItem) -> f64;
+
+        /// Returns the implicit height of the item.
+        #[rust_name = "implicit_height"]
+        fn implicitHeight(self: &QQuickItem) -> f64;
+
+        /// Returns the implicit width of the item.
+        #[rust_name = "implicit_width"]
+        fn implicitWidth(self: &QQuickItem) -> f64;
+
+        /// Returns whether the item is enabled to receive mouse and keyboard events.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QQuickItem) -> bool;
+
+        /// Returns whether the item is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickItem) -> bool;
+
+        /// Returns whether the mouse input should remain exclusively with this item.
+        #[rust_name = "keep_mouse_grab"]
+        fn keepMouseGrab(self: &QQuickItem) -> bool;
+
+        /// Returns the opacity of the item.
+        fn opacity(self: &QQuickItem) -> f64;
+
+        /// Returns the visual parent of the item, this is a nullptr for the root item.
+        #[rust_name = "parent_item"]
+        fn parentItem(self: &QQuickItem) -> *mut QQuickItem;
+
+        /// Schedules a polish event for this item.
+        fn polish(self: Pin<&mut QQuickItem>);
+
+        /// Sets whether hover events are accepted by this item.
+        #[rust_name = "set_accept_hover_events"]
+        fn setAcceptHoverEvents(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Sets whether the item is enabled to receive mouse and keyboard events.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Enables the specified flag for this item if enabled is true; if enabled is false, the flag is disabled.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Sets the height of the item.
+        #[rust_name = "set_height"]
+        fn setHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the implicit height of the item.
+        #[rust_name = "set_implicit_height"]
+        fn setImplicitHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the implicit width of the item.
+        #[rust_name = "set_implicit_width"]
+        fn setImplicitWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets whether the mouse input should remain exclusively with this item.
+        #[rust_name = "set_keep_mouse_grab"]
+        fn setKeepMouseGrab(self: Pin<&mut QQuickItem>, keep: bool);
+
+        /// Sets the opacity of the item.
+        #[rust_name = "set_opacity"]
+        fn setOpacity(self: Pin<&mut QQuickItem>, opacity: f64);
+
+        #[doc(hidden)]
+        #[rust_name = "set_parent_item_raw"]
+        unsafe fn setParentItem(self: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+
+        /// Sets whether the item is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickItem>, visible: bool);
+
+        /// Sets the width of the item.
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the x position of the item relative to its parent.
+        #[rust_name = "set_x"]
+        fn setX(self: Pin<&mut QQuickItem>, x: f64);
+
+        /// Sets the y position of the item relative to its parent.
+        #[rust_name = "set_y"]
+        fn setY(self: Pin<&mut QQuickItem>, y: f64);
+
+        /// Sets the stacking order of sibling items.
+        #[rust_name = "set_z"]
+        fn setZ(self: Pin<&mut QQuickItem>, z: f64);
+
+        /// Schedules a call to update_paint_node for this item.
+        ///
+        /// The call to update_paint_node will always happen if the item is showing in a QQuickWindow.
+        /// Only items which specify [QQuickItemFlag::ItemHasContents] are allowed to call this.
+        fn update(self: Pin<&mut QQuickItem>);
+
+        /// Returns the width of the item.
+        fn width(self: &QQuickItem) -> f64;
+
+        /// Returns the window in which this item is rendered, this is a nullptr if the item is not in a window.
+        fn window(self: &QQuickItem) -> *mut QQuickWindow;
+
+        /// Returns the x position of the item relative to its parent.
+        fn x(self: &QQuickItem) -> f64;
+
+        /// Returns the y position of the item relative to its parent.
+        fn y(self: &QQuickItem) -> f64;
+
+        /// Returns the stacking order of sibling items.
+        fn z(self: &QQuickItem) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_set_accepted_mouse_buttons"]
+        fn qquickitemSetAcceptedMouseButtons(item: Pin<&mut QQuickItem>, buttons: MouseButton);
+    }
+}
+
+use crate::MouseButton;
+use core::pin::Pin;
+
+pub use ffi::{QQuickItem, QQuickItemFlag, QQuickItemUpdatePaintNodeData};
+
+impl QQuickItem {
+    /// Sets the mouse buttons accepted by this item, use [MouseButton::AllButtons] to accept all buttons.
+    ///
+    /// By default no mouse buttons are accepted, so the mouse event overrides are not called.
+    pub fn set_accepted_mouse_buttons(self: Pin<&mut Self>, buttons: MouseButton) {
+        ffi::qquickitem_set_accepted_mouse_buttons(self, buttons);
+    }
+
+    /// Sets the visual parent of this item.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a valid pointer to a QQuickItem or a nullptr.
+    pub unsafe fn set_parent_item(self: Pin<&mut Self>, parent: *mut Self) {
+        self.set_parent_item_raw(parent);
+    }
+}
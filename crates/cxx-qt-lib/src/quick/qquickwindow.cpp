@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickwindow.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSGTexture>
+qquickwindowCreateTextureFromImage(const QQuickWindow& window,
+                                   const QImage& image)
+{
+  // The caller takes ownership of the texture
+  return ::std::unique_ptr<QSGTexture>(window.createTextureFromImage(image));
+}
+
+QString
+qquickwindowSceneGraphBackend()
+{
+  return QQuickWindow::sceneGraphBackend();
+}
+
+void
+qquickwindowSetSceneGraphBackend(const QString& backend)
+{
+  // In Qt 5 this is overloaded with QSGRendererInterface::GraphicsApi
+  QQuickWindow::setSceneGraphBackend(backend);
+}
+
+}
+}
@@ -0,0 +1,90 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qsgtexture.h");
+        type QSGTexture = crate::QSGTexture;
+
+        include!("cxx-qt-lib/qquickwindow.h");
+        /// The QQuickWindow class provides the window for displaying a graphical QML scene.
+        type QQuickWindow;
+
+        /// Returns the color used to clear the color buffer at the beginning of each frame.
+        fn color(self: &QQuickWindow) -> QColor;
+
+        /// Returns the device pixel ratio for this window.
+        ///
+        /// This is different from the device pixel ratio of the screen when the window is rendered into an offscreen target.
+        #[rust_name = "effective_device_pixel_ratio"]
+        fn effectiveDevicePixelRatio(self: &QQuickWindow) -> f64;
+
+        /// Returns the height of the window.
+        fn height(self: &QQuickWindow) -> i32;
+
+        /// Sets the color used to clear the color buffer at the beginning of each frame.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QQuickWindow>, color: &QColor);
+
+        /// Schedules the window to render another frame.
+        fn update(self: Pin<&mut QQuickWindow>);
+
+        /// Returns the width of the window.
+        fn width(self: &QQuickWindow) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_create_texture_from_image"]
+        fn qquickwindowCreateTextureFromImage(
+            window: &QQuickWindow,
+            image: &QImage,
+        ) -> UniquePtr<QSGTexture>;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_set_scene_graph_backend"]
+        fn qquickwindowSetSceneGraphBackend(backend: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_scene_graph_backend"]
+        fn qquickwindowSceneGraphBackend() -> QString;
+    }
+}
+
+use crate::{QImage, QSGTexture, QString};
+use cxx::UniquePtr;
+
+pub use ffi::QQuickWindow;
+
+impl QQuickWindow {
+    /// Creates a new QSGTexture from the supplied image.
+    ///
+    /// This must only be called from the scene graph render thread,
+    /// for example from within update_paint_node of a QQuickItem.
+    ///
+    /// A nullptr is returned if the image has a zero width or height, or if the scene graph has not been initialized.
+    pub fn create_texture_from_image(&self, image: &QImage) -> UniquePtr<QSGTexture> {
+        ffi::qquickwindow_create_texture_from_image(self, image)
+    }
+
+    /// Returns the requested Qt Quick scene graph backend.
+    pub fn scene_graph_backend() -> QString {
+        ffi::qquickwindow_scene_graph_backend()
+    }
+
+    /// Requests the specified Qt Quick scene graph backend, such as `software`.
+    ///
+    /// This must be called before any QQuickWindow is created.
+    pub fn set_scene_graph_backend(backend: &QString) {
+        ffi::qquickwindow_set_scene_graph_backend(backend);
+    }
+}
@@ -0,0 +1,119 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsggeometry.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSGGeometryDrawingMode
+qsggeometryDrawingMode(const QSGGeometry& geometry)
+{
+  // The drawing mode is stored as an unsigned int for compatibility with GL
+  return static_cast<QSGGeometryDrawingMode>(geometry.drawingMode());
+}
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewColoredPoint2D(::std::int32_t vertexCount,
+                             ::std::int32_t indexCount)
+{
+  return ::std::make_unique<QSGGeometry>(
+    QSGGeometry::defaultAttributes_ColoredPoint2D(),
+    static_cast<int>(vertexCount),
+    static_cast<int>(indexCount));
+}
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewPoint2D(::std::int32_t vertexCount, ::std::int32_t indexCount)
+{
+  return ::std::make_unique<QSGGeometry>(
+    QSGGeometry::defaultAttributes_Point2D(),
+    static_cast<int>(vertexCount),
+    static_cast<int>(indexCount));
+}
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewTexturedPoint2D(::std::int32_t vertexCount,
+                              ::std::int32_t indexCount)
+{
+  return ::std::make_unique<QSGGeometry>(
+    QSGGeometry::defaultAttributes_TexturedPoint2D(),
+    static_cast<int>(vertexCount),
+    static_cast<int>(indexCount));
+}
+
+void
+qsggeometrySetDrawingMode(QSGGeometry& geometry, QSGGeometryDrawingMode mode)
+{
+  geometry.setDrawingMode(static_cast<unsigned int>(mode));
+}
+
+void
+qsggeometrySetIndex(QSGGeometry& geometry,
+                    ::std::int32_t index,
+                    ::std::uint16_t value)
+{
+  // The index type of a geometry created with the default attributes is
+  // unsigned short
+  geometry.indexDataAsUShort()[index] = value;
+}
+
+void
+qsggeometrySetVertexColoredPoint2D(QSGGeometry& geometry,
+                                   ::std::int32_t index,
+                                   float x,
+                                   float y,
+                                   const QColor& color)
+{
+  geometry.vertexDataAsColoredPoint2D()[index].set(
+    x,
+    y,
+    static_cast<uchar>(color.red()),
+    static_cast<uchar>(color.green()),
+    static_cast<uchar>(color.blue()),
+    static_cast<uchar>(color.alpha()));
+}
+
+void
+qsggeometrySetVertexPoint2D(QSGGeometry& geometry,
+                            ::std::int32_t index,
+                            float x,
+                            float y)
+{
+  geometry.vertexDataAsPoint2D()[index].set(x, y);
+}
+
+void
+qsggeometrySetVertexTexturedPoint2D(QSGGeometry& geometry,
+                                    ::std::int32_t index,
+                                    float x,
+                                    float y,
+                                    float tx,
+                                    float ty)
+{
+  geometry.vertexDataAsTexturedPoint2D()[index].set(x, y, tx, ty);
+}
+
+::std::int32_t
+qsggeometrySizeOfColoredPoint2D()
+{
+  return static_cast<::std::int32_t>(sizeof(QSGGeometry::ColoredPoint2D));
+}
+
+::std::int32_t
+qsggeometrySizeOfPoint2D()
+{
+  return static_cast<::std::int32_t>(sizeof(QSGGeometry::Point2D));
+}
+
+::std::int32_t
+qsggeometrySizeOfTexturedPoint2D()
+{
+  return static_cast<::std::int32_t>(sizeof(QSGGeometry::TexturedPoint2D));
+}
+
+}
+}
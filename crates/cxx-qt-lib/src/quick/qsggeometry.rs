@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the drawing mode, or primitive topology, of the geometry.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGGeometryDrawingMode {
+        /// Each vertex is drawn as a point.
+        DrawPoints = 0x0000,
+        /// Each pair of vertices is drawn as a line.
+        DrawLines = 0x0001,
+        /// The vertices are drawn as a closed loop of lines.
+        DrawLineLoop = 0x0002,
+        /// The vertices are drawn as a connected strip of lines.
+        DrawLineStrip = 0x0003,
+        /// Each set of three vertices is drawn as a triangle.
+        DrawTriangles = 0x0004,
+        /// The vertices are drawn as a connected strip of triangles.
+        DrawTriangleStrip = 0x0005,
+        /// The vertices are drawn as a fan of triangles.
+        DrawTriangleFan = 0x0006,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsggeometry.h");
+        type QSGGeometryDrawingMode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+
+        /// The QSGGeometry class provides low-level storage for graphics primitives in the scene graph.
+        ///
+        /// A geometry is created with one of the default attribute sets,
+        /// which then defines the set_vertex method that can be used to fill in the vertex data.
+        type QSGGeometry;
+
+        /// Resizes the vertex and index data of this geometry object to fit vertex_count vertices and index_count indices.
+        ///
+        /// Vertex and index data will be invalidated after this call
+        /// and the caller must mark the associated geometry node as dirty.
+        fn allocate(self: Pin<&mut QSGGeometry>, vertex_count: i32, index_count: i32);
+
+        /// Returns the number of indices in this geometry object.
+        #[rust_name = "index_count"]
+        fn indexCount(self: &QSGGeometry) -> i32;
+
+        /// Gets the current line or point width to be used for this geometry.
+        #[rust_name = "line_width"]
+        fn lineWidth(self: &QSGGeometry) -> f32;
+
+        /// Mark that the vertices and indices in this geometry objects have changed.
+        ///
+        /// The geometry node also needs to be marked as dirty with
+        /// [QSGNodeDirtyStateBit::DirtyGeometry](crate::QSGNodeDirtyStateBit::DirtyGeometry).
+        #[rust_name = "mark_index_data_dirty"]
+        fn markIndexDataDirty(self: Pin<&mut QSGGeometry>);
+
+        /// Mark that the vertices in this geometry has changed and must be uploaded again.
+        ///
+        /// The geometry node also needs to be marked as dirty with
+        /// [QSGNodeDirtyStateBit::DirtyGeometry](crate::QSGNodeDirtyStateBit::DirtyGeometry).
+        #[rust_name = "mark_vertex_data_dirty"]
+        fn markVertexDataDirty(self: Pin<&mut QSGGeometry>);
+
+        /// Sets the line or point width to be used for this geometry.
+        ///
+        /// This is only supported for a width of 1 with some graphics APIs.
+        #[rust_name = "set_line_width"]
+        fn setLineWidth(self: Pin<&mut QSGGeometry>, width: f32);
+
+        /// Returns the byte size of each vertex in the geometry.
+        #[rust_name = "size_of_vertex"]
+        fn sizeOfVertex(self: &QSGGeometry) -> i32;
+
+        /// Returns the number of vertices in this geometry object.
+        #[rust_name = "vertex_count"]
+        fn vertexCount(self: &QSGGeometry) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_drawing_mode"]
+        fn qsggeometryDrawingMode(geometry: &QSGGeometry) -> QSGGeometryDrawingMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_new_colored_point_2d"]
+        fn qsggeometryNewColoredPoint2D(
+            vertex_count: i32,
+            index_count: i32,
+        ) -> UniquePtr<QSGGeometry>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_new_point_2d"]
+        fn qsggeometryNewPoint2D(vertex_count: i32, index_count: i32) -> UniquePtr<QSGGeometry>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_new_textured_point_2d"]
+        fn qsggeometryNewTexturedPoint2D(
+            vertex_count: i32,
+            index_count: i32,
+        ) -> UniquePtr<QSGGeometry>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_drawing_mode"]
+        fn qsggeometrySetDrawingMode(geometry: Pin<&mut QSGGeometry>, mode: QSGGeometryDrawingMode);
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_index"]
+        fn qsggeometrySetIndex(geometry: Pin<&mut QSGGeometry>, index: i32, value: u16);
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_vertex_colored_point_2d"]
+        fn qsggeometrySetVertexColoredPoint2D(
+            geometry: Pin<&mut QSGGeometry>,
+            index: i32,
+            x: f32,
+            y: f32,
+            color: &QColor,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_vertex_point_2d"]
+        fn qsggeometrySetVertexPoint2D(geometry: Pin<&mut QSGGeometry>, index: i32, x: f32, y: f32);
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_vertex_textured_point_2d"]
+        fn qsggeometrySetVertexTexturedPoint2D(
+            geometry: Pin<&mut QSGGeometry>,
+            index: i32,
+            x: f32,
+            y: f32,
+            tx: f32,
+            ty: f32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_size_of_point_2d"]
+        fn qsggeometrySizeOfPoint2D() -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_size_of_textured_point_2d"]
+        fn qsggeometrySizeOfTexturedPoint2D() -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_size_of_colored_point_2d"]
+        fn qsggeometrySizeOfColoredPoint2D() -> i32;
+    }
+
+    // QSGGeometry has a virtual destructor and owns its vertex data so it needs to be used via a pointer
+    impl UniquePtr<QSGGeometry> {}
+}
+
+use crate::QColor;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::{QSGGeometry, QSGGeometryDrawingMode};
+
+impl QSGGeometry {
+    /// Returns the drawing mode of this geometry, the default is [QSGGeometryDrawingMode::DrawTriangleStrip].
+    pub fn drawing_mode(&self) -> QSGGeometryDrawingMode {
+        ffi::qsggeometry_drawing_mode(self)
+    }
+
+    /// Constructs a geometry where each vertex is a 2D position and a color.
+    ///
+    /// The vertices are set with [QSGGeometry::set_vertex_colored_point_2d].
+    pub fn new_colored_point_2d(vertex_count: i32, index_count: i32) -> UniquePtr<Self> {
+        ffi::qsggeometry_new_colored_point_2d(vertex_count, index_count)
+    }
+
+    /// Constructs a geometry where each vertex is a 2D position.
+    ///
+    /// The vertices are set with [QSGGeometry::set_vertex_point_2d].
+    pub fn new_point_2d(vertex_count: i32, index_count: i32) -> UniquePtr<Self> {
+        ffi::qsggeometry_new_point_2d(vertex_count, index_count)
+    }
+
+    /// Constructs a geometry where each vertex is a 2D position and a texture coordinate.
+    ///
+    /// The vertices are set with [QSGGeometry::set_vertex_textured_point_2d].
+    pub fn new_textured_point_2d(vertex_count: i32, index_count: i32) -> UniquePtr<Self> {
+        ffi::qsggeometry_new_textured_point_2d(vertex_count, index_count)
+    }
+
+    /// Sets the drawing mode to be used for this geometry.
+    pub fn set_drawing_mode(self: Pin<&mut Self>, mode: QSGGeometryDrawingMode) {
+        ffi::qsggeometry_set_drawing_mode(self, mode);
+    }
+
+    /// Sets the index at the given position in the index data, which is stored as unsigned shorts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of range of [QSGGeometry::index_count].
+    pub fn set_index(self: Pin<&mut Self>, position: i32, value: u16) {
+        assert!(
+            (0..self.index_count()).contains(&position),
+            "index position out of range"
+        );
+        ffi::qsggeometry_set_index(self, position, value);
+    }
+
+    /// Sets the vertex at the given index of a geometry created with [QSGGeometry::new_colored_point_2d].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range or the geometry does not have a colored point 2D layout.
+    pub fn set_vertex_colored_point_2d(
+        self: Pin<&mut Self>,
+        index: i32,
+        x: f32,
+        y: f32,
+        color: &QColor,
+    ) {
+        self.assert_vertex(index, ffi::qsggeometry_size_of_colored_point_2d());
+        ffi::qsggeometry_set_vertex_colored_point_2d(self, index, x, y, color);
+    }
+
+    /// Sets the vertex at the given index of a geometry created with [QSGGeometry::new_point_2d].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range or the geometry does not have a point 2D layout.
+    pub fn set_vertex_point_2d(self: Pin<&mut Self>, index: i32, x: f32, y: f32) {
+        self.assert_vertex(index, ffi::qsggeometry_size_of_point_2d());
+        ffi::qsggeometry_set_vertex_point_2d(self, index, x, y);
+    }
+
+    /// Sets the vertex at the given index of a geometry created with [QSGGeometry::new_textured_point_2d].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range or the geometry does not have a textured point 2D layout.
+    pub fn set_vertex_textured_point_2d(
+        self: Pin<&mut Self>,
+        index: i32,
+        x: f32,
+        y: f32,
+        tx: f32,
+        ty: f32,
+    ) {
+        self.assert_vertex(index, ffi::qsggeometry_size_of_textured_point_2d());
+        ffi::qsggeometry_set_vertex_textured_point_2d(self, index, x, y, tx, ty);
+    }
+
+    fn assert_vertex(&self, index: i32, size_of_vertex: i32) {
+        assert_eq!(
+            self.size_of_vertex(),
+            size_of_vertex,
+            "vertex layout does not match the geometry"
+        );
+        assert!(
+            (0..self.vertex_count()).contains(&index),
+            "vertex index out of range"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsgmaterial.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSGFlatColorMaterial>
+qsgflatcolormaterialNew()
+{
+  return ::std::make_unique<QSGFlatColorMaterial>();
+}
+
+::std::unique_ptr<QSGTextureMaterial>
+qsgtexturematerialNew()
+{
+  return ::std::make_unique<QSGTextureMaterial>();
+}
+
+}
+}
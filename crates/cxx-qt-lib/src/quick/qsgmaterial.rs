@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgtexture.h");
+        type QSGTextureFiltering = crate::QSGTextureFiltering;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        type QSGTexture = crate::QSGTexture;
+
+        include!("cxx-qt-lib/qsgmaterial.h");
+        /// The QSGMaterial class is the base class for materials, which define how the geometry of a node is filled.
+        type QSGMaterial;
+
+        /// The QSGFlatColorMaterial class provides a convenient way of rendering solid colored geometry in the scene graph.
+        type QSGFlatColorMaterial;
+
+        /// Returns this flat color material's color.
+        fn color(self: &QSGFlatColorMaterial) -> &QColor;
+
+        /// Sets this flat color material's color to color.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QSGFlatColorMaterial>, color: &QColor);
+
+        /// The QSGTextureMaterial class provides a convenient way of rendering textured geometry in the scene graph.
+        ///
+        /// The geometry to be rendered with a texture material
+        /// should be created with [QSGGeometry::new_textured_point_2d](crate::QSGGeometry::new_textured_point_2d).
+        type QSGTextureMaterial;
+
+        /// Returns this texture material's filtering mode.
+        fn filtering(self: &QSGTextureMaterial) -> QSGTextureFiltering;
+
+        /// Sets the filtering to filtering.
+        #[rust_name = "set_filtering"]
+        fn setFiltering(self: Pin<&mut QSGTextureMaterial>, filtering: QSGTextureFiltering);
+
+        #[doc(hidden)]
+        #[rust_name = "set_texture_raw"]
+        unsafe fn setTexture(self: Pin<&mut QSGTextureMaterial>, texture: *mut QSGTexture);
+
+        /// Returns this texture material's texture, this is a nullptr if no texture has been set.
+        fn texture(self: &QSGTextureMaterial) -> *mut QSGTexture;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsgflatcolormaterial_new"]
+        fn qsgflatcolormaterialNew() -> UniquePtr<QSGFlatColorMaterial>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsgtexturematerial_new"]
+        fn qsgtexturematerialNew() -> UniquePtr<QSGTextureMaterial>;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgmaterialUpcast"]
+        unsafe fn qsgflatcolormaterial_upcast(
+            material: *mut QSGFlatColorMaterial,
+        ) -> *mut QSGMaterial;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgmaterialUpcast"]
+        unsafe fn qsgtexturematerial_upcast(material: *mut QSGTextureMaterial) -> *mut QSGMaterial;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgmaterialDowncast"]
+        unsafe fn qsgflatcolormaterial_downcast(
+            material: *mut QSGMaterial,
+        ) -> *mut QSGFlatColorMaterial;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgmaterialDowncast"]
+        unsafe fn qsgtexturematerial_downcast(
+            material: *mut QSGMaterial,
+        ) -> *mut QSGTextureMaterial;
+    }
+
+    // Materials are polymorphic with a virtual destructor so they need to be used via a pointer
+    impl UniquePtr<QSGMaterial> {}
+    impl UniquePtr<QSGFlatColorMaterial> {}
+    impl UniquePtr<QSGTextureMaterial> {}
+}
+
+use crate::QSGTexture;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::{QSGFlatColorMaterial, QSGMaterial, QSGTextureMaterial};
+
+impl QSGFlatColorMaterial {
+    /// Converts the material into a QSGMaterial, so that it can be set on a geometry node.
+    pub fn into_qsgmaterial(material: UniquePtr<Self>) -> UniquePtr<QSGMaterial> {
+        // SAFETY: the pointer is valid or a nullptr, as all materials have a virtual destructor
+        // the returned UniquePtr can delete the material via the base class.
+        unsafe { UniquePtr::from_raw(ffi::qsgflatcolormaterial_upcast(material.into_raw())) }
+    }
+
+    /// Casts a pointer to a QSGMaterial, such as the material of a geometry node, into this material type.
+    ///
+    /// # Safety
+    ///
+    /// The material must be a nullptr or a valid pointer to an instance of this material type.
+    pub unsafe fn from_qsgmaterial_ptr(material: *mut QSGMaterial) -> *mut Self {
+        ffi::qsgflatcolormaterial_downcast(material)
+    }
+
+    /// Constructs a new flat color material, the default color is black.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsgflatcolormaterial_new()
+    }
+}
+
+impl QSGTextureMaterial {
+    /// Converts the material into a QSGMaterial, so that it can be set on a geometry node.
+    pub fn into_qsgmaterial(material: UniquePtr<Self>) -> UniquePtr<QSGMaterial> {
+        // SAFETY: the pointer is valid or a nullptr, as all materials have a virtual destructor
+        // the returned UniquePtr can delete the material via the base class.
+        unsafe { UniquePtr::from_raw(ffi::qsgtexturematerial_upcast(material.into_raw())) }
+    }
+
+    /// Casts a pointer to a QSGMaterial, such as the material of a geometry node, into this material type.
+    ///
+    /// # Safety
+    ///
+    /// The material must be a nullptr or a valid pointer to an instance of this material type.
+    pub unsafe fn from_qsgmaterial_ptr(material: *mut QSGMaterial) -> *mut Self {
+        ffi::qsgtexturematerial_downcast(material)
+    }
+
+    /// Constructs a new texture material, a texture needs to be set before it is rendered.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsgtexturematerial_new()
+    }
+
+    /// Sets the texture of this material.
+    ///
+    /// # Safety
+    ///
+    /// The material does not take ownership of the texture,
+    /// so the texture must be a valid pointer which outlives the material, or a nullptr.
+    pub unsafe fn set_texture(self: Pin<&mut Self>, texture: *mut QSGTexture) {
+        self.set_texture_raw(texture);
+    }
+}
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsgnode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsgnodeMarkDirty(QSGNode& node, QSGNodeDirtyStateBit bits)
+{
+  node.markDirty(QSGNode::DirtyState(bits));
+}
+
+::std::unique_ptr<QSGNode>
+qsgnodeNew()
+{
+  return ::std::make_unique<QSGNode>();
+}
+
+void
+qsgnodeRemoveAllChildNodesAndDelete(QSGNode& node)
+{
+  while (QSGNode* child = node.firstChild()) {
+    node.removeChildNode(child);
+    if (child->flags().testFlag(QSGNode::OwnedByParent)) {
+      delete child;
+    }
+  }
+}
+
+::std::unique_ptr<QSGGeometryNode>
+qsggeometrynodeNew()
+{
+  return ::std::make_unique<QSGGeometryNode>();
+}
+
+::std::unique_ptr<QSGSimpleRectNode>
+qsgsimplerectnodeNew(const QRectF& rect, const QColor& color)
+{
+  return ::std::make_unique<QSGSimpleRectNode>(rect, color);
+}
+
+}
+}
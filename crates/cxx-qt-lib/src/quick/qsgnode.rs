@@ -0,0 +1,297 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Flags which control the ownership and behaviour of a node.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGNodeFlag {
+        /// The node is owned by its parent and will be deleted when the parent is deleted.
+        OwnedByParent = 0x0001,
+        /// The node's virtual preprocess function will be called before rendering starts.
+        UsePreprocess = 0x0002,
+        /// Only valid for geometry nodes, the node owns its geometry and deletes it when it is destroyed.
+        OwnsGeometry = 0x00010000,
+        /// Only valid for geometry nodes, the node owns its material and deletes it when it is destroyed.
+        OwnsMaterial = 0x00020000,
+        /// Only valid for geometry nodes, the node owns its opaque material and deletes it when it is destroyed.
+        OwnsOpaqueMaterial = 0x00040000,
+    }
+
+    /// Used in [QSGNode::mark_dirty] to indicate what has changed in the node.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGNodeDirtyStateBit {
+        /// The matrix in a transform node has changed.
+        DirtyMatrix = 0x0100,
+        /// A node was added.
+        DirtyNodeAdded = 0x0400,
+        /// A node was removed.
+        DirtyNodeRemoved = 0x0800,
+        /// The geometry in a geometry node has changed.
+        DirtyGeometry = 0x1000,
+        /// The material in a geometry node has changed.
+        DirtyMaterial = 0x2000,
+        /// The opacity of an opacity node has changed.
+        DirtyOpacity = 0x4000,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgnode.h");
+        type QSGNodeFlag;
+        type QSGNodeDirtyStateBit;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsggeometry.h");
+        type QSGGeometry = crate::QSGGeometry;
+        include!("cxx-qt-lib/qsgmaterial.h");
+        type QSGMaterial = crate::QSGMaterial;
+
+        /// The QSGNode class is the base class for all nodes in the scene graph.
+        ///
+        /// A node without geometry can be used as a container for other nodes.
+        type QSGNode;
+
+        #[doc(hidden)]
+        #[rust_name = "append_child_node_raw"]
+        unsafe fn appendChildNode(self: Pin<&mut QSGNode>, node: *mut QSGNode);
+
+        /// Returns the number of child nodes.
+        #[rust_name = "child_count"]
+        fn childCount(self: &QSGNode) -> i32;
+
+        /// Returns the child at index i, this is a nullptr if the index is out of range.
+        #[rust_name = "child_at_index"]
+        fn childAtIndex(self: &QSGNode, i: i32) -> *mut QSGNode;
+
+        /// Returns the first child of this node, this is a nullptr if there are no children.
+        #[rust_name = "first_child"]
+        fn firstChild(self: &QSGNode) -> *mut QSGNode;
+
+        /// Returns the parent node of this node, this is a nullptr if the node has no parent.
+        fn parent(self: &QSGNode) -> *mut QSGNode;
+
+        /// Removes all child nodes from this node's list of children.
+        ///
+        /// Children which have the [QSGNodeFlag::OwnedByParent] flag set are not deleted by this,
+        /// use [QSGNode::remove_all_child_nodes_and_delete] instead.
+        #[rust_name = "remove_all_child_nodes"]
+        fn removeAllChildNodes(self: Pin<&mut QSGNode>);
+
+        /// Sets the flag for this node to enabled.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QSGNode>, flag: QSGNodeFlag, enabled: bool);
+
+        /// The QSGGeometryNode class is used for all rendered content in the scene graph.
+        ///
+        /// The geometry node has a geometry and a material, the geometry defines the mesh
+        /// and the material defines how the pixels of the mesh are filled.
+        type QSGGeometryNode;
+
+        /// Returns this node's geometry, this is a nullptr if no geometry has been set.
+        fn geometry(self: Pin<&mut QSGGeometryNode>) -> *mut QSGGeometry;
+
+        /// Returns this node's material, this is a nullptr if no material has been set.
+        fn material(self: &QSGGeometryNode) -> *mut QSGMaterial;
+
+        #[doc(hidden)]
+        #[rust_name = "set_geometry_raw"]
+        unsafe fn setGeometry(self: Pin<&mut QSGGeometryNode>, geometry: *mut QSGGeometry);
+
+        #[doc(hidden)]
+        #[rust_name = "set_material_raw"]
+        unsafe fn setMaterial(self: Pin<&mut QSGGeometryNode>, material: *mut QSGMaterial);
+
+        /// The QSGSimpleRectNode class is a convenience class for drawing a solid filled rectangle.
+        type QSGSimpleRectNode;
+
+        /// Returns the color of this rectangle.
+        fn color(self: &QSGSimpleRectNode) -> QColor;
+
+        /// Returns the rectangle that this node covers.
+        fn rect(self: &QSGSimpleRectNode) -> QRectF;
+
+        /// Sets the color of this rectangle.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QSGSimpleRectNode>, color: &QColor);
+
+        /// Sets the rectangle of this node.
+        #[rust_name = "set_rect"]
+        fn setRect(self: Pin<&mut QSGSimpleRectNode>, rect: &QRectF);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_mark_dirty"]
+        fn qsgnodeMarkDirty(node: Pin<&mut QSGNode>, bits: QSGNodeDirtyStateBit);
+
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_new"]
+        fn qsgnodeNew() -> UniquePtr<QSGNode>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_remove_all_child_nodes_and_delete"]
+        fn qsgnodeRemoveAllChildNodesAndDelete(node: Pin<&mut QSGNode>);
+
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_new"]
+        fn qsggeometrynodeNew() -> UniquePtr<QSGGeometryNode>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsgsimplerectnode_new"]
+        fn qsgsimplerectnodeNew(rect: &QRectF, color: &QColor) -> UniquePtr<QSGSimpleRectNode>;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgnodeUpcast"]
+        unsafe fn qsggeometrynode_upcast(node: *mut QSGGeometryNode) -> *mut QSGNode;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgnodeUpcast"]
+        unsafe fn qsgsimplerectnode_upcast(node: *mut QSGSimpleRectNode) -> *mut QSGNode;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgnodeDowncast"]
+        unsafe fn qsggeometrynode_downcast(node: *mut QSGNode) -> *mut QSGGeometryNode;
+
+        #[doc(hidden)]
+        #[cxx_name = "qsgnodeDowncast"]
+        unsafe fn qsgsimplerectnode_downcast(node: *mut QSGNode) -> *mut QSGSimpleRectNode;
+    }
+
+    // Nodes are polymorphic with a virtual destructor so they need to be used via a pointer
+    impl UniquePtr<QSGNode> {}
+    impl UniquePtr<QSGGeometryNode> {}
+    impl UniquePtr<QSGSimpleRectNode> {}
+}
+
+use crate::{QColor, QRectF, QSGGeometry, QSGMaterial};
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::{QSGGeometryNode, QSGNode, QSGNodeDirtyStateBit, QSGNodeFlag, QSGSimpleRectNode};
+
+impl QSGNode {
+    /// Appends node to this node's list of children.
+    ///
+    /// The node is owned by this node if it has the [QSGNodeFlag::OwnedByParent] flag set,
+    /// which is the default for all nodes.
+    pub fn append_child_node(self: Pin<&mut Self>, node: UniquePtr<QSGNode>) {
+        if node.is_null() {
+            return;
+        }
+
+        // SAFETY: the node is a valid pointer, the ownership is transferred to the parent
+        unsafe { self.append_child_node_raw(node.into_raw()) }
+    }
+
+    /// Notifies all connected renderers that the node has dirty bits.
+    pub fn mark_dirty(self: Pin<&mut Self>, bits: QSGNodeDirtyStateBit) {
+        ffi::qsgnode_mark_dirty(self, bits);
+    }
+
+    /// Constructs a new node which can be used as a container for other nodes.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsgnode_new()
+    }
+
+    /// Removes all child nodes from this node's list of children,
+    /// deleting any children which have the [QSGNodeFlag::OwnedByParent] flag set.
+    pub fn remove_all_child_nodes_and_delete(self: Pin<&mut Self>) {
+        ffi::qsgnode_remove_all_child_nodes_and_delete(self);
+    }
+}
+
+macro_rules! impl_qsgnode_casts {
+    ($ty:ident, $upcast:ident, $downcast:ident) => {
+        impl $ty {
+            /// Converts the node into a QSGNode, so that it can be appended as a child
+            /// or returned from update_paint_node with [UniquePtr::into_raw].
+            pub fn into_qsgnode(node: UniquePtr<Self>) -> UniquePtr<QSGNode> {
+                // SAFETY: the pointer is valid or a nullptr, as all nodes have a virtual destructor
+                // the returned UniquePtr can delete the node via the base class.
+                unsafe { UniquePtr::from_raw(ffi::$upcast(node.into_raw())) }
+            }
+
+            /// Casts a pointer to a QSGNode, such as the old node given to update_paint_node, into this node type.
+            ///
+            /// # Safety
+            ///
+            /// The node must be a nullptr or a valid pointer to an instance of this node type.
+            pub unsafe fn from_qsgnode_ptr(node: *mut QSGNode) -> *mut Self {
+                ffi::$downcast(node)
+            }
+        }
+    };
+}
+
+impl_qsgnode_casts!(
+    QSGGeometryNode,
+    qsggeometrynode_upcast,
+    qsggeometrynode_downcast
+);
+impl_qsgnode_casts!(
+    QSGSimpleRectNode,
+    qsgsimplerectnode_upcast,
+    qsgsimplerectnode_downcast
+);
+
+impl QSGGeometryNode {
+    /// Constructs a new geometry node, the geometry and material need to be set before it is rendered.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsggeometrynode_new()
+    }
+
+    /// Sets the geometry of this node, the node takes ownership of the geometry.
+    pub fn set_geometry(mut self: Pin<&mut Self>, geometry: UniquePtr<QSGGeometry>) {
+        self.as_mut()
+            .as_qsgnode()
+            .set_flag(QSGNodeFlag::OwnsGeometry, true);
+        // SAFETY: the geometry is a valid pointer or a nullptr, the ownership is transferred to the node
+        unsafe { self.set_geometry_raw(geometry.into_raw()) }
+    }
+
+    /// Sets the material of this node, the node takes ownership of the material.
+    pub fn set_material(mut self: Pin<&mut Self>, material: UniquePtr<QSGMaterial>) {
+        self.as_mut()
+            .as_qsgnode()
+            .set_flag(QSGNodeFlag::OwnsMaterial, true);
+        // SAFETY: the material is a valid pointer or a nullptr, the ownership is transferred to the node
+        unsafe { self.set_material_raw(material.into_raw()) }
+    }
+
+    /// Returns this node as a QSGNode.
+    pub fn as_qsgnode(self: Pin<&mut Self>) -> Pin<&mut QSGNode> {
+        // SAFETY: the pointer is valid as it comes from a reference
+        unsafe {
+            let ptr = ffi::qsggeometrynode_upcast(self.get_unchecked_mut());
+            Pin::new_unchecked(&mut *ptr)
+        }
+    }
+}
+
+impl QSGSimpleRectNode {
+    /// Constructs a new rectangle node with the given rectangle and color.
+    pub fn new(rect: &QRectF, color: &QColor) -> UniquePtr<Self> {
+        ffi::qsgsimplerectnode_new(rect, color)
+    }
+
+    /// Returns this node as a QSGNode.
+    pub fn as_qsgnode(self: Pin<&mut Self>) -> Pin<&mut QSGNode> {
+        // SAFETY: the pointer is valid as it comes from a reference
+        unsafe {
+            let ptr = ffi::qsgsimplerectnode_upcast(self.get_unchecked_mut());
+            Pin::new_unchecked(&mut *ptr)
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies how sampling of texels should filter when texture coordinates are not pixel aligned.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGTextureFiltering {
+        /// Sampling returns the nearest texel.
+        Nearest = 1,
+        /// Sampling returns a linear interpolation of the neighboring texels.
+        Linear = 2,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgtexture.h");
+        type QSGTextureFiltering;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+
+        /// The QSGTexture class is the base class for textures used in the scene graph.
+        ///
+        /// Textures are created with [QQuickWindow::create_texture_from_image](crate::QQuickWindow::create_texture_from_image).
+        type QSGTexture;
+
+        /// Returns the sampling mode to be used for this texture.
+        fn filtering(self: &QSGTexture) -> QSGTextureFiltering;
+
+        /// Returns true if the texture data contains an alpha channel.
+        #[rust_name = "has_alpha_channel"]
+        fn hasAlphaChannel(self: &QSGTexture) -> bool;
+
+        /// Sets the sampling mode to be used for this texture.
+        #[rust_name = "set_filtering"]
+        fn setFiltering(self: Pin<&mut QSGTexture>, filter: QSGTextureFiltering);
+
+        /// Returns the size of the texture.
+        #[rust_name = "texture_size"]
+        fn textureSize(self: &QSGTexture) -> QSize;
+    }
+
+    // QSGTexture is a QObject with a virtual destructor so it needs to be used via a pointer
+    impl UniquePtr<QSGTexture> {}
+}
+
+pub use ffi::{QSGTexture, QSGTextureFiltering};
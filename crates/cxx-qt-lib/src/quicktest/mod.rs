@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod quicktestmain;
+pub use quicktestmain::{QuickTest, QuickTestOutcome, QuickTestResult, QuickTestResults};
@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/quicktestmain.h"
+
+#include <vector>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtQuick/QQuickWindow>
+#include <QtQuickTest/quicktest.h>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+quicktestRun(const QString& name,
+             const QString& source,
+             const QString& sceneGraphBackend,
+             const QStringList& arguments)
+{
+  // The backend needs to be set before any window is created
+  if (!sceneGraphBackend.isEmpty()) {
+    QQuickWindow::setSceneGraphBackend(sceneGraphBackend);
+  }
+
+  // quick_test_main expects the arguments as they are given to main,
+  // the QGuiApplication that it creates may keep pointers into them
+  // so they need to outlive the call.
+  QList<QByteArray> argumentsData;
+  for (const auto& argument : arguments) {
+    argumentsData.append(argument.toLocal8Bit());
+  }
+
+  ::std::vector<char*> argv;
+  for (auto& argument : argumentsData) {
+    argv.push_back(argument.data());
+  }
+  argv.push_back(nullptr);
+
+  int argc = static_cast<int>(argumentsData.size());
+  const QByteArray nameData = name.toLocal8Bit();
+  const QByteArray sourceData = source.toLocal8Bit();
+  return static_cast<::std::int32_t>(quick_test_main(
+    argc, argv.data(), nameData.constData(), sourceData.constData()));
+}
+
+}
+}
@@ -0,0 +1,388 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/quicktestmain.h");
+
+        #[doc(hidden)]
+        #[rust_name = "quicktest_run"]
+        fn quicktestRun(
+            name: &QString,
+            source: &QString,
+            scene_graph_backend: &QString,
+            arguments: &QStringList,
+        ) -> i32;
+    }
+}
+
+use crate::{QList, QString, QStringList};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Runs QML `TestCase` suites with [Qt Quick Test](https://doc.qt.io/qt-6/qtquicktest-index.html),
+/// so that they can be run from `cargo test` without a display or GPU.
+///
+/// By default the `offscreen` platform and the `software` scene graph backend are used.
+/// The QML modules registered with `CxxQtBuilder::qml_module` are available to the tests,
+/// note that the `link_qt_object_files` feature of `cxx-qt-build` is required so that the modules are linked into the test.
+///
+/// As Qt expects the application to be created on the main thread,
+/// the tests are best run from a test target with `harness = false`.
+///
+/// ```toml
+/// [[test]]
+/// name = "qml"
+/// harness = false
+/// ```
+///
+/// ```rust,ignore
+/// fn main() {
+///     cxx_qt_lib::QuickTest::new("my_crate", concat!(env!("CARGO_MANIFEST_DIR"), "/tests/qml"))
+///         .run()
+///         .report();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuickTest {
+    name: String,
+    source: PathBuf,
+    platform: String,
+    scene_graph_backend: String,
+    import_paths: Vec<PathBuf>,
+    functions: Vec<String>,
+    arguments: Vec<String>,
+}
+
+impl QuickTest {
+    /// Create a test run with the given name, which runs the given QML file,
+    /// or all of the `tst_*.qml` files in the given directory.
+    pub fn new(name: impl Into<String>, source: impl AsRef<Path>) -> Self {
+        Self {
+            name: name.into(),
+            source: source.as_ref().to_path_buf(),
+            platform: "offscreen".to_owned(),
+            scene_graph_backend: "software".to_owned(),
+            import_paths: vec![],
+            functions: vec![],
+            arguments: vec![],
+        }
+    }
+
+    /// Pass an extra argument to Qt Quick Test, such as `-maxwarnings 0`.
+    pub fn argument(mut self, argument: impl Into<String>) -> Self {
+        self.arguments.push(argument.into());
+        self
+    }
+
+    /// Only run the given test function, in the form `TestCaseName::test_function`.
+    ///
+    /// This can be called multiple times to run multiple functions.
+    pub fn function(mut self, function: impl Into<String>) -> Self {
+        self.functions.push(function.into());
+        self
+    }
+
+    /// Add a directory to the QML import path, in addition to the modules registered from Rust.
+    pub fn import_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.import_paths.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Use the given Qt platform plugin instead of `offscreen`, for example `xcb` to show the windows.
+    pub fn platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// Use the given Qt Quick scene graph backend instead of `software`,
+    /// an empty string uses the default backend of Qt.
+    pub fn scene_graph_backend(mut self, backend: impl Into<String>) -> Self {
+        self.scene_graph_backend = backend.into();
+        self
+    }
+
+    /// Run the tests, this blocks until all of the tests have completed.
+    ///
+    /// A QGuiApplication is created for the tests if there is not already an instance.
+    pub fn run(self) -> QuickTestResults {
+        // Qt Quick Test writes the results in the TAP format to a file, which is read afterwards
+        let output = TapFile::new();
+        let output_path = output.path.as_path();
+
+        let mut arguments = vec![
+            self.name.clone(),
+            "-platform".to_owned(),
+            self.platform.clone(),
+            // The QML modules from CxxQtBuilder are in the resource system,
+            // which is not in the default import path of Qt 5
+            "-import".to_owned(),
+            "qrc:/qt/qml".to_owned(),
+        ];
+        for import_path in &self.import_paths {
+            arguments.push("-import".to_owned());
+            arguments.push(import_path.to_string_lossy().into_owned());
+        }
+        arguments.push("-o".to_owned());
+        arguments.push(format!("{},tap", output_path.to_string_lossy()));
+        arguments.extend(self.arguments.iter().cloned());
+        arguments.extend(self.functions.iter().cloned());
+
+        let arguments = QStringList::from(&QList::from(
+            arguments.iter().map(QString::from).collect::<Vec<_>>(),
+        ));
+
+        let exit_code = ffi::quicktest_run(
+            &QString::from(&self.name),
+            &QString::from(self.source.to_string_lossy().as_ref()),
+            &QString::from(&self.scene_graph_backend),
+            &arguments,
+        );
+
+        let tap = std::fs::read_to_string(output_path).unwrap_or_default();
+        QuickTestResults::from_tap(&tap, exit_code)
+    }
+}
+
+/// A uniquely named file in the temporary directory for the TAP output of a run,
+/// which is removed when dropped, even if the run panics
+struct TapFile {
+    path: PathBuf,
+}
+
+impl TapFile {
+    fn new() -> Self {
+        static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            path: std::env::temp_dir().join(format!(
+                "cxx-qt-quicktest-{}-{}.tap",
+                std::process::id(),
+                RUN_COUNTER.fetch_add(1, Ordering::SeqCst)
+            )),
+        }
+    }
+}
+
+impl Drop for TapFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// The outcome of a single QML test function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuickTestOutcome {
+    /// The test passed.
+    Passed,
+    /// The test failed.
+    Failed,
+    /// The test was skipped with `skip()`.
+    Skipped,
+    /// The test failed as expected with `expectFail()`.
+    ExpectedFailure,
+}
+
+/// The result of a single QML test function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuickTestResult {
+    /// The name of the test, in the form `TestCaseName::test_function()`.
+    pub name: String,
+    /// The outcome of the test.
+    pub outcome: QuickTestOutcome,
+    /// The message of a failure or skip, this is empty if there is no message.
+    pub message: String,
+}
+
+/// The results of all of the QML test functions that have been run by [QuickTest::run].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuickTestResults {
+    /// The results of each test function, in the order that they were run.
+    pub results: Vec<QuickTestResult>,
+    /// The exit code of Qt Quick Test, this is the number of failures or a negative value for an error.
+    pub exit_code: i32,
+}
+
+impl QuickTestResults {
+    /// Returns an iterator over the tests which have failed.
+    pub fn failures(&self) -> impl Iterator<Item = &QuickTestResult> {
+        self.results
+            .iter()
+            .filter(|result| result.outcome == QuickTestOutcome::Failed)
+    }
+
+    /// Returns true if all of the tests passed, or were skipped, and Qt Quick Test did not report an error.
+    pub fn is_success(&self) -> bool {
+        self.exit_code == 0 && self.failures().next().is_none()
+    }
+
+    /// Print the result of each test in the same way as `cargo test`,
+    /// then panic if any of the tests failed so that the Rust test fails.
+    pub fn report(&self) {
+        println!("{self}");
+
+        if !self.is_success() {
+            let failures = self
+                .failures()
+                .map(|failure| format!("    {}", failure.name))
+                .collect::<Vec<_>>();
+            if failures.is_empty() {
+                panic!("Qt Quick Test failed with exit code {}", self.exit_code);
+            } else {
+                panic!("QML tests failed:\n{}", failures.join("\n"));
+            }
+        }
+    }
+
+    fn from_tap(output: &str, exit_code: i32) -> Self {
+        let mut results: Vec<QuickTestResult> = vec![];
+        let mut in_diagnostics = false;
+
+        for line in output.lines() {
+            let trimmed = line.trim();
+
+            // Diagnostics of a test are a YAML block between --- and ...
+            if in_diagnostics {
+                if trimmed == "..." {
+                    in_diagnostics = false;
+                } else if let Some(message) = trimmed.strip_prefix("message: ") {
+                    if let Some(result) = results.last_mut() {
+                        if result.message.is_empty() {
+                            result.message = message.trim_matches('"').to_owned();
+                        }
+                    }
+                }
+                continue;
+            }
+            if trimmed == "---" {
+                in_diagnostics = true;
+                continue;
+            }
+
+            let (passed, rest) = if let Some(rest) = line.strip_prefix("ok ") {
+                (true, rest)
+            } else if let Some(rest) = line.strip_prefix("not ok ") {
+                (false, rest)
+            } else {
+                continue;
+            };
+
+            // Skip the test number, then split any directive such as # SKIP from the name
+            let rest = rest.split_once(" - ").map(|(_, rest)| rest).unwrap_or(rest);
+            let (name, directive) = rest.split_once(" # ").unwrap_or((rest, ""));
+            let (outcome, message) = if let Some(message) = directive.strip_prefix("SKIP") {
+                (QuickTestOutcome::Skipped, message.trim())
+            } else if let Some(message) = directive.strip_prefix("TODO") {
+                (QuickTestOutcome::ExpectedFailure, message.trim())
+            } else if passed {
+                (QuickTestOutcome::Passed, "")
+            } else {
+                (QuickTestOutcome::Failed, "")
+            };
+
+            results.push(QuickTestResult {
+                name: name.trim().to_owned(),
+                outcome,
+                message: message.to_owned(),
+            });
+        }
+
+        Self { results, exit_code }
+    }
+}
+
+impl fmt::Display for QuickTestResults {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |outcome| {
+            self.results
+                .iter()
+                .filter(|result| result.outcome == outcome)
+                .count()
+        };
+
+        writeln!(f, "running {} QML tests", self.results.len())?;
+        for result in &self.results {
+            let outcome = match result.outcome {
+                QuickTestOutcome::Passed => "ok",
+                QuickTestOutcome::Failed => "FAILED",
+                QuickTestOutcome::Skipped => "ignored",
+                QuickTestOutcome::ExpectedFailure => "ok (expected failure)",
+            };
+            write!(f, "test {} ... {outcome}", result.name)?;
+            if !result.message.is_empty() {
+                write!(f, ", {}", result.message)?;
+            }
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "\ntest result: {}. {} passed; {} failed; {} ignored",
+            if self.is_success() { "ok" } else { "FAILED" },
+            count(QuickTestOutcome::Passed) + count(QuickTestOutcome::ExpectedFailure),
+            count(QuickTestOutcome::Failed),
+            count(QuickTestOutcome::Skipped)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_from_tap() {
+        let output = r#"TAP version 13
+# SceneGraphTests
+ok 1 - SceneGraphTests::initTestCase()
+not ok 2 - SceneGraphTests::test_render()
+  ---
+  type: QCOMPARE
+  message: Compared values are not the same
+  wanted: 255 (expected)
+  found: 0 (actual)
+  at: SceneGraphTests::test_render() (tst_scene_graph.qml:30)
+  ...
+ok 3 - SceneGraphTests::test_skipped() # SKIP not supported
+not ok 4 - SceneGraphTests::test_expected() # TODO known issue
+ok 5 - SceneGraphTests::cleanupTestCase()
+1..5
+"#;
+        let results = QuickTestResults::from_tap(output, 1);
+        assert_eq!(results.results.len(), 5);
+        assert_eq!(
+            results.results[1],
+            QuickTestResult {
+                name: "SceneGraphTests::test_render()".to_owned(),
+                outcome: QuickTestOutcome::Failed,
+                message: "Compared values are not the same".to_owned(),
+            }
+        );
+        assert_eq!(results.results[2].outcome, QuickTestOutcome::Skipped);
+        assert_eq!(results.results[2].message, "not supported");
+        assert_eq!(
+            results.results[3].outcome,
+            QuickTestOutcome::ExpectedFailure
+        );
+        assert_eq!(results.failures().count(), 1);
+        assert!(!results.is_success());
+    }
+
+    #[test]
+    fn results_success() {
+        let output = "ok 1 - Tests::initTestCase()\nok 2 - Tests::test_a()\n";
+        assert!(QuickTestResults::from_tap(output, 0).is_success());
+        // An error without any failed tests, such as a QML syntax error, is still a failure
+        assert!(!QuickTestResults::from_tap(output, -1).is_success());
+    }
+}
@@ -8,9 +8,9 @@
 //! The cxx-qt-macro crate provides the procedural attribute macros which are used with cxx-qt.
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemMod};
+use syn::{parse_macro_input, spanned::Spanned, ItemMod};
 
-use cxx_qt_gen::{write_rust, GeneratedRustBlocks, Parser};
+use cxx_qt_gen::{bridge_hash_check, write_rust, GeneratedRustBlocks, Parser, TypeRegistry};
 
 /// A procedural macro which generates a QObject for a struct inside a module.
 ///
@@ -118,9 +118,19 @@ pub fn qobject(_args: TokenStream, _input: TokenStream) -> TokenStream {
 
 // Take the module and C++ namespace and generate the rust code
 fn extract_and_generate(module: ItemMod) -> TokenStream {
-    Parser::from(module)
-        .and_then(|parser| GeneratedRustBlocks::from(&parser))
-        .map(|generated_rust| write_rust(&generated_rust))
+    let span = module.span();
+    // The types from other bridges in this crate are collected by cxx-qt-build into OUT_DIR,
+    // any #[cfg] attributes are passed through to the generated code for rustc to evaluate
+    Parser::from_with_type_registry(module, &TypeRegistry::from_out_dir())
+        .and_then(|parser| {
+            let mut tokens = write_rust(&GeneratedRustBlocks::from(&parser)?);
+            // Assert that cxx-qt-build generated the C++ code from the same bridge,
+            // or report that the bridge has not been given to cxx-qt-build
+            if let Some(check) = bridge_hash_check(&tokens, &parser.cxx_file_stem, span) {
+                tokens.extend(check);
+            }
+            Ok(tokens)
+        })
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
@@ -13,7 +13,7 @@ fn main() {
     qt_build_utils::setup_linker();
 
     let cpp_files = ["src/connection.cpp"];
-    let rust_bridges = ["src/connection.rs"];
+    let rust_bridges = ["src/connection.rs", "src/threading.rs"];
 
     for bridge in &rust_bridges {
         println!("cargo:rerun-if-changed={bridge}");
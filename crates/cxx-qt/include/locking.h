@@ -7,32 +7,253 @@
 
 #pragma once
 
+#include <condition_variable>
+#include <cstddef>
+#include <map>
 #include <memory>
 #include <mutex>
+#include <thread>
 
 namespace rust::cxxqt1 {
 
+// Whether const access to the Rust object takes a shared lock
+// or the same exclusive lock as mutable access
+enum class CxxQtLockingMode
+{
+  Exclusive,
+  Shared,
+};
+
+// A recursive shared/upgradable/exclusive mutex
+//
+// Property getters take a shared lock, as they do not run any user code,
+// so can never ask for the exclusive lock while holding the shared lock.
+//
+// Const invokables take an upgradable lock, which can be held by only one
+// thread at a time, alongside any number of shared locks. The thread holding
+// the upgradable lock can then take the exclusive lock once the other readers
+// have finished, so two threads can never wait for each other to upgrade.
+//
+// The lock is recursive so that signals can be emitted and then call slots
+// on the same object without deadlocks.
+class CxxQtSharedMutex final
+{
+public:
+  void lock()
+  {
+    const auto id = ::std::this_thread::get_id();
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    if (m_writerDepth > 0 && m_writer == id) {
+      m_writerDepth++;
+      return;
+    }
+
+    // Only the thread holding the upgradable lock can hold a lock while
+    // waiting, any other thread waits for the upgradable lock to be released
+    const auto upgrading = m_upgraderDepth > 0 && m_upgrader == id;
+    m_writersWaiting++;
+    m_condition.wait(guard, [&]() {
+      return m_writerDepth == 0 && (upgrading || m_upgraderDepth == 0) &&
+             m_readerCount == readerDepth(id);
+    });
+    m_writersWaiting--;
+
+    m_writer = id;
+    m_writerDepth = 1;
+  }
+
+  void unlock()
+  {
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    m_writerDepth--;
+    if (m_writerDepth == 0) {
+      m_writer = ::std::thread::id();
+      guard.unlock();
+      m_condition.notify_all();
+    }
+  }
+
+  void lock_shared()
+  {
+    const auto id = ::std::this_thread::get_id();
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    // Recursive locks are not blocked by waiting writers,
+    // as the writer could then be waiting on this thread
+    if (!holdsLock(id)) {
+      m_condition.wait(guard, [&]() {
+        return m_writerDepth == 0 && m_writersWaiting == 0;
+      });
+    }
+
+    m_readers[id]++;
+    m_readerCount++;
+  }
+
+  void unlock_shared()
+  {
+    const auto id = ::std::this_thread::get_id();
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    const auto reader = m_readers.find(id);
+    reader->second--;
+    if (reader->second == 0) {
+      m_readers.erase(reader);
+    }
+    m_readerCount--;
+    guard.unlock();
+    m_condition.notify_all();
+  }
+
+  void lock_upgrade()
+  {
+    const auto id = ::std::this_thread::get_id();
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    // The thread holding the exclusive lock is the only thread that could
+    // hold the upgradable lock, so it can always take it
+    const auto recursive = (m_upgraderDepth > 0 && m_upgrader == id) ||
+                           (m_writerDepth > 0 && m_writer == id);
+    if (!recursive) {
+      const auto reading = readerDepth(id) > 0;
+      m_condition.wait(guard, [&]() {
+        return m_writerDepth == 0 && m_upgraderDepth == 0 &&
+               (reading || m_writersWaiting == 0);
+      });
+    }
+
+    m_upgrader = id;
+    m_upgraderDepth++;
+  }
+
+  void unlock_upgrade()
+  {
+    ::std::unique_lock<::std::mutex> guard(m_mutex);
+    m_upgraderDepth--;
+    if (m_upgraderDepth == 0) {
+      m_upgrader = ::std::thread::id();
+      guard.unlock();
+      m_condition.notify_all();
+    }
+  }
+
+private:
+  bool holdsLock(::std::thread::id id) const
+  {
+    return (m_writerDepth > 0 && m_writer == id) ||
+           (m_upgraderDepth > 0 && m_upgrader == id) || readerDepth(id) > 0;
+  }
+
+  ::std::size_t readerDepth(::std::thread::id id) const
+  {
+    const auto reader = m_readers.find(id);
+    return reader == m_readers.end() ? 0 : reader->second;
+  }
+
+  ::std::mutex m_mutex;
+  ::std::condition_variable m_condition;
+  ::std::thread::id m_writer;
+  ::std::size_t m_writerDepth = 0;
+  ::std::size_t m_writersWaiting = 0;
+  ::std::thread::id m_upgrader;
+  ::std::size_t m_upgraderDepth = 0;
+  ::std::map<::std::thread::id, ::std::size_t> m_readers;
+  ::std::size_t m_readerCount = 0;
+};
+
+// The mutex guarding the Rust object of a QObject
+//
+// With CxxQtLockingMode::Exclusive this is a recursive mutex and every lock
+// is exclusive, otherwise a CxxQtSharedMutex is used.
+class CxxQtLockingMutex final
+{
+public:
+  explicit CxxQtLockingMutex(CxxQtLockingMode mode)
+    : m_shared(mode == CxxQtLockingMode::Shared
+                 ? ::std::make_unique<CxxQtSharedMutex>()
+                 : nullptr)
+  {
+  }
+
+  void lock()
+  {
+    if (m_shared) {
+      m_shared->lock();
+    } else {
+      m_exclusive.lock();
+    }
+  }
+
+  void unlock()
+  {
+    if (m_shared) {
+      m_shared->unlock();
+    } else {
+      m_exclusive.unlock();
+    }
+  }
+
+  void lock_shared()
+  {
+    if (m_shared) {
+      m_shared->lock_shared();
+    } else {
+      m_exclusive.lock();
+    }
+  }
+
+  void unlock_shared()
+  {
+    if (m_shared) {
+      m_shared->unlock_shared();
+    } else {
+      m_exclusive.unlock();
+    }
+  }
+
+  void lock_upgrade()
+  {
+    if (m_shared) {
+      m_shared->lock_upgrade();
+    } else {
+      m_exclusive.lock();
+    }
+  }
+
+  void unlock_upgrade()
+  {
+    if (m_shared) {
+      m_shared->unlock_upgrade();
+    } else {
+      m_exclusive.unlock();
+    }
+  }
+
+private:
+  ::std::recursive_mutex m_exclusive;
+  ::std::unique_ptr<CxxQtSharedMutex> m_shared;
+};
+
 class CxxQtLocking
 {
 public:
-  explicit CxxQtLocking()
-    : m_rustObjMutex(::std::make_shared<::std::recursive_mutex>())
+  explicit CxxQtLocking(CxxQtLockingMode mode = CxxQtLockingMode::Exclusive)
+    : m_rustObjMutex(::std::make_shared<CxxQtLockingMutex>(mode))
   {
   }
 
   virtual ~CxxQtLocking() = default;
 
 protected:
-  [[nodiscard]] ::std::lock_guard<::std::recursive_mutex> unsafeRustLock() const
+  [[nodiscard]] CxxQtLockingMutex& unsafeRustMutex() const
   {
-    return ::std::lock_guard<::std::recursive_mutex>(*m_rustObjMutex);
+    return *m_rustObjMutex;
   }
 
-  ::std::shared_ptr<::std::recursive_mutex> m_rustObjMutex;
+  ::std::shared_ptr<CxxQtLockingMutex> m_rustObjMutex;
 
-  // Friend MaybeLockGuard so that it can use unsafeRustLock()
+  // Friend the lock guards so that they can use unsafeRustMutex()
   template<typename T, typename D>
   friend struct MaybeLockGuard;
+  template<typename T, typename D>
+  friend struct MaybeSharedLockGuard;
 };
 
 }
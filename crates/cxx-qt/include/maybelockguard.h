@@ -24,17 +24,75 @@ struct MaybeLockGuard
 };
 
 // Create a lock guard for types that implement CxxQtLocking
+//
+// Const access takes an upgradable lock and mutable access takes an exclusive
+// lock, with CxxQtLockingMode::Exclusive every lock is an exclusive lock
 template<typename T>
 struct MaybeLockGuard<T,
                       ::std::enable_if_t<::std::is_base_of_v<CxxQtLocking, T>>>
 {
   MaybeLockGuard(const CxxQtLocking& locking)
-    : m_lock(locking.unsafeRustLock())
+    : m_mutex(locking.unsafeRustMutex())
+    , m_upgradable(true)
+  {
+    m_mutex.lock_upgrade();
+  }
+
+  MaybeLockGuard(CxxQtLocking& locking)
+    : m_mutex(locking.unsafeRustMutex())
+    , m_upgradable(false)
+  {
+    m_mutex.lock();
+  }
+
+  ~MaybeLockGuard()
   {
+    if (m_upgradable) {
+      m_mutex.unlock_upgrade();
+    } else {
+      m_mutex.unlock();
+    }
   }
 
+  MaybeLockGuard(const MaybeLockGuard&) = delete;
+  MaybeLockGuard& operator=(const MaybeLockGuard&) = delete;
+
+private:
+  CxxQtLockingMutex& m_mutex;
+  const bool m_upgradable;
+};
+
+// An empty implementation of MaybeSharedLockGuard
+//
+// This means for types that do not implement CxxQtLocking we do nothing
+template<typename T, typename Derived = void>
+struct MaybeSharedLockGuard
+{
+  MaybeSharedLockGuard(const T&) {}
+};
+
+// Create a shared lock guard for types that implement CxxQtLocking
+//
+// This is only used for const access which does not run any user code,
+// such as property getters, as a shared lock cannot be upgraded
+template<typename T>
+struct MaybeSharedLockGuard<
+  T,
+  ::std::enable_if_t<::std::is_base_of_v<CxxQtLocking, T>>>
+{
+  MaybeSharedLockGuard(const CxxQtLocking& locking)
+    : m_mutex(locking.unsafeRustMutex())
+  {
+    m_mutex.lock_shared();
+  }
+
+  ~MaybeSharedLockGuard() { m_mutex.unlock_shared(); }
+
+  MaybeSharedLockGuard(const MaybeSharedLockGuard&) = delete;
+  MaybeSharedLockGuard& operator=(const MaybeSharedLockGuard&) = delete;
+
 private:
-  ::std::lock_guard<::std::recursive_mutex> m_lock;
+  CxxQtLockingMutex& m_mutex;
 };
 
 }
@@ -6,16 +6,19 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
 #include <shared_mutex>
-#include <stdexcept>
 
 #include <QtCore/QDebug>
 #include <QtCore/QMetaObject>
+#include <QtCore/QThread>
 
 #include "rust/cxx.h"
 
+#include <cxx-qt/locking.h>
+
 namespace rust {
 namespace cxxqt1 {
 
@@ -32,12 +35,20 @@ public:
   ::std::shared_mutex mutex;
 };
 
+// This is declared as a shared enum in the threading bridge of the cxx-qt crate
+enum class CxxQtThreadQueueStatus : ::std::uint8_t
+{
+  Queued = 0,
+  ObjectDestroyed = 1,
+  InvokeFailed = 2,
+};
+
 template<typename T>
 class CxxQtThread final
 {
 public:
   CxxQtThread(::std::shared_ptr<CxxQtGuardedPointer<T>> obj,
-              ::std::shared_ptr<::std::recursive_mutex> rustObjMutex)
+              ::std::shared_ptr<CxxQtLockingMutex> rustObjMutex)
     : m_obj(obj)
     , m_rustObjMutex(rustObjMutex)
   {
@@ -47,16 +58,29 @@ public:
   CxxQtThread(const CxxQtThread<T>& other) = default;
   CxxQtThread(CxxQtThread<T>&& other) = default;
 
+  bool isDestroyed() const
+  {
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return !m_obj->ptr;
+  }
+
+  bool isCurrentThread() const
+  {
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return m_obj->ptr && m_obj->ptr->thread() == QThread::currentThread();
+  }
+
   template<typename A>
-  void queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-             ::rust::Box<A> arg) const
+  CxxQtThreadQueueStatus queue(
+    ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+    ::rust::Box<A> arg) const
   {
     // Ensure that we can read the pointer and it's not being written to
     const auto guard = ::std::shared_lock(m_obj->mutex);
     if (!m_obj->ptr) {
-      throw ::std::runtime_error(
-        "Cannot queue function pointer as object has been destroyed");
-      return;
+      return CxxQtThreadQueueStatus::ObjectDestroyed;
     }
 
     // Construct the lambda
@@ -70,7 +94,7 @@ public:
       const auto guard = ::std::shared_lock(obj->mutex);
       if (obj->ptr) {
         // Ensure that the rustObj is locked
-        const ::std::lock_guard<::std::recursive_mutex> guardRustObj(
+        const ::std::lock_guard<CxxQtLockingMutex> guardRustObj(
           *rustObjMutex);
         func(*obj->ptr, ::std::move(arg));
       } else {
@@ -82,14 +106,15 @@ public:
     // Add the lambda to the queue
     if (!QMetaObject::invokeMethod(
           m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
-      throw ::std::runtime_error(
-        "Cannot queue function pointer as invokeMethod on object failed");
+      return CxxQtThreadQueueStatus::InvokeFailed;
     }
+
+    return CxxQtThreadQueueStatus::Queued;
   }
 
 private:
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
-  ::std::shared_ptr<::std::recursive_mutex> m_rustObjMutex;
+  ::std::shared_ptr<CxxQtLockingMutex> m_rustObjMutex;
 };
 
 template<typename T>
@@ -106,13 +131,27 @@ cxxQtThreadDrop(CxxQtThread<T>& cxxQtThread)
   cxxQtThread.~CxxQtThread<T>();
 }
 
+template<typename T>
+bool
+cxxQtThreadIsCurrentThread(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isCurrentThread();
+}
+
+template<typename T>
+bool
+cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isDestroyed();
+}
+
 template<typename A, typename T>
-void
+CxxQtThreadQueueStatus
 cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
                  ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
                  ::rust::Box<A> arg)
 {
-  cxxQtThread.queue(::std::move(func), ::std::move(arg));
+  return cxxQtThread.queue(::std::move(func), ::std::move(arg));
 }
 
 } // namespace cxxqt1
@@ -19,8 +19,11 @@ template<typename T>
 class CxxQtThreading : public CxxQtLocking
 {
 public:
-  explicit CxxQtThreading(T* obj)
-    : m_cxxQtThreadObj(::std::make_shared<CxxQtGuardedPointer<T>>(obj))
+  explicit CxxQtThreading(
+    T* obj,
+    CxxQtLockingMode mode = CxxQtLockingMode::Exclusive)
+    : CxxQtLocking(mode)
+    , m_cxxQtThreadObj(::std::make_shared<CxxQtGuardedPointer<T>>(obj))
   {
   }
 
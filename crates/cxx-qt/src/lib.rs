@@ -22,7 +22,9 @@ pub use cxx_qt_macro::qobject;
 
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
-pub use threading::CxxQtThread;
+#[doc(hidden)]
+pub use threading::CxxQtThreadQueueStatus;
+pub use threading::{CxxQtThread, CxxQtThreadError};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
 //
@@ -50,6 +52,8 @@ pub trait CxxQtType {
 ///
 /// Note that a recursive mutex is used internally, this allows for signals to be emitted and then call slots on the same object without deadlocks.
 ///
+/// By default all access takes an exclusive lock, implement [SharedLocking] to allow const access to take a shared lock.
+///
 /// # Safety
 ///
 /// This is a marker trait used to disable locking.
@@ -87,6 +91,47 @@ pub trait Locking {
     // empty
 }
 
+/// Types which implement the `SharedLocking` trait take a shared lock for const access in C++,
+/// instead of the exclusive lock used by default when [Locking] is enabled.
+///
+/// Property getters then take a shared lock, so they can run in parallel with each other and with
+/// `&self` invokables, which take an upgradable lock that only one thread can hold at a time.
+/// `Pin<&mut Self>` invokables, property setters, signal handlers and closures queued
+/// with a [CxxQtThread] take an exclusive lock.
+/// This stops read-only access, such as property reads from QML, waiting for long-running writers on other threads.
+///
+/// As the Rust struct can then be read from multiple threads at the same time it must be [Sync].
+///
+/// The lock is still recursive, so a `&self` invokable can call a `Pin<&mut Self>` method through C++,
+/// for example a slot of the same object. This upgrades to the exclusive lock once the property getters
+/// on other threads have finished, as no other thread can be waiting to upgrade at the same time.
+///
+/// # Example
+///
+/// ```rust
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         type MyStruct = super::MyStructRust;
+///     }
+///
+///     impl cxx_qt::SharedLocking for MyStruct {}
+/// }
+///
+/// #[derive(Default)]
+/// pub struct MyStructRust;
+///
+/// # // Note that we need a fake main function for doc tests to build.
+/// # fn main() {}
+/// ```
+pub trait SharedLocking: Locking + CxxQtType
+where
+    Self::Rust: Sync,
+{
+    // empty
+}
+
 /// This trait indicates that the object implements threading and has a method which returns a [CxxQtThread].
 ///
 /// The QObjects generated by CXX-Qt are neither [`Send`](https://doc.rust-lang.org/std/marker/trait.Send.html) nor [`Sync`](https://doc.rust-lang.org/std/marker/trait.Sync.html).
@@ -149,11 +194,17 @@ pub trait Threading: Locking + Sized {
     fn qt_thread(&self) -> CxxQtThread<Self>;
 
     #[doc(hidden)]
-    fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), cxx::Exception>
+    fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), CxxQtThreadError>
     where
         F: FnOnce(core::pin::Pin<&mut Self>),
         F: Send + 'static;
 
+    #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+
     #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &CxxQtThread<Self>) -> CxxQtThread<Self>;
 
@@ -364,6 +415,55 @@ where
     }
 }
 
+/// This trait can be implemented on a `#[qml_singleton]` to construct the singleton with a Rust factory.
+///
+/// By default QML creates a singleton with the default constructor of the QObject.
+/// When the trait is enabled in the bridge with `impl cxx_qt::QmlSingletonFactory for T {}`,
+/// CXX-Qt generates a static `create(QQmlEngine*, QJSEngine*)` function for QML instead,
+/// which constructs the inner Rust struct by calling [QmlSingletonFactory::create].
+///
+/// This allows for the singleton to be given state which is not available to a [Default]
+/// implementation, such as configuration or handles created in the `main` function.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # // FIXME: test doesn't link correctly on Windows
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qml_element]
+///         #[qml_singleton]
+///         type MySingleton = super::MySingletonRust;
+///     }
+///
+///     impl cxx_qt::QmlSingletonFactory for MySingleton {}
+/// }
+///
+/// pub struct MySingletonRust {
+///     database_url: String,
+/// }
+///
+/// static DATABASE_URL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+///
+/// impl cxx_qt::QmlSingletonFactory for qobject::MySingleton {
+///     fn create() -> MySingletonRust {
+///         MySingletonRust {
+///             database_url: DATABASE_URL.get().cloned().unwrap_or_default(),
+///         }
+///     }
+/// }
+///
+/// # // Note that we need a fake main function for doc tests to build.
+/// # fn main() {}
+/// ```
+pub trait QmlSingletonFactory: CxxQtType {
+    /// This function is called when a QML engine creates the singleton,
+    /// to construct the inner Rust struct of the CXX-Qt QObject.
+    fn create() -> <Self as CxxQtType>::Rust;
+}
+
 #[doc(hidden)]
 // Write the cxx-qt headers to the specified directory.
 pub fn write_headers(directory: impl AsRef<Path>) {
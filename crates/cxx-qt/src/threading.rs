@@ -3,11 +3,39 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, pin::Pin};
 use cxx::ExternType;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+};
 
 use crate::Threading;
 
+#[cxx::bridge]
+mod ffi {
+    /// The result of queueing a closure onto the Qt event loop from C++
+    #[namespace = "rust::cxxqt1"]
+    #[repr(u8)]
+    enum CxxQtThreadQueueStatus {
+        /// The closure was queued
+        Queued,
+        /// The QObject was destroyed so the closure was dropped
+        ObjectDestroyed,
+        /// QMetaObject::invokeMethod failed so the closure was dropped
+        InvokeFailed,
+    }
+
+    // The enum is defined in thread.h so that the C++ templates can use it
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/thread.h");
+        type CxxQtThreadQueueStatus;
+    }
+}
+
+pub use ffi::CxxQtThreadQueueStatus;
+
 /// A threading helper which is created from a QObject that implements [Threading].
 ///
 /// This allows for queueing closures onto the Qt event loop from a background thread
@@ -17,6 +45,9 @@ use crate::Threading;
 /// This closure will be executed on the thread the QObject lives in while holding a lock on the Rust object.
 /// Updating the QObject is then thread-safe.
 ///
+/// Closures are delivered to the thread that the QObject lives in when they run,
+/// so if the QObject is moved to another thread with `moveToThread` closures follow it to the new thread.
+///
 /// See the [Threading] example for more information.
 #[repr(C)]
 pub struct CxxQtThread<T>
@@ -69,15 +100,194 @@ impl<T> CxxQtThread<T>
 where
     T: Threading,
 {
+    /// Returns true if the QObject has been destroyed
+    ///
+    /// Once the QObject has been destroyed, any closures that are queued are dropped without being called.
+    /// Note that the QObject could be destroyed on its own thread immediately after this returns false.
+    pub fn is_destroyed(&self) -> bool {
+        T::is_destroyed(self)
+    }
+
     /// Queue the given closure onto the Qt event loop for this QObject
     ///
     /// The first argument of the closure is a pinned mutable reference to the QObject.
     /// With this parameter, you can then update the QObject to reflect any state changes that have occured in the background thread.
-    pub fn queue<F>(&self, f: F) -> Result<(), cxx::Exception>
+    ///
+    /// If the QObject is destroyed after the closure has been queued, the closure is dropped without being called.
+    /// Use [CxxQtThread::queue_with_notification] to find out whether the closure ran.
+    pub fn queue<F>(&self, f: F) -> Result<(), CxxQtThreadError>
     where
         F: FnOnce(Pin<&mut T>),
         F: Send + 'static,
     {
         T::queue(self, f)
     }
+
+    /// Queue the given closure onto the Qt event loop for this QObject and block until it has run,
+    /// returning the value from the closure.
+    ///
+    /// This is useful for background threads that need to read the current state of the QObject before continuing.
+    ///
+    /// If this is called from the thread that the QObject lives in, an error is returned
+    /// instead of waiting forever for the event loop that is blocked.
+    ///
+    /// # Panics
+    ///
+    /// If the closure panics, the panic is caught on the thread of the QObject
+    /// and resumed on the calling thread, in the same way as [std::thread::JoinHandle::join].
+    pub fn queue_blocking<F, R>(&self, f: F) -> Result<R, CxxQtThreadError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        if T::is_current_thread(self) {
+            return Err(CxxQtThreadError::WouldDeadlock);
+        }
+
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.queue(move |qobject| {
+            // A panic must not unwind into the Qt event loop, so send it to the caller instead
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(qobject)));
+            // The receiver is only dropped once the value has been received
+            let _ = sender.send(result);
+        })?;
+
+        // If the QObject is destroyed before the closure runs then the closure,
+        // and therefore the sender, is dropped without sending a value
+        match receiver.recv() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(payload)) => panic::resume_unwind(payload),
+            Err(_) => Err(CxxQtThreadError::ObjectDestroyed),
+        }
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject,
+    /// calling notify once the closure has either run or been dropped.
+    ///
+    /// notify is called with `Ok(())` on the thread of the QObject after the closure has run,
+    /// or with [CxxQtThreadError::ObjectDestroyed] if the QObject was destroyed before the closure could run.
+    ///
+    /// If the closure cannot be queued the error is returned and notify is not called.
+    pub fn queue_with_notification<F, N>(&self, f: F, notify: N) -> Result<(), CxxQtThreadError>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+        N: FnOnce(Result<(), CxxQtThreadError>),
+        N: Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(NotificationState {
+            notify: Some(notify),
+            queued: false,
+            dropped: false,
+        }));
+        let mut guard = NotificationGuard {
+            state: state.clone(),
+        };
+        self.queue(move |qobject| {
+            f(qobject);
+            guard.notify(Ok(()));
+        })?;
+
+        // The closure could have been dropped on the thread of the QObject
+        // before queue returned, in which case the notification is our responsibility
+        let notify = {
+            let mut state = state.lock().unwrap();
+            state.queued = true;
+            if state.dropped {
+                state.notify.take()
+            } else {
+                None
+            }
+        };
+        if let Some(notify) = notify {
+            notify(Err(CxxQtThreadError::ObjectDestroyed));
+        }
+
+        Ok(())
+    }
+}
+
+struct NotificationState<N> {
+    notify: Option<N>,
+    queued: bool,
+    dropped: bool,
+}
+
+/// Calls the notification when the queued closure has run or has been dropped without running
+struct NotificationGuard<N>
+where
+    N: FnOnce(Result<(), CxxQtThreadError>),
+{
+    state: Arc<Mutex<NotificationState<N>>>,
 }
+
+impl<N> NotificationGuard<N>
+where
+    N: FnOnce(Result<(), CxxQtThreadError>),
+{
+    fn notify(&mut self, result: Result<(), CxxQtThreadError>) {
+        // Take the notification out of the lock before calling it
+        let notify = self.state.lock().unwrap().notify.take();
+        if let Some(notify) = notify {
+            notify(result);
+        }
+    }
+}
+
+impl<N> Drop for NotificationGuard<N>
+where
+    N: FnOnce(Result<(), CxxQtThreadError>),
+{
+    fn drop(&mut self) {
+        let queued = {
+            let mut state = self.state.lock().unwrap();
+            state.dropped = true;
+            state.queued
+        };
+
+        // If queueing failed the error is returned to the caller instead,
+        // if it is still in progress then the caller sends the notification
+        if queued {
+            self.notify(Err(CxxQtThreadError::ObjectDestroyed));
+        }
+    }
+}
+
+/// Errors that can occur when queueing a closure with a [CxxQtThread]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CxxQtThreadError {
+    /// The QObject was destroyed before the closure could run, so the closure was dropped
+    ObjectDestroyed,
+    /// The closure could not be queued onto the Qt event loop of the QObject
+    InvokeFailed,
+    /// A blocking queue was requested from the thread that the QObject lives in,
+    /// which would wait forever for its own event loop
+    WouldDeadlock,
+}
+
+impl From<CxxQtThreadQueueStatus> for Result<(), CxxQtThreadError> {
+    fn from(status: CxxQtThreadQueueStatus) -> Self {
+        match status {
+            CxxQtThreadQueueStatus::Queued => Ok(()),
+            CxxQtThreadQueueStatus::ObjectDestroyed => Err(CxxQtThreadError::ObjectDestroyed),
+            _ => Err(CxxQtThreadError::InvokeFailed),
+        }
+    }
+}
+
+impl fmt::Display for CxxQtThreadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ObjectDestroyed => write!(f, "the QObject has been destroyed"),
+            Self::InvokeFailed => write!(f, "invokeMethod on the QObject failed"),
+            Self::WouldDeadlock => write!(
+                f,
+                "cannot block on a closure queued from the thread of the QObject"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CxxQtThreadError {}
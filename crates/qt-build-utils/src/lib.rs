@@ -196,6 +196,8 @@ pub struct QtBuild {
     qmlcachegen_executable: Option<String>,
     rcc_executable: Option<String>,
     qt_modules: Vec<String>,
+    qml_foreign_types: Vec<PathBuf>,
+    qml_import_paths: Vec<PathBuf>,
 }
 
 impl QtBuild {
@@ -298,6 +300,8 @@ impl QtBuild {
                         rcc_executable: None,
                         version,
                         qt_modules,
+                        qml_foreign_types: Vec::new(),
+                        qml_import_paths: Vec::new(),
                     });
                 }
                 Err(e) => {
@@ -322,6 +326,8 @@ impl QtBuild {
                         rcc_executable: None,
                         version,
                         qt_modules,
+                        qml_foreign_types: Vec::new(),
+                        qml_import_paths: Vec::new(),
                     });
                 }
                 // If QT_VERSION_MAJOR is specified, it is expected that one of the versioned
@@ -519,6 +525,25 @@ impl QtBuild {
         &self.version
     }
 
+    /// Add metatypes JSON files from [moc](Self::moc) of other libraries, such as another crate's
+    /// QML module, which are passed to qmltyperegistrar as foreign types by [Self::register_qml_module].
+    ///
+    /// This allows QML types to use types that are registered by those libraries.
+    pub fn qml_foreign_types(&mut self, metatypes_json: &[impl AsRef<Path>]) {
+        self.qml_foreign_types.extend(
+            metatypes_json
+                .iter()
+                .map(|path| path.as_ref().to_path_buf()),
+        );
+    }
+
+    /// Add QML import paths which are passed to qmlcachegen by [Self::register_qml_module],
+    /// so that QML files can import QML modules of other libraries.
+    pub fn qml_import_paths(&mut self, import_paths: &[impl AsRef<Path>]) {
+        self.qml_import_paths
+            .extend(import_paths.iter().map(|path| path.as_ref().to_path_buf()));
+    }
+
     /// Lazy load the path of a Qt executable tool
     /// Skip doing this in the constructor because not every user of this crate will use each tool
     fn get_qt_tool(&self, tool_name: &str) -> Result<String, ()> {
@@ -735,12 +760,16 @@ prefer :/qt/qml/{qml_uri_dirs}/
             std::fs::create_dir_all(&qmlcachegen_dir)
                 .expect("Could not create qmlcachegen directory for QML module");
 
-            let common_args = [
+            let mut common_args = vec![
                 "-i".to_string(),
                 qmldir_file_path.to_string(),
                 "--resource".to_string(),
                 qrc_path.clone(),
             ];
+            for import_path in &self.qml_import_paths {
+                common_args.push("-I".to_string());
+                common_args.push(import_path.to_string_lossy().to_string());
+            }
 
             let mut qml_file_qrc_paths = Vec::new();
             for file in qml_files {
@@ -829,6 +858,15 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 "-o".to_string(),
                 qmltyperegistrar_output_path.to_string_lossy().to_string(),
             ];
+            if !self.qml_foreign_types.is_empty() {
+                let foreign_types = self
+                    .qml_foreign_types
+                    .iter()
+                    .map(|path| path.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                args.push(format!("--foreign-types={foreign_types}"));
+            }
             args.extend(
                 metatypes_json
                     .iter()
@@ -0,0 +1,21 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::{CxxQtBuilder, QmlModule};
+
+fn main() {
+    // The include paths, type registry, QML import path and metatypes of the upstream crate
+    // are found automatically from the metadata that its build script exported
+    CxxQtBuilder::new()
+        // Qt Qml requires linking Qt Network on macOS
+        .qt_module("Network")
+        .qml_module(QmlModule {
+            uri: "com.kdab.cxx_qt.downstream",
+            rust_files: &["src/tracker.rs"],
+            qml_files: &["qml/main.qml"],
+            ..Default::default()
+        })
+        .with_opts(cxx_qt_lib_headers::build_opts())
+        .build();
+}
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Window 2.12
+
+// The QML module of the upstream crate, which is found with its exported QML import path
+import com.kdab.cxx_qt.upstream 1.0
+import com.kdab.cxx_qt.downstream 1.0
+
+Window {
+    height: 480
+    title: qsTr("Cargo Dependencies")
+    visible: true
+    width: 640
+
+    Counter {
+        id: counter
+    }
+
+    Tracker {
+        id: tracker
+        counter: counter
+        target: 5
+    }
+
+    Connections {
+        target: counter
+
+        function onCountChanged() {
+            remainingLabel.update();
+        }
+    }
+
+    Column {
+        anchors.fill: parent
+        anchors.margins: 10
+        spacing: 10
+
+        CounterLabel {
+            counter: counter
+        }
+
+        Label {
+            id: remainingLabel
+
+            function update() {
+                text = qsTr("Remaining: %1").arg(tracker.remaining());
+            }
+
+            Component.onCompleted: update()
+        }
+
+        Button {
+            text: qsTr("Increment Counter")
+
+            onClicked: counter.increment()
+        }
+    }
+}
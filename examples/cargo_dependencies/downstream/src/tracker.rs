@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// The bridge definition for a QObject which refers to a QObject of the upstream crate
+#[cxx_qt::bridge(cxx_file_stem = "tracker")]
+pub mod qobject {
+    // The include and namespace of the Counter QObject are found in the type registry of the upstream crate
+    use cargo_dependencies_upstream::counter::qobject::Counter;
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(*mut Counter, counter)]
+        #[qproperty(i32, target)]
+        type Tracker = super::TrackerRust;
+    }
+
+    unsafe extern "RustQt" {
+        /// The number of increments of the counter that are remaining to reach the target
+        #[qinvokable]
+        fn remaining(self: &Tracker) -> i32;
+    }
+}
+
+use cargo_dependencies_upstream::counter::qobject::Counter;
+
+/// The Rust struct for the Tracker QObject
+pub struct TrackerRust {
+    counter: *mut Counter,
+    target: i32,
+}
+
+impl Default for TrackerRust {
+    fn default() -> Self {
+        Self {
+            counter: std::ptr::null_mut(),
+            target: 10,
+        }
+    }
+}
+
+impl qobject::Tracker {
+    /// The number of increments of the counter that are remaining to reach the target
+    pub fn remaining(&self) -> i32 {
+        // SAFETY: the counter is set from QML and outlives the tracker
+        let count = unsafe { self.counter().as_ref() }.map_or(0, |counter| *counter.count());
+        (self.target() - count).max(0)
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::{CxxQtBuilder, QmlModule};
+
+fn main() {
+    CxxQtBuilder::new()
+        .qml_module(QmlModule {
+            uri: "com.kdab.cxx_qt.upstream",
+            rust_files: &["src/counter.rs"],
+            qml_files: &["qml/CounterLabel.qml"],
+            ..Default::default()
+        })
+        .with_opts(cxx_qt_lib_headers::build_opts())
+        .build();
+}
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+
+import com.kdab.cxx_qt.upstream 1.0
+
+Label {
+    property Counter counter
+
+    text: counter ? qsTr("Count: %1").arg(counter.count) : qsTr("No counter")
+}
@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// The bridge definition for the QObject which is used by the downstream crate
+#[cxx_qt::bridge(cxx_file_stem = "counter")]
+pub mod qobject {
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, count)]
+        type Counter = super::CounterRust;
+    }
+
+    unsafe extern "RustQt" {
+        /// Increment the count
+        #[qinvokable]
+        fn increment(self: Pin<&mut Counter>);
+    }
+}
+
+use core::pin::Pin;
+
+/// The Rust struct for the Counter QObject
+#[derive(Default)]
+pub struct CounterRust {
+    count: i32,
+}
+
+impl qobject::Counter {
+    /// Increment the count
+    pub fn increment(self: Pin<&mut Self>) {
+        let count = *self.count() + 1;
+        self.set_count(count);
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A CXX-Qt crate with a QObject and a QML module which are used by another CXX-Qt crate
+
+/// A module for the QObject which is shared with dependent crates
+pub mod counter;
@@ -23,6 +23,7 @@ fn main() {
                 "src/signals.rs",
                 "src/singleton.rs",
                 "src/properties.rs",
+                "src/scene_graph.rs",
                 "src/threading.rs",
                 "src/types.rs",
                 "src/uncreatable.rs",
@@ -37,6 +38,7 @@ fn main() {
                 "../qml/pages/MultipleQObjectsPage.qml",
                 "../qml/pages/NestedQObjectsPage.qml",
                 "../qml/pages/PropertiesPage.qml",
+                "../qml/pages/SceneGraphPage.qml",
                 "../qml/pages/SerialisationPage.qml",
                 "../qml/pages/SignalsPage.qml",
                 "../qml/pages/SingletonPage.qml",
@@ -13,6 +13,9 @@ pub mod qobject {
         include!("cxx-qt-lib/qcolor.h");
         /// QColor from cxx_qt_lib
         type QColor = cxx_qt_lib::QColor;
+        include!("cxx-qt-lib/qjsvalue.h");
+        /// QJSValue from cxx_qt_lib
+        type QJSValue = cxx_qt_lib::QJSValue;
     }
 
     unsafe extern "RustQt" {
@@ -57,6 +60,10 @@ pub mod qobject {
         /// Mutable invokable method with no parameters that resets the color
         #[qinvokable]
         fn reset(self: Pin<&mut RustInvokables>);
+
+        /// Immutable invokable method which calls a JavaScript function with the color components
+        #[qinvokable]
+        fn with_color_components(self: &RustInvokables, callback: &QJSValue) -> QJSValue;
     }
     // ANCHOR_END: book_invokable_signature
 
@@ -70,7 +77,7 @@ pub mod qobject {
 
 use core::pin::Pin;
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::QColor;
+use cxx_qt_lib::{QColor, QJSValue};
 
 /// A QObject which has Q_INVOKABLEs
 pub struct RustInvokablesRust {
@@ -117,6 +124,19 @@ impl qobject::RustInvokables {
     pub fn reset(self: Pin<&mut Self>) {
         self.store_helper(0.0, 0.4667, 0.7843);
     }
+
+    /// Immutable invokable method which calls a JavaScript function with the color components
+    pub fn with_color_components(&self, callback: &QJSValue) -> QJSValue {
+        if !callback.is_callable() {
+            return QJSValue::undefined();
+        }
+
+        callback.call(&[
+            QJSValue::from(self.red as f64),
+            QJSValue::from(self.green as f64),
+            QJSValue::from(self.blue as f64),
+        ])
+    }
 }
 // ANCHOR_END: book_invokable_impl
 
@@ -18,9 +18,25 @@ pub mod invokables;
 pub mod multiple_qobjects;
 pub mod nested_qobjects;
 pub mod properties;
+pub mod scene_graph;
 pub mod serialisation;
 pub mod signals;
 pub mod singleton;
 pub mod threading;
 pub mod types;
 pub mod uncreatable;
+
+#[cfg(test)]
+mod tests {
+    // The QML tests that only use types from Rust can also be run with cargo test,
+    // the other tests need the types registered from C++ in tests/main.cpp
+    #[test]
+    fn qml_scene_graph() {
+        cxx_qt_lib::QuickTest::new(
+            "qml_features",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/tst_scene_graph.qml"),
+        )
+        .run()
+        .report();
+    }
+}
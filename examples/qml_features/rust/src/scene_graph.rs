@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how a QQuickItem can provide nodes to the Qt Quick scene graph from Rust.
+
+/// A CXX-Qt bridge which shows how updatePaintNode and mouse events of a QQuickItem can be overridden
+#[cxx_qt::bridge(cxx_file_stem = "scene_graph")]
+pub mod qobject {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        /// MouseButton from cxx_qt_lib
+        type MouseButton = cxx_qt_lib::MouseButton;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickitem.h");
+        /// QQuickItemFlag from cxx_qt_lib
+        type QQuickItemFlag = cxx_qt_lib::QQuickItemFlag;
+        /// QQuickItemUpdatePaintNodeData from cxx_qt_lib
+        type QQuickItemUpdatePaintNodeData = cxx_qt_lib::QQuickItemUpdatePaintNodeData;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        /// QColor from cxx_qt_lib
+        type QColor = cxx_qt_lib::QColor;
+
+        include!("cxx-qt-lib/qmouseevent.h");
+        /// QMouseEvent from cxx_qt_lib
+        type QMouseEvent = cxx_qt_lib::QMouseEvent;
+
+        include!("cxx-qt-lib/qsgnode.h");
+        /// QSGNode from cxx_qt_lib
+        type QSGNode = cxx_qt_lib::QSGNode;
+    }
+
+    // ANCHOR: book_scene_graph_signature
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[base = "QQuickItem"]
+        #[qproperty(QColor, color)]
+        #[qproperty(i32, clicks)]
+        type SceneGraphItem = super::SceneGraphItemRust;
+
+        /// Override QQuickItem::updatePaintNode to provide the nodes for the scene graph in Rust
+        #[cxx_override]
+        unsafe fn update_paint_node(
+            self: Pin<&mut SceneGraphItem>,
+            old_node: *mut QSGNode,
+            data: *mut QQuickItemUpdatePaintNodeData,
+        ) -> *mut QSGNode;
+
+        /// Override QQuickItem::mousePressEvent to count the clicks on the item in Rust
+        #[cxx_override]
+        unsafe fn mouse_press_event(self: Pin<&mut SceneGraphItem>, event: *mut QMouseEvent);
+
+        /// Define that we need to inherit height() from the base class
+        #[inherit]
+        fn height(self: &SceneGraphItem) -> f64;
+
+        /// Define that we need to inherit setAcceptedMouseButtons() from the base class
+        #[inherit]
+        fn set_accepted_mouse_buttons(self: Pin<&mut SceneGraphItem>, buttons: MouseButton);
+
+        /// Define that we need to inherit setFlag() from the base class
+        #[inherit]
+        fn set_flag(self: Pin<&mut SceneGraphItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Define that we need to inherit update() from the base class
+        #[inherit]
+        fn update(self: Pin<&mut SceneGraphItem>);
+
+        /// Define that we need to inherit width() from the base class
+        #[inherit]
+        fn width(self: &SceneGraphItem) -> f64;
+    }
+    // ANCHOR_END: book_scene_graph_signature
+
+    impl cxx_qt::Constructor<()> for SceneGraphItem {}
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{MouseButton, QColor, QQuickItemFlag, QRectF, QSGNode, QSGSimpleRectNode};
+
+/// A struct which inherits from QQuickItem and renders a rectangle with the scene graph
+#[derive(Default)]
+pub struct SceneGraphItemRust {
+    color: QColor,
+    clicks: i32,
+}
+
+// ANCHOR: book_scene_graph_update_paint_node
+impl qobject::SceneGraphItem {
+    /// Override QQuickItem::updatePaintNode to provide the nodes for the scene graph in Rust
+    ///
+    /// This is called on the render thread while the GUI thread is blocked.
+    ///
+    /// # Safety
+    ///
+    /// The old node is a nullptr or the node which was returned by the previous call.
+    pub unsafe fn update_paint_node(
+        self: Pin<&mut Self>,
+        old_node: *mut QSGNode,
+        _data: *mut qobject::QQuickItemUpdatePaintNodeData,
+    ) -> *mut QSGNode {
+        let rect = QRectF::new(0.0, 0.0, self.width(), self.height());
+
+        // The scene graph owns the node that is returned, so only create it the first time
+        if old_node.is_null() {
+            let node = QSGSimpleRectNode::new(&rect, self.color());
+            return QSGSimpleRectNode::into_qsgnode(node).into_raw();
+        }
+
+        if let Some(node) = QSGSimpleRectNode::from_qsgnode_ptr(old_node).as_mut() {
+            let mut node = Pin::new_unchecked(node);
+            node.as_mut().set_rect(&rect);
+            node.as_mut().set_color(self.color());
+        }
+
+        old_node
+    }
+
+    /// Override QQuickItem::mousePressEvent to count the clicks on the item in Rust
+    ///
+    /// # Safety
+    ///
+    /// The event is a valid pointer for the duration of the call.
+    pub unsafe fn mouse_press_event(self: Pin<&mut Self>, event: *mut qobject::QMouseEvent) {
+        if let Some(event) = event.as_mut() {
+            Pin::new_unchecked(event).accept();
+        }
+
+        let clicks = *self.clicks();
+        self.set_clicks(clicks + 1);
+    }
+}
+// ANCHOR_END: book_scene_graph_update_paint_node
+
+impl cxx_qt::Initialize for qobject::SceneGraphItem {
+    fn initialize(mut self: core::pin::Pin<&mut Self>) {
+        // Without this flag updatePaintNode is never called
+        self.as_mut()
+            .set_flag(QQuickItemFlag::ItemHasContents, true);
+        self.as_mut()
+            .set_accepted_mouse_buttons(MouseButton::LeftButton);
+
+        self.on_color_changed(|qobject| qobject.update()).release();
+    }
+}
@@ -5,6 +5,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickWindow>
 #include <QtQuickTest/quicktest.h>
 
 #include "custom_object.h"
@@ -17,6 +18,9 @@ class Setup : public QObject
 public:
   Setup()
   {
+    // Use the software scene graph backend so that the rendering tests work
+    // without a GPU, this needs to happen before any window is created.
+    QQuickWindow::setSceneGraphBackend(QStringLiteral("software"));
 
     qRegisterMetaType<CustomStruct>("CustomStruct");
     // Note the _cpp at the end of the URI. If qmlRegisterMetatype is used here
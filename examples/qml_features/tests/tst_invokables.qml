@@ -31,4 +31,14 @@ TestCase {
         obj.reset();
         compare(obj.loadColor(), kdabColor);
     }
+
+    function test_callback() {
+        const obj = createTemporaryObject(componentInvokables, null, {});
+        obj.storeColor(1.0, 0.5, 0.0);
+
+        const sum = obj.withColorComponents((red, green, blue) => red + green + blue);
+        compare(sum, 1.5);
+
+        compare(obj.withColorComponents("not a function"), undefined);
+    }
 }
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "SceneGraphTests"
+    when: windowShown
+
+    Component {
+        id: componentSceneGraphItem
+
+        SceneGraphItem {
+            color: "red"
+            height: 50
+            width: 50
+        }
+    }
+
+    function test_render() {
+        const item = createTemporaryObject(componentSceneGraphItem, this, {});
+        verify(item !== null);
+        waitForRendering(item);
+
+        let image = grabImage(item);
+        compare(image.red(25, 25), 255);
+        compare(image.green(25, 25), 0);
+        compare(image.blue(25, 25), 0);
+
+        // Changing the color schedules a call to updatePaintNode
+        item.color = "blue";
+        waitForRendering(item);
+
+        image = grabImage(item);
+        compare(image.red(25, 25), 0);
+        compare(image.green(25, 25), 0);
+        compare(image.blue(25, 25), 255);
+    }
+
+    function test_mouse_press() {
+        const item = createTemporaryObject(componentSceneGraphItem, this, {});
+        verify(item !== null);
+        compare(item.clicks, 0);
+
+        mouseClick(item);
+        compare(item.clicks, 1);
+
+        // Only the left button is accepted
+        mouseClick(item, undefined, undefined, Qt.RightButton);
+        compare(item.clicks, 1);
+    }
+}
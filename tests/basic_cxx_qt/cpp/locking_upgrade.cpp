@@ -0,0 +1,14 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "locking_upgrade.h"
+
+#include "cxx-qt-gen/locking.cxxqt.h"
+
+void
+incrementLocked(const RustSharedLocking& object)
+{
+  const_cast<RustSharedLocking&>(object).increment();
+}
@@ -0,0 +1,12 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+class RustSharedLocking;
+
+// Call the non-const increment method, which takes the exclusive lock
+void
+incrementLocked(const RustSharedLocking& object);
@@ -157,6 +157,70 @@ private Q_SLOTS:
     QCOMPARE(lockingEnabled.getCounter(), 10);
   }
 
+  // Ensure that a thread holding the upgradable lock can upgrade to the
+  // exclusive lock while other threads hold the shared lock
+  void test_shared_locking_upgrade()
+  {
+    RustSharedLocking sharedLocking;
+    QCOMPARE(sharedLocking.getCounter(), 0);
+
+    QVector<LockingWorkerThread*> threads;
+    threads.push_back(new LockingWorkerThread(
+      [&sharedLocking]() { sharedLocking.incrementFromShared(); }, this));
+    for (int i = 0; i < 10; i++) {
+      threads.push_back(new LockingWorkerThread(
+        [&sharedLocking]() {
+          for (int j = 0; j < 100; j++) {
+            sharedLocking.getNumber();
+          }
+        },
+        this));
+    }
+
+    for (auto& thread : threads) {
+      thread->start();
+    }
+
+    for (auto& thread : threads) {
+      thread->wait();
+    }
+
+    QCOMPARE(sharedLocking.getCounter(), 1);
+  }
+
+  // Ensure that multiple threads can upgrade to the exclusive lock at the same
+  // time, as only one of them holds the upgradable lock at a time
+  void test_shared_locking_concurrent_upgrades()
+  {
+    RustSharedLocking sharedLocking;
+    QCOMPARE(sharedLocking.getCounter(), 0);
+
+    QVector<LockingWorkerThread*> threads;
+    for (int i = 0; i < 2; i++) {
+      threads.push_back(new LockingWorkerThread(
+        [&sharedLocking]() { sharedLocking.incrementFromShared(); }, this));
+    }
+    for (int i = 0; i < 10; i++) {
+      threads.push_back(new LockingWorkerThread(
+        [&sharedLocking]() {
+          for (int j = 0; j < 100; j++) {
+            sharedLocking.getNumber();
+          }
+        },
+        this));
+    }
+
+    for (auto& thread : threads) {
+      thread->start();
+    }
+
+    for (auto& thread : threads) {
+      QVERIFY(thread->wait(10000));
+    }
+
+    QCOMPARE(sharedLocking.getCounter(), 2);
+  }
+
   // CXX-Qt allows Rust code to queue a request
   void test_queue_request()
   {
@@ -188,6 +252,82 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt notifies Rust code when a queued request has run
+  void test_queue_request_notification()
+  {
+    cxx_qt::my_object::MyObject obj;
+    const auto completed = obj.fetchQueueCompletedCount();
+    obj.queueTestNotification();
+    QCOMPARE(obj.fetchQueueCompletedCount(), completed);
+    QTRY_COMPARE(obj.fetchQueueCompletedCount(), completed + 1);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // CXX-Qt notifies Rust code when a queued request is dropped
+  void test_queue_request_destroyed()
+  {
+    cxx_qt::my_object::MyObject obj;
+    const auto dropped = obj.fetchQueueDroppedCount();
+    auto destroyed = new cxx_qt::my_object::MyObject;
+    destroyed->queueTestNotification();
+    delete destroyed;
+    QCOMPARE(obj.fetchQueueDroppedCount(), dropped + 1);
+  }
+
+  // CXX-Qt allows Rust code to block a background thread on a queued request
+  void test_queue_blocking()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(21);
+    obj.queueBlockingTest();
+    QTRY_COMPARE(obj.fetchQueueBlockingResult(), 42);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // CXX-Qt returns an error instead of blocking the thread of the QObject
+  void test_queue_blocking_would_deadlock()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QVERIFY(obj.queueBlockingWouldDeadlock());
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+  }
+
+  // CXX-Qt returns an error when blocking on a destroyed QObject
+  void test_queue_blocking_destroyed()
+  {
+    cxx_qt::my_object::MyObject obj;
+    auto destroyed = new cxx_qt::my_object::MyObject;
+    destroyed->queueBlockingDestroyedTest();
+    delete destroyed;
+    QVERIFY(obj.fetchQueueBlockingDestroyed());
+  }
+
+  // CXX-Qt resumes a panic from a blocking queued request on the calling thread
+  void test_queue_blocking_panic()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueBlockingPanicTest();
+    QTRY_VERIFY(obj.fetchQueueBlockingPanicked());
+  }
+
+  // CXX-Qt runs a queued request on the thread that the QObject was moved to
+  void test_queue_moved_to_thread()
+  {
+    QThread thread;
+    thread.start();
+
+    auto obj = new cxx_qt::my_object::MyObject;
+    QObject::connect(&thread, &QThread::finished, obj, &QObject::deleteLater);
+    QVERIFY(obj->moveToThreadTest(thread));
+    QCOMPARE(obj->thread(), &thread);
+
+    obj->queueMovedToThreadTest();
+    QTRY_VERIFY(obj->fetchQueueMovedToThread());
+
+    thread.quit();
+    QVERIFY(thread.wait());
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
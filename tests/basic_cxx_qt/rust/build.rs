@@ -12,6 +12,10 @@ fn main() {
         .file("src/lib.rs")
         .file("src/locking.rs")
         .file("src/types.rs")
+        .cc_builder(|cc| {
+            cc.include("../cpp");
+            cc.file("../cpp/locking_upgrade.cpp");
+        })
         .with_opts(cxx_qt_lib_headers::build_opts())
         .build();
 }
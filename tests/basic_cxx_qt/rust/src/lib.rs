@@ -15,6 +15,8 @@ mod qobject {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = cxx_qt_lib::QThread;
     }
 
     unsafe extern "RustQt" {
@@ -27,6 +29,13 @@ mod qobject {
     // Enabling threading on the qobject
     impl cxx_qt::Threading for MyObject {}
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        #[cxx_name = "qobjectMoveToThread"]
+        fn move_to_thread(object: Pin<&mut MyObject>, thread: &QThread) -> bool;
+    }
+
     // Note that we are only testing with C++ here so we don't need qinvokable
     unsafe extern "RustQt" {
         fn double_number_self(self: Pin<&mut MyObject>);
@@ -39,6 +48,32 @@ mod qobject {
 
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        fn queue_test_notification(self: Pin<&mut MyObject>);
+
+        fn queue_blocking_test(self: Pin<&mut MyObject>);
+
+        fn queue_blocking_would_deadlock(self: &MyObject) -> bool;
+
+        fn queue_blocking_destroyed_test(self: &MyObject);
+
+        fn queue_blocking_panic_test(self: Pin<&mut MyObject>);
+
+        fn move_to_thread_test(self: Pin<&mut MyObject>, thread: &QThread) -> bool;
+
+        fn queue_moved_to_thread_test(self: &MyObject);
+
+        fn fetch_queue_blocking_result(self: &MyObject) -> i32;
+
+        fn fetch_queue_blocking_destroyed(self: &MyObject) -> bool;
+
+        fn fetch_queue_blocking_panicked(self: &MyObject) -> bool;
+
+        fn fetch_queue_moved_to_thread(self: &MyObject) -> bool;
+
+        fn fetch_queue_completed_count(self: &MyObject) -> i32;
+
+        fn fetch_queue_dropped_count(self: &MyObject) -> i32;
+
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
         fn throw_exception(self: &MyObject) -> Result<i32>;
@@ -46,8 +81,24 @@ mod qobject {
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtThread, CxxQtThreadError, CxxQtType, Threading};
+use cxx_qt_lib::{QString, QThread};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicI32, Ordering},
+        Mutex,
+    },
+    thread::ThreadId,
+};
+
+static QUEUE_COMPLETED_COUNT: AtomicI32 = AtomicI32::new(0);
+static QUEUE_DROPPED_COUNT: AtomicI32 = AtomicI32::new(0);
+static QUEUE_BLOCKING_RESULT: AtomicI32 = AtomicI32::new(-1);
+static QUEUE_BLOCKING_PANICKED: AtomicBool = AtomicBool::new(false);
+static QUEUE_BLOCKING_DESTROYED_THREAD: Mutex<Option<CxxQtThread<qobject::MyObject>>> =
+    Mutex::new(None);
+static QUEUE_MOVED_TO_THREAD_ID: Mutex<Option<ThreadId>> = Mutex::new(None);
 
 pub struct MyObjectRust {
     number: i32,
@@ -118,6 +169,115 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_test_notification(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        assert!(!qt_thread.is_destroyed());
+        qt_thread
+            .queue_with_notification(
+                |qobject| {
+                    qobject.rust_mut().update_call_count += 1;
+                },
+                |result| {
+                    let count = if result.is_ok() {
+                        &QUEUE_COMPLETED_COUNT
+                    } else {
+                        &QUEUE_DROPPED_COUNT
+                    };
+                    count.fetch_add(1, Ordering::Relaxed);
+                },
+            )
+            .unwrap();
+    }
+
+    fn queue_blocking_test(self: Pin<&mut Self>) {
+        QUEUE_BLOCKING_RESULT.store(-1, Ordering::SeqCst);
+
+        // Block a worker thread, rather than the thread of the QObject, until the closure has run
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let result = qt_thread
+                .queue_blocking(|qobject| {
+                    let number = *qobject.number();
+                    qobject.rust_mut().update_call_count += 1;
+                    number * 2
+                })
+                .unwrap();
+            QUEUE_BLOCKING_RESULT.store(result, Ordering::SeqCst);
+        });
+    }
+
+    fn queue_blocking_would_deadlock(&self) -> bool {
+        self.qt_thread().queue_blocking(|_| ()) == Err(CxxQtThreadError::WouldDeadlock)
+    }
+
+    fn queue_blocking_destroyed_test(&self) {
+        // Keep the thread helper so that it can be used once this QObject has been destroyed
+        *QUEUE_BLOCKING_DESTROYED_THREAD.lock().unwrap() = Some(self.qt_thread());
+    }
+
+    fn queue_blocking_panic_test(self: Pin<&mut Self>) {
+        QUEUE_BLOCKING_PANICKED.store(false, Ordering::SeqCst);
+
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            // The panic is resumed on this thread rather than unwinding into the Qt event loop
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                qt_thread.queue_blocking(|_| -> i32 { panic!("queue_blocking_panic_test") })
+            }));
+            QUEUE_BLOCKING_PANICKED.store(result.is_err(), Ordering::SeqCst);
+        });
+    }
+
+    fn move_to_thread_test(self: Pin<&mut Self>, thread: &QThread) -> bool {
+        qobject::move_to_thread(self, thread)
+    }
+
+    fn queue_moved_to_thread_test(&self) {
+        *QUEUE_MOVED_TO_THREAD_ID.lock().unwrap() = None;
+
+        // The closure is delivered to the event loop of the thread that the QObject now lives in
+        self.qt_thread()
+            .queue(|_| {
+                *QUEUE_MOVED_TO_THREAD_ID.lock().unwrap() = Some(std::thread::current().id());
+            })
+            .unwrap();
+    }
+
+    fn fetch_queue_blocking_result(&self) -> i32 {
+        QUEUE_BLOCKING_RESULT.load(Ordering::SeqCst)
+    }
+
+    fn fetch_queue_blocking_destroyed(&self) -> bool {
+        let qt_thread = QUEUE_BLOCKING_DESTROYED_THREAD.lock().unwrap().take();
+        qt_thread.is_some_and(|qt_thread| {
+            std::thread::spawn(move || {
+                qt_thread.is_destroyed()
+                    && qt_thread.queue_blocking(|_| ()) == Err(CxxQtThreadError::ObjectDestroyed)
+            })
+            .join()
+            .unwrap()
+        })
+    }
+
+    fn fetch_queue_blocking_panicked(&self) -> bool {
+        QUEUE_BLOCKING_PANICKED.load(Ordering::SeqCst)
+    }
+
+    fn fetch_queue_moved_to_thread(&self) -> bool {
+        QUEUE_MOVED_TO_THREAD_ID
+            .lock()
+            .unwrap()
+            .is_some_and(|id| id != std::thread::current().id())
+    }
+
+    fn fetch_queue_completed_count(&self) -> i32 {
+        QUEUE_COMPLETED_COUNT.load(Ordering::Relaxed)
+    }
+
+    fn fetch_queue_dropped_count(&self) -> i32 {
+        QUEUE_DROPPED_COUNT.load(Ordering::Relaxed)
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
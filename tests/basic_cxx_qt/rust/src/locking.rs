@@ -26,6 +26,30 @@ pub mod qobject {
         fn increment(self: Pin<&mut RustLockingDisabled>);
     }
 
+    unsafe extern "RustQt" {
+        /// A QObject which has cxx_qt::SharedLocking
+        #[qobject]
+        #[qproperty(i32, number)]
+        type RustSharedLocking = super::RustSharedLockingRust;
+
+        fn get_counter(self: &RustSharedLocking) -> u32;
+
+        fn increment(self: Pin<&mut RustSharedLocking>);
+
+        /// Increment the counter while holding the upgradable lock, which upgrades to the exclusive lock
+        fn increment_from_shared(self: &RustSharedLocking);
+    }
+
+    unsafe extern "C++" {
+        include!("locking_upgrade.h");
+
+        /// Call the increment method of the C++ object, which takes the exclusive lock
+        #[cxx_name = "incrementLocked"]
+        fn increment_locked(object: &RustSharedLocking);
+    }
+
+    impl cxx_qt::SharedLocking for RustSharedLocking {}
+
     // ANCHOR: book_disable_locking
     unsafe impl !cxx_qt::Locking for RustLockingDisabled {}
     // ANCHOR_END: book_disable_locking
@@ -71,3 +95,25 @@ impl qobject::RustLockingDisabled {
         self.counter.store(counter + 1, Ordering::Release);
     }
 }
+
+#[derive(Default)]
+pub struct RustSharedLockingRust {
+    pub(crate) counter: AtomicU32,
+    number: i32,
+}
+
+impl qobject::RustSharedLocking {
+    fn get_counter(&self) -> u32 {
+        self.counter.load(Ordering::Acquire)
+    }
+
+    fn increment(self: Pin<&mut Self>) {
+        self.counter.fetch_add(1, Ordering::AcqRel);
+    }
+
+    fn increment_from_shared(&self) {
+        // Give other threads time to read the property with the shared lock
+        thread::sleep(Duration::from_millis(100));
+        qobject::increment_locked(self);
+    }
+}
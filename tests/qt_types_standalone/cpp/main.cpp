@@ -12,6 +12,7 @@
 #include "qcoreapplication.h"
 #include "qdate.h"
 #include "qdatetime.h"
+#include "qfile.h"
 #include "qguiapplication.h"
 #include "qhash.h"
 #include "qline.h"
@@ -29,16 +30,22 @@
 #include "qpolygon.h"
 #include "qpolygonf.h"
 #include "qqmlapplicationengine.h"
+#include "qqmlcomponent.h"
+#include "qqmlcontext.h"
 #include "qqmlengine.h"
+#include "qqmlerror.h"
+#include "qquickimageprovider.h"
 #include "qrect.h"
 #include "qrectf.h"
 #include "qregion.h"
 #include "qset.h"
+#include "qsettings.h"
 #include "qsize.h"
 #include "qsizef.h"
 #include "qstring.h"
 #include "qstringlist.h"
 #include "qtime.h"
+#include "qtimer.h"
 #include "qtimezone.h"
 #include "qurl.h"
 #include "qvariant.h"
@@ -64,6 +71,7 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QCoreApplicationTest));
   runTest(QScopedPointer<QObject>(new QDateTest));
   runTest(QScopedPointer<QObject>(new QDateTimeTest));
+  runTest(QScopedPointer<QObject>(new QFileTest));
   runTest(QScopedPointer<QObject>(new QGuiApplicationTest));
   runTest(QScopedPointer<QObject>(new QHashTest));
   runTest(QScopedPointer<QObject>(new QLineTest));
@@ -78,15 +86,21 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlComponentTest));
+  runTest(QScopedPointer<QObject>(new QQmlContextTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlErrorTest));
+  runTest(QScopedPointer<QObject>(new QQuickImageProviderTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
   runTest(QScopedPointer<QObject>(new QSetTest));
+  runTest(QScopedPointer<QObject>(new QSettingsTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
+  runTest(QScopedPointer<QObject>(new QTimerTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
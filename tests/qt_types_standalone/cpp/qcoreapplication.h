@@ -7,6 +7,7 @@
 #pragma once
 
 #include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
 #include <QtTest/QTest>
 
 #include "cxx-qt-gen/qcoreapplication.cxx.h"
@@ -34,4 +35,27 @@ private Q_SLOTS:
     app.setApplicationName(QStringLiteral("kdab"));
     QVERIFY(read_qcoreapplication(app));
   }
+
+  void spawnLocal()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QCoreApplication app(argc, args.data());
+    QObject context;
+    spawn_local_qcoreapplication(app, context, 10);
+
+    // The future is not polled until the event loop is running
+    QCOMPARE(read_spawn_local_polls(), 0u);
+    QVERIFY(!read_spawn_local_completed());
+
+    QTimer::singleShot(500, &app, &QCoreApplication::quit);
+    app.exec();
+
+    // The future was polled once, then woken by the timer and polled again
+    QVERIFY(read_spawn_local_completed());
+    QCOMPARE(read_spawn_local_polls(), 2u);
+  }
 };
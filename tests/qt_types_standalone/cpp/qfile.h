@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qfile.cxx.h"
+
+class QFileTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void writeReadSeek()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("file.txt"));
+
+    QVERIFY(write_read_seek_qfile(fileName));
+
+    QFile file(fileName);
+    QVERIFY(file.open(QIODevice::ReadOnly));
+    QCOMPARE(file.readAll(), QByteArrayLiteral("Hello, World!"));
+  }
+
+  void read()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("file.txt"));
+
+    {
+      QFile file(fileName);
+      QVERIFY(file.open(QIODevice::WriteOnly));
+      file.write(QByteArrayLiteral("KDAB"));
+    }
+
+    QCOMPARE(read_qfile(fileName), QStringLiteral("KDAB"));
+    QCOMPARE(read_qfile(dir.filePath(QStringLiteral("missing.txt"))),
+             QString());
+  }
+
+  void readResource()
+  {
+    QCOMPARE(read_qresource(QStringLiteral(":/kdab/kdab.txt")),
+             QStringLiteral("KDAB\n"));
+    QCOMPARE(read_qresource(QStringLiteral(":/kdab/missing.txt")), QString());
+  }
+};
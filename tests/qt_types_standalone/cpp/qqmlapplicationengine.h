@@ -44,4 +44,23 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlapplicationengine(engine));
   }
+
+  void rootObjects()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlApplicationEngine engine;
+    QVERIFY(read_qqmlapplicationengine_root_objects(engine).isEmpty());
+
+    engine.loadData(
+      QByteArrayLiteral("import QtQml 2.15\nQtObject { objectName: \"kdab\" }"),
+      QUrl(QStringLiteral("qrc:/kdab.qml")));
+    QCOMPARE(read_qqmlapplicationengine_root_objects(engine),
+             QStringList{ QStringLiteral("kdab") });
+  }
 };
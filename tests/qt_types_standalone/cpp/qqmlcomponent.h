@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QScopedPointer>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qqmlcomponent.cxx.h"
+
+class QQmlComponentTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    const auto component = construct_qqmlcomponent(engine);
+    QVERIFY(component != nullptr);
+    QVERIFY(component->isReady());
+    QCOMPARE(component->engine(), &engine);
+    QCOMPARE(component->url(), QUrl(QStringLiteral("qrc:/kdab.qml")));
+  }
+
+  void create()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QQmlComponent component(&engine);
+    component.setData(
+      QByteArrayLiteral("import QtQml 2.15\nQtObject { objectName: \"kdab\" }"),
+      QUrl(QStringLiteral("qrc:/kdab.qml")));
+    QScopedPointer<QObject> object(create_qqmlcomponent(component));
+    QVERIFY(!object.isNull());
+    QCOMPARE(object->objectName(), QStringLiteral("kdab"));
+  }
+
+  void errors()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    const auto component = construct_qqmlcomponent_invalid(engine);
+    QVERIFY(component != nullptr);
+    QVERIFY(component->isError());
+    QCOMPARE(read_qqmlcomponent_errors(*component),
+             static_cast<::std::int32_t>(component->errors().size()));
+    QVERIFY(read_qqmlcomponent_errors(*component) > 0);
+  }
+};
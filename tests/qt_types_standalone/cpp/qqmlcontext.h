@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qqmlcontext.cxx.h"
+
+class QQmlContextTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    const auto context = construct_qqmlcontext(engine);
+    QVERIFY(context != nullptr);
+    QVERIFY(context->isValid());
+    QCOMPARE(context->engine(), &engine);
+    QCOMPARE(context->parentContext(), engine.rootContext());
+    QCOMPARE(context->baseUrl(), QUrl(QStringLiteral("qrc:/kdab.qml")));
+    QCOMPARE(context->contextProperty(QStringLiteral("number")).toInt(), 42);
+  }
+
+  void read()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QQmlContext context(engine.rootContext());
+    context.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
+    context.setContextProperty(QStringLiteral("number"), QVariant(42));
+    QVERIFY(read_qqmlcontext(context));
+  }
+
+  void rootContext()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QVERIFY(read_qqmlengine_root_context(engine));
+  }
+};
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQml/QQmlError>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qqmlerror.cxx.h"
+
+class QQmlErrorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto e = construct_qqmlerror();
+    QVERIFY(e.isValid());
+    QCOMPARE(e.description(), QStringLiteral("kdab"));
+    QCOMPARE(e.url(), QUrl(QStringLiteral("qrc:/kdab.qml")));
+    QCOMPARE(e.line(), 1);
+    QCOMPARE(e.column(), 2);
+  }
+
+  void read()
+  {
+    QQmlError e;
+    e.setDescription(QStringLiteral("kdab"));
+    e.setUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
+    e.setLine(1);
+    e.setColumn(2);
+    QVERIFY(read_qqmlerror(e));
+  }
+
+  void clone()
+  {
+    QQmlError e;
+    e.setDescription(QStringLiteral("kdab"));
+    e.setLine(1);
+
+    const auto c = clone_qqmlerror(e);
+    QCOMPARE(c.description(), e.description());
+    QCOMPARE(c.line(), e.line());
+    QCOMPARE(c.toString(), e.toString());
+  }
+};
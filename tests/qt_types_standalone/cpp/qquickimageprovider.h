@@ -0,0 +1,89 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QScopedPointer>
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquickimageprovider.cxx.h"
+
+class QQuickImageProviderTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  // The values of the QQuickImageBase::Status enum
+  static constexpr int ImageReady = 1;
+  static constexpr int ImageError = 3;
+
+  static QObject* createImage(QQmlEngine& engine, const QString& source)
+  {
+    QQmlComponent component(&engine);
+    component.setData(QStringLiteral("import QtQuick 2.15\n"
+                                     "Image { source: \"%1\" }")
+                        .arg(source)
+                        .toUtf8(),
+                      QUrl());
+    return component.create();
+  }
+
+private Q_SLOTS:
+  void requestImage()
+  {
+    // QQuickImageProvider requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+    QVERIFY(engine.imageProvider(QStringLiteral("colors")) != nullptr);
+
+    // The synchronous provider is called while the Image item is created
+    QScopedPointer<QObject> image(
+      createImage(engine, QStringLiteral("image://colors/red")));
+    QVERIFY(!image.isNull());
+    QCOMPARE(image->property("status").toInt(), ImageReady);
+    QCOMPARE(image->property("sourceSize").toSize(), QSize(16, 16));
+
+    QScopedPointer<QObject> invalid(
+      createImage(engine, QStringLiteral("image://colors/notacolor")));
+    QVERIFY(!invalid.isNull());
+    QCOMPARE(invalid->property("status").toInt(), ImageError);
+  }
+
+  void requestImageResponse()
+  {
+    // QQuickAsyncImageProvider requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+    QVERIFY(engine.imageProvider(QStringLiteral("asynccolors")) != nullptr);
+
+    // The asynchronous provider finishes the response from another thread,
+    // which is then delivered by the event loop
+    QScopedPointer<QObject> image(
+      createImage(engine, QStringLiteral("image://asynccolors/blue")));
+    QVERIFY(!image.isNull());
+    QTRY_COMPARE(image->property("status").toInt(), ImageReady);
+    QCOMPARE(image->property("sourceSize").toSize(), QSize(16, 16));
+
+    QScopedPointer<QObject> invalid(
+      createImage(engine, QStringLiteral("image://asynccolors/notacolor")));
+    QVERIFY(!invalid.isNull());
+    QTRY_COMPARE(invalid->property("status").toInt(), ImageError);
+  }
+};
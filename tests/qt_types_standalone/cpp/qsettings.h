@@ -0,0 +1,73 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSettings>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsettings.cxx.h"
+
+class QSettingsTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void write()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    write_qsettings(fileName);
+
+    QSettings settings(fileName, QSettings::IniFormat);
+    QCOMPARE(settings.status(), QSettings::NoError);
+    QCOMPARE(settings.value(QStringLiteral("number")).toInt(), 42);
+    QCOMPARE(settings.value(QStringLiteral("group/name")).toString(),
+             QStringLiteral("kdab"));
+
+    QCOMPARE(settings.beginReadArray(QStringLiteral("array")), 3);
+    for (int i = 0; i < 3; i++) {
+      settings.setArrayIndex(i);
+      QCOMPARE(settings.value(QStringLiteral("value")).toInt(), i + 1);
+    }
+    settings.endArray();
+  }
+
+  void read()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    {
+      QSettings settings(fileName, QSettings::IniFormat);
+      settings.setValue(QStringLiteral("number"), 42);
+      settings.beginGroup(QStringLiteral("group"));
+      settings.setValue(QStringLiteral("name"), QStringLiteral("kdab"));
+      settings.endGroup();
+      settings.beginWriteArray(QStringLiteral("array"));
+      for (int i = 0; i < 3; i++) {
+        settings.setArrayIndex(i);
+        settings.setValue(QStringLiteral("value"), i + 1);
+      }
+      settings.endArray();
+    }
+
+    QVERIFY(read_qsettings(fileName));
+  }
+
+  void roundTrip()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    write_qsettings(fileName);
+    QVERIFY(read_qsettings(fileName));
+  }
+};
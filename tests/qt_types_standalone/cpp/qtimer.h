@@ -0,0 +1,91 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qtimer.cxx.h"
+
+class QTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto timer = construct_qtimer();
+    QVERIFY(timer != nullptr);
+    QCOMPARE(timer->interval(), 100);
+    QVERIFY(timer->isSingleShot());
+    QVERIFY(!timer->isActive());
+  }
+
+  void startStop()
+  {
+    // QTimer requires a QCoreApplication for the event dispatcher
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QTimer timer;
+    QVERIFY(start_stop_qtimer(timer));
+    QVERIFY(!timer.isActive());
+    QCOMPARE(timer.interval(), 1000);
+  }
+
+  void timeout()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QTimer timer;
+    connect_qtimer_timeout(timer);
+    timer.start(10);
+    QTRY_VERIFY(read_qtimer_timeouts() >= 2);
+    timer.stop();
+  }
+
+  void singleShot()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QObject context;
+    qtimer_single_shot(10, context);
+    QCOMPARE(read_qtimer_single_shot_called(), 0u);
+
+    QTRY_COMPARE(read_qtimer_single_shot_called(), 1u);
+    QCOMPARE(read_qtimer_single_shot_dropped(), 1u);
+  }
+
+  void singleShotContextDestroyed()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto context = new QObject;
+    qtimer_single_shot(10, *context);
+    delete context;
+
+    // The closure is dropped without being called
+    QTRY_COMPARE(read_qtimer_single_shot_dropped(), 1u);
+    QTest::qWait(50);
+    QCOMPARE(read_qtimer_single_shot_called(), 0u);
+  }
+};
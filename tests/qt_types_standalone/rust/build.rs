@@ -12,6 +12,7 @@ fn main() {
         .file("src/qcoreapplication.rs")
         .file("src/qdate.rs")
         .file("src/qdatetime.rs")
+        .file("src/qfile.rs")
         .file("src/qguiapplication.rs")
         .file("src/qhash.rs")
         .file("src/qline.rs")
@@ -29,16 +30,22 @@ fn main() {
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
         .file("src/qqmlapplicationengine.rs")
+        .file("src/qqmlcomponent.rs")
+        .file("src/qqmlcontext.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qqmlerror.rs")
+        .file("src/qquickimageprovider.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qregion.rs")
         .file("src/qset.rs")
+        .file("src/qsettings.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
         .file("src/qtime.rs")
+        .file("src/qtimer.rs")
         .file("src/qtimezone.rs")
         .file("src/qurl.rs")
         .file("src/qvariant.rs")
@@ -46,6 +53,7 @@ fn main() {
         .file("src/qvector2d.rs")
         .file("src/qvector3d.rs")
         .file("src/qvector4d.rs")
+        .qrc("resources/resources.qrc")
         .with_opts(cxx_qt_lib_headers::build_opts())
         .build();
 }
@@ -9,6 +9,7 @@ mod qcolor;
 mod qcoreapplication;
 mod qdate;
 mod qdatetime;
+mod qfile;
 mod qguiapplication;
 mod qhash;
 mod qline;
@@ -26,16 +27,22 @@ mod qpointf;
 mod qpolygon;
 mod qpolygonf;
 mod qqmlapplicationengine;
+mod qqmlcomponent;
+mod qqmlcontext;
 mod qqmlengine;
+mod qqmlerror;
+mod qquickimageprovider;
 mod qrect;
 mod qrectf;
 mod qregion;
 mod qset;
+mod qsettings;
 mod qsize;
 mod qsizef;
 mod qstring;
 mod qstringlist;
 mod qtime;
+mod qtimer;
 mod qtimezone;
 mod qurl;
 mod qvariant;
@@ -3,18 +3,31 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QCoreApplication, QString};
+use cxx_qt_lib::{QCoreApplication, QObject, QString, QTimer};
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    task::{Context, Poll, Waker},
+};
 
 #[cxx::bridge]
 mod qcoreapplication_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qcoreapplication.h");
         type QCoreApplication = cxx_qt_lib::QCoreApplication;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
     }
 
     extern "Rust" {
         fn construct_qcoreapplication() -> UniquePtr<QCoreApplication>;
         fn read_qcoreapplication(c: &QCoreApplication) -> bool;
+        fn spawn_local_qcoreapplication(app: &QCoreApplication, context: &QObject, msec: i32);
+        fn read_spawn_local_polls() -> u32;
+        fn read_spawn_local_completed() -> bool;
     }
 }
 
@@ -29,3 +42,67 @@ fn construct_qcoreapplication() -> cxx::UniquePtr<QCoreApplication> {
 fn read_qcoreapplication(app: &QCoreApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
+
+static SPAWN_LOCAL_POLLS: AtomicU32 = AtomicU32::new(0);
+static SPAWN_LOCAL_COMPLETED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Default)]
+struct TimerState {
+    fired: bool,
+    waker: Option<Waker>,
+}
+
+/// A future which is ready once a QTimer single shot has fired
+struct TimerFuture {
+    state: Rc<RefCell<TimerState>>,
+}
+
+impl TimerFuture {
+    fn new(msec: i32, context: &QObject) -> Self {
+        let state = Rc::new(RefCell::new(TimerState::default()));
+        let timer_state = state.clone();
+        QTimer::single_shot(msec, context, move || {
+            let mut state = timer_state.borrow_mut();
+            state.fired = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Self { state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        SPAWN_LOCAL_POLLS.fetch_add(1, Ordering::SeqCst);
+
+        let mut state = self.state.borrow_mut();
+        if state.fired {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+fn spawn_local_qcoreapplication(app: &QCoreApplication, context: &QObject, msec: i32) {
+    SPAWN_LOCAL_POLLS.store(0, Ordering::SeqCst);
+    SPAWN_LOCAL_COMPLETED.store(false, Ordering::SeqCst);
+
+    let timer = TimerFuture::new(msec, context);
+    app.spawn_local(async move {
+        timer.await;
+        SPAWN_LOCAL_COMPLETED.store(true, Ordering::SeqCst);
+    });
+}
+
+fn read_spawn_local_polls() -> u32 {
+    SPAWN_LOCAL_POLLS.load(Ordering::SeqCst)
+}
+
+fn read_spawn_local_completed() -> bool {
+    SPAWN_LOCAL_COMPLETED.load(Ordering::SeqCst)
+}
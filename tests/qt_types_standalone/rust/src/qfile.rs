@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QFile, QIODeviceOpenModeFlag, QResource, QString};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+#[cxx::bridge]
+mod qfile_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn write_read_seek_qfile(file_name: &QString) -> bool;
+        fn read_qfile(file_name: &QString) -> QString;
+        fn read_qresource(file_name: &QString) -> QString;
+    }
+}
+
+fn write_read_seek_qfile(file_name: &QString) -> bool {
+    let mut file = QFile::new(file_name);
+    if !file
+        .pin_mut()
+        .open(QIODeviceOpenModeFlag::ReadWrite | QIODeviceOpenModeFlag::Truncate)
+    {
+        return false;
+    }
+
+    let mut device = &*file;
+    if device.write_all(b"Hello, World!").is_err() || Write::flush(&mut device).is_err() {
+        return false;
+    }
+
+    // Seek from the start, the end and the current position then read back what was written
+    let mut world = String::new();
+    if device.seek(SeekFrom::Start(7)).ok() != Some(7)
+        || device.read_to_string(&mut world).is_err()
+        || world != "World!"
+    {
+        return false;
+    }
+
+    let mut hello = [0; 5];
+    if device.seek(SeekFrom::End(-13)).ok() != Some(0)
+        || device.read_exact(&mut hello).is_err()
+        || &hello != b"Hello"
+    {
+        return false;
+    }
+
+    let mut world = [0; 5];
+    device.seek(SeekFrom::Current(2)).ok() == Some(7)
+        && device.read_exact(&mut world).is_ok()
+        && &world == b"World"
+        && device.seek(SeekFrom::Current(-100)).is_err()
+}
+
+fn read_qfile(file_name: &QString) -> QString {
+    let mut file = QFile::new(file_name);
+    let mut contents = String::new();
+    if file.pin_mut().open(QIODeviceOpenModeFlag::ReadOnly) {
+        let mut device = &*file;
+        if device.read_to_string(&mut contents).is_err() {
+            contents.clear();
+        }
+    }
+    QString::from(&contents)
+}
+
+fn read_qresource(file_name: &QString) -> QString {
+    let resource = QResource::new(file_name);
+    if !resource.is_valid() {
+        return QString::default();
+    }
+
+    // The resource can also be read as a file
+    let from_file = read_qfile(file_name);
+    if Vec::from(&resource.uncompressed_data()) != from_file.to_string().into_bytes() {
+        return QString::default();
+    }
+    from_file
+}
@@ -3,18 +3,21 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QQmlApplicationEngine, QUrl};
+use cxx_qt_lib::{QList, QQmlApplicationEngine, QString, QStringList, QUrl};
 
 #[cxx::bridge]
 mod qqmlapplicationengine_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qqmlapplicationengine.h");
         type QQmlApplicationEngine = cxx_qt_lib::QQmlApplicationEngine;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
     }
 
     extern "Rust" {
         fn construct_qqmlapplicationengine() -> UniquePtr<QQmlApplicationEngine>;
         fn read_qqmlapplicationengine(c: &QQmlApplicationEngine) -> bool;
+        fn read_qqmlapplicationengine_root_objects(c: &QQmlApplicationEngine) -> QStringList;
     }
 }
 
@@ -29,3 +32,13 @@ fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
 fn read_qqmlapplicationengine(engine: &QQmlApplicationEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn read_qqmlapplicationengine_root_objects(engine: &QQmlApplicationEngine) -> QStringList {
+    let names = engine
+        .root_objects()
+        .into_iter()
+        // SAFETY: the root objects are owned by the engine, which outlives this function
+        .map(|object| unsafe { &*object }.object_name())
+        .collect::<Vec<QString>>();
+    QStringList::from(&QList::from(names))
+}
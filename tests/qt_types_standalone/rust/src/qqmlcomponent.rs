@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QQmlComponent, QQmlComponentStatus, QQmlEngine, QUrl};
+
+#[cxx::bridge]
+mod qqmlcomponent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        type QQmlComponent = cxx_qt_lib::QQmlComponent;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn construct_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+        fn construct_qqmlcomponent_invalid(
+            engine: Pin<&mut QQmlEngine>,
+        ) -> UniquePtr<QQmlComponent>;
+        fn create_qqmlcomponent(component: Pin<&mut QQmlComponent>) -> *mut QObject;
+        fn read_qqmlcomponent_errors(component: &QQmlComponent) -> i32;
+    }
+}
+
+fn construct_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<QQmlComponent> {
+    QQmlComponent::from_data(
+        engine,
+        &QByteArray::from("import QtQml 2.15\nQtObject { objectName: \"kdab\" }"),
+        &QUrl::from("qrc:/kdab.qml"),
+    )
+}
+
+fn construct_qqmlcomponent_invalid(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<QQmlComponent> {
+    QQmlComponent::from_data(
+        engine,
+        &QByteArray::from("import QtQml 2.15\nQtObject { unknownProperty: 1 }"),
+        &QUrl::from("qrc:/invalid.qml"),
+    )
+}
+
+fn create_qqmlcomponent(component: Pin<&mut QQmlComponent>) -> *mut cxx_qt_lib::QObject {
+    if !component.is_ready() {
+        return std::ptr::null_mut();
+    }
+
+    component.create()
+}
+
+fn read_qqmlcomponent_errors(component: &QQmlComponent) -> i32 {
+    if component.status() != QQmlComponentStatus::Error || !component.is_error() {
+        return -1;
+    }
+
+    let errors = component.errors();
+    if errors
+        .iter()
+        .any(|error| !error.is_valid() || error.url().to_string() != "qrc:/invalid.qml")
+    {
+        return -1;
+    }
+
+    errors.len() as i32
+}
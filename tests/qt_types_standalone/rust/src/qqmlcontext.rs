@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QQmlContext, QQmlEngine, QString, QUrl, QVariant};
+
+#[cxx::bridge]
+mod qqmlcontext_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = cxx_qt_lib::QQmlContext;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn construct_qqmlcontext(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlContext>;
+        fn read_qqmlcontext(c: &QQmlContext) -> bool;
+        fn read_qqmlengine_root_context(engine: &QQmlEngine) -> bool;
+    }
+}
+
+fn construct_qqmlcontext(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<QQmlContext> {
+    let mut context = QQmlContext::new(engine);
+    if let Some(mut context) = context.as_mut() {
+        context.as_mut().set_base_url(&QUrl::from("qrc:/kdab.qml"));
+        context
+            .as_mut()
+            .set_context_property(&QString::from("number"), &QVariant::from(&42));
+    }
+    context
+}
+
+fn read_qqmlcontext(context: &QQmlContext) -> bool {
+    context.is_valid()
+        && !context.parent_context().is_null()
+        && context.base_url().to_string() == "qrc:/kdab.qml"
+        && context
+            .context_property(&QString::from("number"))
+            .value::<i32>()
+            == Some(42)
+}
+
+fn read_qqmlengine_root_context(engine: &QQmlEngine) -> bool {
+    let context = engine.root_context();
+    if context.is_null() {
+        return false;
+    }
+
+    // SAFETY: the root context is owned by the engine, which outlives this function
+    let context = unsafe { &*context };
+    context.is_valid()
+        && context.parent_context().is_null()
+        && std::ptr::eq(context.engine(), engine)
+}
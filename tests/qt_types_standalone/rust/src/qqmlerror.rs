@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QQmlError, QString, QUrl};
+
+#[cxx::bridge]
+mod qqmlerror_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = cxx_qt_lib::QQmlError;
+    }
+
+    extern "Rust" {
+        fn construct_qqmlerror() -> QQmlError;
+        fn read_qqmlerror(e: &QQmlError) -> bool;
+        fn clone_qqmlerror(e: &QQmlError) -> QQmlError;
+    }
+}
+
+fn construct_qqmlerror() -> QQmlError {
+    let mut error = QQmlError::default();
+    error.set_description(&QString::from("kdab"));
+    error.set_url(&QUrl::from("qrc:/kdab.qml"));
+    error.set_line(1);
+    error.set_column(2);
+    error
+}
+
+fn read_qqmlerror(error: &QQmlError) -> bool {
+    error.is_valid()
+        && error.description().to_string() == "kdab"
+        && error.url().to_string() == "qrc:/kdab.qml"
+        && error.line() == 1
+        && error.column() == 2
+        && error.object().is_null()
+}
+
+fn clone_qqmlerror(error: &QQmlError) -> QQmlError {
+    error.clone()
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    QColor, QImage, QImageFormat, QQmlEngine, QQuickAsyncImageProvider, QQuickImageProvider,
+    QQuickImageResponse, QSize, QString,
+};
+
+#[cxx::bridge]
+mod qquickimageprovider_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn add_qquickimageproviders(engine: Pin<&mut QQmlEngine>);
+    }
+}
+
+/// Create an image of the given size, filled with the color named by the id
+fn color_image(id: &str, (width, height): (i32, i32)) -> Option<QImage> {
+    let color = QColor::try_from(id).ok()?;
+    let mut image =
+        QImage::from_width_height_and_format(width, height, QImageFormat::Format_ARGB32);
+    image.fill(&color);
+    Some(image)
+}
+
+/// Use the requested size if there is one, otherwise 16x16
+fn image_size(requested_size: &QSize) -> (i32, i32) {
+    if requested_size.is_valid() {
+        (requested_size.width(), requested_size.height())
+    } else {
+        (16, 16)
+    }
+}
+
+struct ColorImageProvider;
+
+impl QQuickImageProvider for ColorImageProvider {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        color_image(&id.to_string(), image_size(requested_size)).unwrap_or_default()
+    }
+}
+
+struct AsyncColorImageProvider;
+
+impl QQuickAsyncImageProvider for AsyncColorImageProvider {
+    fn request_image_response(
+        &self,
+        id: &QString,
+        requested_size: &QSize,
+        response: QQuickImageResponse,
+    ) {
+        let id = id.to_string();
+        let size = image_size(requested_size);
+        // Finish the response from another thread, as a real asynchronous provider would
+        std::thread::spawn(move || match color_image(&id, size) {
+            Some(image) => response.finish(image),
+            None => response.finish_with_error(&QString::from("Unknown color")),
+        });
+    }
+}
+
+fn add_qquickimageproviders(mut engine: Pin<&mut QQmlEngine>) {
+    engine
+        .as_mut()
+        .add_image_provider(&QString::from("colors"), ColorImageProvider);
+    engine
+        .as_mut()
+        .add_async_image_provider(&QString::from("asynccolors"), AsyncColorImageProvider);
+}
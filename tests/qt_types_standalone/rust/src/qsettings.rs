@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QSettings, QSettingsFormat, QSettingsStatus, QString};
+
+#[cxx::bridge]
+mod qsettings_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn write_qsettings(file_name: &QString);
+        fn read_qsettings(file_name: &QString) -> bool;
+    }
+}
+
+fn write_qsettings(file_name: &QString) {
+    let mut settings = QSettings::new_with_file(file_name, QSettingsFormat::IniFormat);
+    if let Some(mut settings) = settings.as_mut() {
+        settings.as_mut().set_value(&QString::from("number"), &42);
+
+        settings.as_mut().begin_group(&QString::from("group"));
+        settings
+            .as_mut()
+            .set_value(&QString::from("name"), &QString::from("kdab"));
+        settings.as_mut().end_group();
+
+        settings
+            .as_mut()
+            .begin_write_array(&QString::from("array"), -1);
+        for (index, value) in [1, 2, 3].iter().enumerate() {
+            settings.as_mut().set_array_index(index as i32);
+            settings.as_mut().set_value(&QString::from("value"), value);
+        }
+        settings.as_mut().end_array();
+
+        settings.as_mut().sync();
+    }
+}
+
+fn read_qsettings(file_name: &QString) -> bool {
+    let mut settings = QSettings::new_with_file(file_name, QSettingsFormat::IniFormat);
+    let mut settings = if let Some(settings) = settings.as_mut() {
+        settings
+    } else {
+        return false;
+    };
+
+    if settings.status() != QSettingsStatus::NoError
+        || settings.format() != QSettingsFormat::IniFormat
+        || settings.value::<i32>(&QString::from("number")) != Some(42)
+        || settings.value::<i32>(&QString::from("missing")).is_some()
+        || !settings.contains(&QString::from("group/name"))
+    {
+        return false;
+    }
+
+    settings.as_mut().begin_group(&QString::from("group"));
+    let name = settings.value::<QString>(&QString::from("name"));
+    settings.as_mut().end_group();
+    if name != Some(QString::from("kdab")) {
+        return false;
+    }
+
+    let size = settings.as_mut().begin_read_array(&QString::from("array"));
+    let values = (0..size)
+        .map(|index| {
+            settings.as_mut().set_array_index(index);
+            settings.value::<i32>(&QString::from("value"))
+        })
+        .collect::<Vec<_>>();
+    settings.as_mut().end_array();
+    values == [Some(1), Some(2), Some(3)]
+}
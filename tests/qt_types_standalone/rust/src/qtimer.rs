@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QTimer};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[cxx::bridge]
+mod qtimer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn construct_qtimer() -> UniquePtr<QTimer>;
+        fn start_stop_qtimer(timer: Pin<&mut QTimer>) -> bool;
+        fn connect_qtimer_timeout(timer: Pin<&mut QTimer>);
+        fn read_qtimer_timeouts() -> u32;
+        fn qtimer_single_shot(msec: i32, context: &QObject);
+        fn read_qtimer_single_shot_called() -> u32;
+        fn read_qtimer_single_shot_dropped() -> u32;
+    }
+}
+
+static TIMEOUTS: AtomicU32 = AtomicU32::new(0);
+static SINGLE_SHOT_CALLED: AtomicU32 = AtomicU32::new(0);
+static SINGLE_SHOT_DROPPED: AtomicU32 = AtomicU32::new(0);
+
+/// Counts when the single shot closure, and therefore this guard it captures, is dropped
+struct SingleShotGuard;
+
+impl Drop for SingleShotGuard {
+    fn drop(&mut self) {
+        SINGLE_SHOT_DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn construct_qtimer() -> cxx::UniquePtr<QTimer> {
+    let mut timer = QTimer::new();
+    if let Some(mut timer) = timer.as_mut() {
+        timer.as_mut().set_interval(100);
+        timer.as_mut().set_single_shot(true);
+    }
+    timer
+}
+
+fn start_stop_qtimer(mut timer: Pin<&mut QTimer>) -> bool {
+    if timer.is_active() || timer.remaining_time() != -1 {
+        return false;
+    }
+
+    timer.as_mut().start_with_interval(1000);
+    if !timer.is_active() || timer.interval() != 1000 || timer.remaining_time() < 0 {
+        return false;
+    }
+
+    timer.as_mut().stop();
+    if timer.is_active() || timer.remaining_time() != -1 {
+        return false;
+    }
+
+    // Starting again reuses the interval from before
+    timer.as_mut().start();
+    let restarted = timer.is_active() && timer.interval() == 1000;
+    timer.as_mut().stop();
+    restarted
+}
+
+fn connect_qtimer_timeout(timer: Pin<&mut QTimer>) {
+    TIMEOUTS.store(0, Ordering::SeqCst);
+    timer
+        .on_timeout(|_| {
+            TIMEOUTS.fetch_add(1, Ordering::SeqCst);
+        })
+        .release();
+}
+
+fn read_qtimer_timeouts() -> u32 {
+    TIMEOUTS.load(Ordering::SeqCst)
+}
+
+fn qtimer_single_shot(msec: i32, context: &QObject) {
+    SINGLE_SHOT_CALLED.store(0, Ordering::SeqCst);
+    SINGLE_SHOT_DROPPED.store(0, Ordering::SeqCst);
+
+    let guard = SingleShotGuard;
+    QTimer::single_shot(msec, context, move || {
+        let _guard = guard;
+        SINGLE_SHOT_CALLED.fetch_add(1, Ordering::SeqCst);
+    });
+}
+
+fn read_qtimer_single_shot_called() -> u32 {
+    SINGLE_SHOT_CALLED.load(Ordering::SeqCst)
+}
+
+fn read_qtimer_single_shot_dropped() -> u32 {
+    SINGLE_SHOT_DROPPED.load(Ordering::SeqCst)
+}